@@ -1,5 +1,11 @@
 import type * as LSP from 'vscode-languageserver-protocol'
-import React, { createContext, useMemo, useContext, useState } from 'react'
+import React, {
+  createContext,
+  useEffect,
+  useMemo,
+  useContext,
+  useState,
+} from 'react'
 import {
   LanguageServerClient,
   FromServer,
@@ -24,9 +30,10 @@ import {
 } from 'editor/plugins/lsp/types'
 import { wasmUrl } from 'lang/wasm'
 import { PROJECT_ENTRYPOINT } from 'lib/constants'
-import { err } from 'lib/trap'
+import { err, reportRejection } from 'lib/trap'
 import { isDesktop } from 'lib/isDesktop'
 import { codeManager } from 'lib/singletons'
+import { UpdateTelemetryParams } from 'wasm-lib/kcl/bindings/UpdateTelemetryParams'
 
 function getWorkspaceFolders(): LSP.WorkspaceFolder[] {
   return []
@@ -74,6 +81,7 @@ export const LspProvider = ({ children }: { children: React.ReactNode }) => {
     settings: {
       context: {
         modeling: { defaultUnit },
+        telemetry: { level: telemetryLevel },
       },
     },
   } = useSettingsAuthContext()
@@ -137,6 +145,17 @@ export const LspProvider = ({ children }: { children: React.ReactNode }) => {
     }
   }, [kclLspClient, isKclLspReady])
 
+  // Tell the server what telemetry the user has opted in to, whenever it changes.
+  useEffect(() => {
+    if (!isKclLspReady || !kclLspClient) return
+    const params: UpdateTelemetryParams = {
+      telemetry: { level: telemetryLevel.current, local_path: null },
+    }
+    kclLspClient
+      .requestCustom('kcl/updateTelemetry', params)
+      .catch(reportRejection)
+  }, [kclLspClient, isKclLspReady, telemetryLevel.current])
+
   // Here we initialize the plugin which will start the client.
   // Now that we have multi-file support the name of the file is a dep of
   // this use memo, as well as the directory structure, which I think is
@@ -17,6 +17,10 @@ export interface CopilotWorkerOptions {
   wasmUrl: string
   token: string
   apiBaseUrl: string
+  // An OpenAI-compatible server to get completions from instead of the API.
+  completionUrl?: string
+  model?: string
+  completionApiKey?: string
 }
 
 export interface LspWorkerEvent {
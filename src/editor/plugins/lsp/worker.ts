@@ -7,6 +7,7 @@ import {
 import { fileSystemManager } from 'lang/std/fileSystemManager'
 import init, {
   ServerConfig,
+  CopilotLspServerConfig,
   copilot_lsp_run,
   kcl_lsp_run,
 } from 'wasm-lib/pkg/wasm_lib'
@@ -32,12 +33,13 @@ const initialise = async (wasmUrl: string) => {
 
 export async function copilotLspRun(
   config: ServerConfig,
+  copilotConfig: CopilotLspServerConfig,
   token: string,
   baseUrl: string
 ) {
   try {
     console.log('starting copilot lsp')
-    await copilot_lsp_run(config, token, baseUrl)
+    await copilot_lsp_run(config, copilotConfig, token, baseUrl)
   } catch (e: any) {
     console.log('copilot lsp failed', e)
     // We can't restart here because a moved value, we should do this another way.
@@ -94,6 +96,11 @@ onmessage = function (event: MessageEvent) {
               let copilotData = eventData as CopilotWorkerOptions
               await copilotLspRun(
                 config,
+                new CopilotLspServerConfig(
+                  copilotData.completionUrl,
+                  copilotData.model,
+                  copilotData.completionApiKey
+                ),
                 copilotData.token,
                 copilotData.apiBaseUrl
               )
@@ -50,6 +50,10 @@ export default class CodeManager {
     return this._code
   }
 
+  get currentFilePath(): string | null {
+    return this._currentFilePath
+  }
+
   registerCallBacks({ setCode }: { setCode: (arg: string) => void }) {
     this.#updateState = setCode
   }
@@ -5,18 +5,22 @@ import init, {
   kcl_lint,
   lexer_wasm,
   modify_ast_for_sketch_wasm,
+  apply_ast_edits_wasm,
   is_points_ccw,
   get_tangential_arc_to_info,
   program_memory_init,
   make_default_planes,
   modify_grid,
   coredump,
+  coredump_local,
   toml_stringify,
   default_app_settings,
   parse_app_settings,
   parse_project_settings,
   default_project_settings,
   base64_decode,
+  artifact_source_range,
+  artifacts_at_source_position,
 } from '../wasm-lib/pkg/wasm_lib'
 import { KCLError } from './errors'
 import { KclError as RustKclError } from '../wasm-lib/kcl/bindings/KclError'
@@ -25,6 +29,8 @@ import { Discovered } from '../wasm-lib/kcl/bindings/Discovered'
 import { KclValue } from '../wasm-lib/kcl/bindings/KclValue'
 import type { Program } from '../wasm-lib/kcl/bindings/Program'
 import type { Token } from '../wasm-lib/kcl/bindings/Token'
+import type { AstEdit } from '../wasm-lib/kcl/bindings/AstEdit'
+import type { AstEditResult } from '../wasm-lib/kcl/bindings/AstEditResult'
 import { Coords2d } from './std/sketch'
 import { fileSystemManager } from 'lang/std/fileSystemManager'
 import { CoreDumpInfo } from 'wasm-lib/kcl/bindings/CoreDumpInfo'
@@ -39,6 +45,7 @@ import { ProjectConfiguration } from 'wasm-lib/kcl/bindings/ProjectConfiguration
 import { Sketch } from '../wasm-lib/kcl/bindings/Sketch'
 import { IdGenerator } from 'wasm-lib/kcl/bindings/IdGenerator'
 import { ExecState as RawExecState } from '../wasm-lib/kcl/bindings/ExecState'
+import { ArtifactGraph as KclArtifactGraph } from '../wasm-lib/kcl/bindings/ArtifactGraph'
 import { ProgramMemory as RawProgramMemory } from '../wasm-lib/kcl/bindings/ProgramMemory'
 import { EnvironmentRef } from '../wasm-lib/kcl/bindings/EnvironmentRef'
 import { Environment } from '../wasm-lib/kcl/bindings/Environment'
@@ -144,6 +151,7 @@ export type PathToNode = [string | number, string][]
 export interface ExecState {
   memory: ProgramMemory
   idGenerator: IdGenerator
+  artifactGraph: KclArtifactGraph
 }
 
 /**
@@ -154,6 +162,7 @@ export function emptyExecState(): ExecState {
   return {
     memory: ProgramMemory.empty(),
     idGenerator: defaultIdGenerator(),
+    artifactGraph: { artifacts: {} },
   }
 }
 
@@ -161,13 +170,13 @@ function execStateFromRaw(raw: RawExecState): ExecState {
   return {
     memory: ProgramMemory.fromRaw(raw.memory),
     idGenerator: raw.idGenerator,
+    artifactGraph: raw.artifactGraph,
   }
 }
 
 export function defaultIdGenerator(): IdGenerator {
   return {
     nextId: 0,
-    ids: [],
   }
 }
 
@@ -521,6 +530,53 @@ export const modifyAstForSketch = async (
   }
 }
 
+/**
+ * Apply typed edits to a program as one transaction: either every edit
+ * applies, or none do. Returns the new source, the re-parsed program, and
+ * where each edited node ended up.
+ */
+export function applyAstEdits(
+  ast: Program,
+  edits: AstEdit[]
+): AstEditResult | Error {
+  try {
+    const result: AstEditResult = apply_ast_edits_wasm(
+      JSON.stringify(ast),
+      JSON.stringify(edits)
+    )
+    return result
+  } catch (e: any) {
+    const parsed: RustKclError = JSON.parse(e.toString())
+    return new KCLError(
+      parsed.kind,
+      parsed.msg,
+      rangeTypeFix(parsed.sourceRanges)
+    )
+  }
+}
+
+/**
+ * The source range of the code which made an artifact, from the artifact
+ * graph built while executing.
+ */
+export function artifactSourceRange(
+  artifactGraph: KclArtifactGraph,
+  id: string
+): [number, number] | null {
+  return artifact_source_range(JSON.stringify(artifactGraph), id)
+}
+
+/**
+ * The ids of the artifacts made by the code at a position in the source,
+ * innermost code first.
+ */
+export function artifactsAtSourcePosition(
+  artifactGraph: KclArtifactGraph,
+  pos: number
+): string[] {
+  return artifacts_at_source_position(JSON.stringify(artifactGraph), pos)
+}
+
 export function isPointsCCW(points: Coords2d[]): number {
   return is_points_ccw(new Float64Array(points.flat()))
 }
@@ -616,6 +672,25 @@ export async function coreDump(
   }
 }
 
+/**
+ * Make a core dump as a zip archive without uploading anything, for
+ * machines which can't reach the API. The archive has the code of every
+ * open file, the client state, the engine command log and a screenshot.
+ */
+export async function coreDumpLocal(
+  coreDumpManager: CoreDumpManager
+): Promise<Uint8Array> {
+  try {
+    console.warn('CoreDump: Initializing local core dump')
+    const archive: Uint8Array = await coredump_local(coreDumpManager)
+    console.log(`CoreDump: local core dump is ${archive.byteLength} bytes`)
+    return archive
+  } catch (e: any) {
+    console.error('CoreDump: error', e)
+    return Promise.reject(new Error(`Error getting local core dump: ${e}`))
+  }
+}
+
 export function tomlStringify(toml: any): string | Error {
   return toml_stringify(JSON.stringify(toml))
 }
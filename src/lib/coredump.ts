@@ -7,6 +7,7 @@ import { UAParser } from 'ua-parser-js'
 import screenshot from 'lib/screenshot'
 import { VITE_KC_API_BASE_URL } from 'env'
 import CodeManager from 'lang/codeManager'
+import { FILE_EXT } from 'lib/constants'
 
 /* eslint-disable suggest-no-throw/suggest-no-throw --
  * All the throws in CoreDumpManager are intentional and should be caught and handled properly
@@ -64,6 +65,42 @@ export class CoreDumpManager {
     return this.codeManager.code
   }
 
+  // Get the KCL code of every file in the current file's project, keyed by path relative to it,
+  // so imports between them resolve when the core dump is replayed.
+  // The current file's code is taken from the editor, so unsaved changes are included.
+  async kclFiles(): Promise<string> {
+    const currentFilePath = this.codeManager.currentFilePath
+    if (!isDesktop() || !currentFilePath) {
+      return JSON.stringify({ 'main.kcl': this.codeManager.code })
+    }
+
+    const projectDir = window.electron.path.dirname(currentFilePath)
+    const files: { [path: string]: string } = {}
+    const collect = async (dir: string) => {
+      for (const entry of await window.electron.readdir(dir)) {
+        // Ignore hidden files and directories, like the file tree does.
+        if (entry.startsWith('.')) continue
+        const entryPath = window.electron.path.join(dir, entry)
+        if (await window.electron.statIsDirectory(entryPath)) {
+          await collect(entryPath)
+        } else if (entry.endsWith(FILE_EXT)) {
+          const relativePath = window.electron.path.relative(
+            projectDir,
+            entryPath
+          )
+          files[relativePath] =
+            entryPath === currentFilePath
+              ? this.codeManager.code
+              : await window.electron.readFile(entryPath, {
+                  encoding: 'utf-8',
+                })
+        }
+      }
+    }
+    await collect(projectDir)
+    return JSON.stringify(files)
+  }
+
   // Get the backend pool we've requested.
   pool(): string {
     return this.engineCommandManager.settings.pool || ''
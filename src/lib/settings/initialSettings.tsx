@@ -19,6 +19,9 @@ import Tooltip from 'components/Tooltip'
 import { toSync } from 'lib/utils'
 import { reportRejection } from 'lib/trap'
 import { CameraProjectionType } from 'wasm-lib/kcl/bindings/CameraProjectionType'
+import { TelemetryLevel } from 'wasm-lib/kcl/bindings/TelemetryLevel'
+
+const telemetryLevels: TelemetryLevel[] = ['off', 'metadata_only', 'full']
 
 /**
  * A setting that can be set at the user or project level
@@ -498,6 +501,35 @@ export function createSettings() {
       //   hideOnLevel: 'project',
       // }),
     },
+    /**
+     * Settings that control what usage data is collected.
+     */
+    telemetry: {
+      /**
+       * How much to collect about the KCL you write. Nothing is collected
+       * unless you opt in.
+       */
+      level: new Setting<TelemetryLevel>({
+        defaultValue: 'off',
+        hideOnLevel: 'project',
+        description:
+          'How much to collect about the KCL you write: nothing, metadata about each file, or the code with strings and comments removed',
+        validate: (v) => telemetryLevels.includes(v),
+        commandConfig: {
+          inputType: 'options',
+          defaultValueFromContext: (context) => context.telemetry.level.current,
+          options: (cmdContext, settingsContext) =>
+            telemetryLevels.map((v) => ({
+              name: v.charAt(0).toUpperCase() + v.slice(1).replace('_', ' '),
+              value: v,
+              isCurrent: settingsContext.telemetry.level.shouldShowCurrentLabel(
+                cmdContext.argumentsToSubmit.level as SettingsLevel,
+                v
+              ),
+            })),
+        },
+      }),
+    },
     /**
      * Settings that affect the behavior of the command bar.
      */
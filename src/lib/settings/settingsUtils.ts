@@ -58,6 +58,9 @@ export function configurationToSettingsPayload(
       textWrapping: configuration?.settings?.text_editor?.text_wrapping,
       blinkingCursor: configuration?.settings?.text_editor?.blinking_cursor,
     },
+    telemetry: {
+      level: configuration?.settings?.telemetry?.level,
+    },
     projects: {
       defaultProjectName:
         configuration?.settings?.project?.default_project_name,
@@ -34,6 +34,10 @@ struct StdlibMetadata {
     /// Then specific docs detailing that this is deprecated will be generated.
     #[serde(default)]
     deprecated: bool,
+    /// Whether the function needs the engine, and errors without it.
+    /// Then its examples are not run against the mock engine.
+    #[serde(default)]
+    needs_engine: bool,
 }
 
 #[proc_macro_attribute]
@@ -117,6 +121,7 @@ fn do_stdlib_inner(
     // Fail if the name is not camel case.
     let whitelist = [
         "mirror2d",
+        "mirror3d",
         "patternLinear3d",
         "patternLinear2d",
         "patternCircular3d",
@@ -168,7 +173,7 @@ fn do_stdlib_inner(
         quote! { "" }
     };
 
-    let cb = doc_info.code_blocks.clone();
+    let cb = doc_info.code_blocks.iter().map(|(code, _)| code).collect::<Vec<_>>();
     let code_blocks = if !cb.is_empty() {
         quote! {
             let code_blocks = vec![#(#cb),*];
@@ -192,7 +197,7 @@ fn do_stdlib_inner(
     };
 
     // Make sure the function name is in all the code blocks.
-    for code_block in doc_info.code_blocks.iter() {
+    for (code_block, _) in doc_info.code_blocks.iter() {
         if !code_block.contains(&name) {
             errors.push(Error::new_spanned(
                 &ast.sig,
@@ -208,7 +213,17 @@ fn do_stdlib_inner(
         .code_blocks
         .iter()
         .enumerate()
-        .map(|(index, code_block)| generate_code_block_test(&fn_name_str, code_block, index))
+        .map(|(index, (code_block, has_image))| {
+            generate_code_block_test(&fn_name_str, code_block, index, metadata.needs_engine, *has_image)
+        })
+        .collect::<Vec<_>>();
+
+    let examples_without_images = doc_info
+        .code_blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, has_image))| !has_image)
+        .map(|(index, _)| index)
         .collect::<Vec<_>>();
 
     let tags = metadata
@@ -436,6 +451,10 @@ fn do_stdlib_inner(
                 #code_blocks
             }
 
+            fn examples_without_images(&self) -> Vec<usize> {
+                vec![#(#examples_without_images),*]
+            }
+
             fn std_lib_fn(&self) -> crate::std::StdFn {
                 #boxed_fn_name_ident
             }
@@ -475,12 +494,14 @@ fn get_crate(var: Option<String>) -> proc_macro2::TokenStream {
 struct DocInfo {
     pub summary: Option<String>,
     pub description: Option<String>,
-    pub code_blocks: Vec<String>,
+    /// Each code block, and whether it has a rendered image, which it doesn't when its fence
+    /// is marked `no_image`.
+    pub code_blocks: Vec<(String, bool)>,
 }
 
 fn extract_doc_from_attrs(attrs: &[syn::Attribute]) -> DocInfo {
     let doc = syn::Ident::new("doc", proc_macro2::Span::call_site());
-    let mut code_blocks: Vec<String> = Vec::new();
+    let mut code_blocks: Vec<(String, bool)> = Vec::new();
 
     let raw_lines = attrs.iter().flat_map(|attr| {
         if let syn::Meta::NameValue(nv) = &attr.meta {
@@ -498,13 +519,15 @@ fn extract_doc_from_attrs(attrs: &[syn::Attribute]) -> DocInfo {
 
     // Parse any code blocks from the doc string.
     let mut code_block: Option<String> = None;
+    let mut has_image = true;
     let mut parsed_lines = Vec::new();
     for line in raw_lines {
-        if line.starts_with("```") {
+        if let Some(info) = line.strip_prefix("```") {
             if let Some(ref inner_code_block) = code_block {
-                code_blocks.push(inner_code_block.trim().to_string());
+                code_blocks.push((inner_code_block.trim().to_string(), has_image));
                 code_block = None;
             } else {
+                has_image = !info.split(',').any(|attr| attr.trim() == "no_image");
                 code_block = Some(String::new());
             }
 
@@ -530,7 +553,7 @@ fn extract_doc_from_attrs(attrs: &[syn::Attribute]) -> DocInfo {
             }
         } else {
             if let Some(ref inner_code_block) = code_block {
-                code_blocks.push(inner_code_block.trim().to_string());
+                code_blocks.push((inner_code_block.trim().to_string(), true));
                 code_block = None;
             }
             lines.push(line);
@@ -540,7 +563,7 @@ fn extract_doc_from_attrs(attrs: &[syn::Attribute]) -> DocInfo {
     let mut lines = lines.into_iter();
 
     if let Some(code_block) = code_block {
-        code_blocks.push(code_block.trim().to_string());
+        code_blocks.push((code_block.trim().to_string(), true));
     }
 
     // Skip initial blank lines; they make for excessively terse summaries.
@@ -742,13 +765,33 @@ fn parse_array_type(type_name: &str) -> Option<(&str, usize)> {
 
 // For each kcl code block, we want to generate a test that checks that the
 // code block is valid kcl code and compiles and executes.
-fn generate_code_block_test(fn_name: &str, code_block: &str, index: usize) -> proc_macro2::TokenStream {
+fn generate_code_block_test(
+    fn_name: &str,
+    code_block: &str,
+    index: usize,
+    needs_engine: bool,
+    has_image: bool,
+) -> proc_macro2::TokenStream {
     let test_name = format_ident!("kcl_test_example_{}{}", fn_name, index);
     let test_name_mock = format_ident!("test_mock_example_{}{}", fn_name, index);
     let output_test_name_str = format!("serial_test_example_{}{}", fn_name, index);
+    // Functions which need the engine error against the mock engine.
+    let ignore_mock = needs_engine.then(|| quote! { #[ignore] });
+    // Examples marked `no_image` are executed against the engine, but have no image to compare to.
+    let snapshot = if has_image {
+        quote! {
+            let result = crate::test_server::execute_and_snapshot(code, crate::settings::types::UnitLength::Mm).await.unwrap();
+            twenty_twenty::assert_image(&format!("tests/outputs/{}.png", #output_test_name_str), &result, 0.99);
+        }
+    } else {
+        quote! {
+            crate::test_server::execute_and_snapshot(code, crate::settings::types::UnitLength::Mm).await.unwrap();
+        }
+    };
 
     quote! {
         #[tokio::test(flavor = "multi_thread")]
+        #ignore_mock
         async fn #test_name_mock() {
             let tokens = crate::token::lexer(#code_block).unwrap();
             let parser = crate::parser::Parser::new(tokens);
@@ -762,15 +805,15 @@ fn generate_code_block_test(fn_name: &str, code_block: &str, index: usize) -> pr
                 context_type: crate::executor::ContextType::Mock,
             };
 
-            ctx.run(&program, None, id_generator, None).await.unwrap();
+            // Modules the example imports are with the other example inputs.
+            ctx.run(&program, None, id_generator, Some("tests/inputs".to_string())).await.unwrap();
         }
 
         #[tokio::test(flavor = "multi_thread", worker_threads = 5)]
         async fn #test_name() {
             let code = #code_block;
             // Note, `crate` must be kcl_lib
-            let result = crate::test_server::execute_and_snapshot(code, crate::settings::types::UnitLength::Mm).await.unwrap();
-            twenty_twenty::assert_image(&format!("tests/outputs/{}.png", #output_test_name_str), &result, 0.99);
+            #snapshot
         }
     }
 }
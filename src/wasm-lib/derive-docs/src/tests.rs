@@ -415,6 +415,42 @@ fn test_stdlib_doc_comment_with_code() {
     expectorate::assert_contents("tests/doc_comment_with_code.gen", &get_text_fmt(&item).unwrap());
 }
 
+#[test]
+fn test_stdlib_doc_comment_with_code_without_image() {
+    let (item, errors) = do_stdlib(
+        quote! {
+            name = "myFunc",
+        },
+        quote! {
+            /// This is some function.
+            /// It does shit.
+            ///
+            /// ```no_run
+            /// This is a code block.
+            /// myFunc
+            /// ```
+            ///
+            /// ```no_run,no_image
+            /// This is a code block with no image.
+            /// myFunc
+            /// ```
+            fn inner_my_func(
+                /// The args to do shit to.
+                args: Option<kittycad::types::InputFormat>
+            ) -> Result<Vec<Box<Sketch>>> {
+                args
+            }
+        },
+    )
+    .unwrap();
+
+    assert!(errors.is_empty());
+    expectorate::assert_contents(
+        "tests/doc_comment_with_code_without_image.gen",
+        &get_text_fmt(&item).unwrap(),
+    );
+}
+
 #[test]
 fn test_stdlib_fail_non_camel_case() {
     let (_, errors) = do_stdlib(
@@ -0,0 +1,9 @@
+//! The `kcl` Python module. The bindings are in `kcl_lib::python`; this crate builds them into a
+//! cdylib for maturin, outside the workspace so `pyo3/extension-module` stays out of its builds.
+
+use pyo3::prelude::*;
+
+#[pymodule]
+fn kcl(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    kcl_lib::python::register(m)
+}
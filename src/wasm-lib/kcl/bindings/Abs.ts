@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: abs
+Compute the absolute value of a number.*/
+export type Abs = Record<string, never>;
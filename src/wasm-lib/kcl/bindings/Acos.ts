@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: acos
+Compute the arccosine of a number (in radians).*/
+export type Acos = Record<string, never>;
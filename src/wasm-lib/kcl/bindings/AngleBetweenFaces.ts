@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: angleBetweenFaces
+Get the angle in degrees between the normals of two planar faces of a
+solid. Faces which are square to each other are 90 degrees apart, and the angle inside the solid where they meet is 180 minus the result. Faces of solids which have had features added to them are measured by the engine.*/
+export type AngleBetweenFaces = Record<string, never>;
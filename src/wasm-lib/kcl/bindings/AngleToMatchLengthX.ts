@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: angleToMatchLengthX
+Compute the angle (in degrees) in o*/
+export type AngleToMatchLengthX = Record<string, never>;
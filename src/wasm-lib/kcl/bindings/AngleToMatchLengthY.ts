@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: angleToMatchLengthY
+Returns the angle to match the given length for y.*/
+export type AngleToMatchLengthY = Record<string, never>;
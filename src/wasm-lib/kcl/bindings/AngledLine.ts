@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: angledLine
+Draw a line segment relative to the current origin using the polar
+measure of some angle and distance.*/
+export type AngledLine = Record<string, never>;
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data to draw an angled line.
+ */
+export type AngledLineData = { 
+/**
+ * The angle of the line (in degrees).
+ */
+angle: number, 
+/**
+ * The length of the line.
+ */
+length: number, } | [number, number];
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: angledLineOfXLength
+Create a line segment from the current 2-dimensional sketch origin
+along some angle (in degrees) for some relative length in the 'x' dimension.*/
+export type AngledLineOfXLength = Record<string, never>;
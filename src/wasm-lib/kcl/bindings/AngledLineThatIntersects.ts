@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: angledLineThatIntersects
+Draw an angled line from the current origin, constructing a line segment
+such that the newly created line intersects the desired target line segment.*/
+export type AngledLineThatIntersects = Record<string, never>;
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TagIdentifier } from "./TagIdentifier";
+
+/**
+ * Data for drawing an angled line that intersects with a given line.
+ */
+export type AngledLineThatIntersectsData = { 
+/**
+ * The angle of the line.
+ */
+angle: number, 
+/**
+ * The tag of the line to intersect with.
+ */
+intersectTag: TagIdentifier, 
+/**
+ * The offset from the intersecting line.
+ */
+offset: number | null, };
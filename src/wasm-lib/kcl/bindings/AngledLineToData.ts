@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data to draw an angled line to a point.
+ */
+export type AngledLineToData = { 
+/**
+ * The angle of the line.
+ */
+angle: number, 
+/**
+ * The point to draw to.
+ */
+to: number, };
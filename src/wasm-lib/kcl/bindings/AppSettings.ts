@@ -0,0 +1,43 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppTheme } from "./AppTheme";
+import type { AppearanceSettings } from "./AppearanceSettings";
+import type { FloatOrInt } from "./FloatOrInt";
+import type { OnboardingStatus } from "./OnboardingStatus";
+
+/**
+ * Application wide settings.
+ */
+export type AppSettings = { 
+/**
+ * The settings for the appearance of the app.
+ */
+appearance: AppearanceSettings, 
+/**
+ * The onboarding status of the app.
+ */
+onboarding_status: OnboardingStatus, 
+/**
+ * Backwards compatible project directory setting.
+ */
+project_directory: string | null, 
+/**
+ * Backwards compatible theme setting.
+ */
+theme: AppTheme | null, 
+/**
+ * The hue of the primary theme color for the app.
+ */
+theme_color: FloatOrInt | null, 
+/**
+ * Whether or not Screen Space Ambient Occlusion (SSAO) is enabled.
+ */
+enable_ssao: boolean | null, 
+/**
+ * Permanently dismiss the banner warning to download the desktop app.
+ * This setting only applies to the web app. And is temporary until we have Linux support.
+ */
+dismiss_web_banner: boolean, 
+/**
+ * When the user is idle, and this is true, the stream will be torn down.
+ */
+stream_idle_mode: boolean, };
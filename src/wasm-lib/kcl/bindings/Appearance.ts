@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: appearance
+Set the color, metalness and roughness of solids or imported geometry.
+The engine renders the geometry with them, so they show in snapshots and are written to exports which carry materials, like glTF.*/
+export type Appearance = Record<string, never>;
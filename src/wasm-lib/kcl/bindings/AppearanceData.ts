@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How a solid looks.
+ */
+export type AppearanceData = { 
+/**
+ * The color, as a hex string like `"#ff8800"`, with an optional alpha like `"#ff880080"`.
+ */
+color: string, 
+/**
+ * How metallic the surface is, from 0 to 100. Defaults to 0.
+ */
+metalness: number | null, 
+/**
+ * How rough the surface is, from 0 to 100. Defaults to 0.
+ */
+roughness: number | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: arc
+Draw a curved line segment along an imaginary circle.
+The arc is constructed such that the current position of the sketch is placed along an imaginary circle of the specified radius, at angleStart degrees. The resulting arc is the segment of the imaginary circle from that origin point to angleEnd, radius away from the center of the imaginary circle.
+
+Unless this makes a lot of sense and feels like what you're looking for to construct your shape, you're likely looking for tangentialArc.*/
+export type Arc = Record<string, never>;
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data to draw an arc.
+ */
+export type ArcData = { 
+/**
+ * The start angle.
+ */
+angle_start: number, 
+/**
+ * The end angle.
+ */
+angle_end: number, 
+/**
+ * The radius.
+ */
+radius: number, } | { 
+/**
+ * The center.
+ */
+center: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The radius.
+ */
+radius: number, };
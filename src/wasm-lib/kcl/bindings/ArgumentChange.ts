@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A changed argument of a call inside a changed statement.
+ */
+export type ArgumentChange = { 
+/**
+ * The name of the function being called.
+ */
+call: string, 
+/**
+ * The index of the argument.
+ */
+index: number, before: string, after: string, };
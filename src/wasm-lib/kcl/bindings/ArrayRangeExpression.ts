@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expr } from "./Expr";
+
+export type ArrayRangeExpression = { "type": "ArrayRangeExpression", start: number, end: number, startElement: Expr, endElement: Expr, 
+/**
+ * Is the `end_element` included in the range?
+ */
+endInclusive: boolean, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CapArtifact } from "./CapArtifact";
+import type { EdgeArtifact } from "./EdgeArtifact";
+import type { EdgeCutArtifact } from "./EdgeCutArtifact";
+import type { PathArtifact } from "./PathArtifact";
+import type { PlaneArtifact } from "./PlaneArtifact";
+import type { SegmentArtifact } from "./SegmentArtifact";
+import type { WallArtifact } from "./WallArtifact";
+
+/**
+ * A piece of geometry the program made.
+ */
+export type Artifact = { "type": "plane" } & PlaneArtifact | { "type": "path" } & PathArtifact | { "type": "segment" } & SegmentArtifact | { "type": "wall" } & WallArtifact | { "type": "cap" } & CapArtifact | { "type": "edge" } & EdgeArtifact | { "type": "edgeCut" } & EdgeCutArtifact;
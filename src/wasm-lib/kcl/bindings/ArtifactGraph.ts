@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Artifact } from "./Artifact";
+
+/**
+ * Every artifact the program made, by id.
+ */
+export type ArtifactGraph = { artifacts: { [key in string]?: Artifact }, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: asin
+Compute the arcsine of a number (in radians).*/
+export type Asin = Record<string, never>;
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Instance } from "./Instance";
+import type { PartDefinition } from "./PartDefinition";
+
+/**
+ * The parts and instances of the program.
+ */
+export type Assembly = { 
+/**
+ * The parts which were imported.
+ */
+parts: Array<PartDefinition>, 
+/**
+ * The instances at the top of the tree, in the order they were made.
+ */
+instances: Array<Instance>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: assert
+Check a value at runtime, and raise an error if the argument provided
+is false.*/
+export type Assert = Record<string, never>;
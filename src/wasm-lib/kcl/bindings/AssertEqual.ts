@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: assertEqual
+Check that a numerical value equals another at runtime,
+otherwise raise an error.*/
+export type AssertEqual = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: assertLessThan
+Check that a numerical value is less than to another at runtime,
+otherwise raise an error.*/
+export type AssertLessThan = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: assertLessThanOrEq
+Check that a numerical value is less than or equal to another at runtime,
+otherwise raise an error.*/
+export type AssertLessThanOrEq = Record<string, never>;
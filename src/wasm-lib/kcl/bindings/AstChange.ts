@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ArgumentChange } from "./ArgumentChange";
+
+/**
+ * A change to a top-level statement between two versions of a program.
+ */
+export type AstChange = { "type": "added", name: string, source: string, } | { "type": "removed", name: string, source: string, } | { "type": "changed", name: string, before: string, after: string, 
+/**
+ * The call arguments that changed, if the statement kept its shape.
+ */
+arguments: Array<ArgumentChange>, };
@@ -0,0 +1,60 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LiteralValue } from "./LiteralValue";
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * A single typed edit to a KCL program.
+ *
+ * Every range refers to the program the transaction is applied to, before any of its edits.
+ * Arguments and edge references are written as KCL source, e.g. `"{ radius: 2, tags: [seg01] }"`
+ * or `"getOppositeEdge(seg02)"`.
+ */
+export type AstEdit = { "type": "insertPipeCall", 
+/**
+ * The pipe (or expression) to insert into.
+ */
+pipe: SourceRange, 
+/**
+ * Where to insert the call. Defaults to the end of the pipe.
+ */
+index: number | null, 
+/**
+ * The name of the function to call.
+ */
+name: string, 
+/**
+ * The arguments of the call, as KCL source.
+ */
+arguments: Array<string>, } | { "type": "addFilletTag", 
+/**
+ * The `fillet` or `chamfer` call.
+ */
+call: SourceRange, 
+/**
+ * The edge reference to add, as KCL source.
+ */
+tag: string, } | { "type": "removeFilletTag", 
+/**
+ * The `fillet` or `chamfer` call.
+ */
+call: SourceRange, 
+/**
+ * The edge reference to remove, as KCL source.
+ */
+tag: string, } | { "type": "setLiteral", 
+/**
+ * The literal to change.
+ */
+range: SourceRange, 
+/**
+ * The new value.
+ */
+value: LiteralValue, } | { "type": "wrapInVariable", 
+/**
+ * The expression to wrap.
+ */
+range: SourceRange, 
+/**
+ * The name of the new constant.
+ */
+name: string, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Program } from "./Program";
+import type { SourceRangeMapping } from "./SourceRangeMapping";
+
+/**
+ * The outcome of an [`AstTransaction`].
+ */
+export type AstEditResult = { 
+/**
+ * The new source code.
+ */
+source: string, 
+/**
+ * The re-parsed program, with source ranges matching `source`.
+ */
+program: Program, 
+/**
+ * For every edit, its target's range before the edit and the range of the edited node after it.
+ */
+sourceMap: Array<SourceRangeMapping>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AstEdit } from "./AstEdit";
+
+/**
+ * A list of edits applied to a program as a unit: either all of them apply, or none do.
+ */
+export type AstTransaction = { edits: Array<AstEdit>, };
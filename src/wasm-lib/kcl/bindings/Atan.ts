@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: atan
+Compute the arctangent of a number (in radians).*/
+export type Atan = Record<string, never>;
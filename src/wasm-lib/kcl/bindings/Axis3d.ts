@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A line through space, for revolving or patterning around.
+ */
+export type Axis3d = { 
+/**
+ * A point on the axis.
+ */
+origin: [number, number, number], 
+/**
+ * The direction of the axis.
+ */
+direction: [number, number, number], };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Axis and origin.
+ */
+export type AxisAndOrigin = "x" | "y" | "negX" | "negY" | { "custom": { 
+/**
+ * The axis.
+ */
+axis: [number, number], 
+/**
+ * The origin.
+ */
+origin: [number, number], } };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Axis3d } from "./Axis3d";
+import type { AxisAndOrigin } from "./AxisAndOrigin";
+import type { EdgeReference } from "./EdgeReference";
+
+/**
+ * Axis or tagged edge.
+ */
+export type AxisOrEdgeReference = AxisAndOrigin | EdgeReference | Axis3d;
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GeoMeta } from "./GeoMeta";
+import type { TagDeclarator } from "./TagDeclarator";
+
+/**
+ * A base path.
+ */
+export type BasePath = { 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, };
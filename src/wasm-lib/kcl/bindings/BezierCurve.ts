@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: bezierCurve
+Draw a smooth, continuous, curved line segment from the current origin to
+the desired (x, y), using a number of control points to shape the curve's shape.*/
+export type BezierCurve = Record<string, never>;
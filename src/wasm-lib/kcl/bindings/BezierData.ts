@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data to draw a bezier curve.
+ */
+export type BezierData = { 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The first control point.
+ */
+control1: [number, number], 
+/**
+ * The second control point.
+ */
+control2: [number, number], };
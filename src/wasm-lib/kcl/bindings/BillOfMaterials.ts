@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BomLine } from "./BomLine";
+
+/**
+ * A bill of materials.
+ */
+export type BillOfMaterials = { 
+/**
+ * One line for each different part, by name, part number and then material.
+ */
+lines: Array<BomLine>, };
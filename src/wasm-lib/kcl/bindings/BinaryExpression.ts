@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinaryOperator } from "./BinaryOperator";
+import type { BinaryPart } from "./BinaryPart";
+
+export type BinaryExpression = { "type": "BinaryExpression", start: number, end: number, operator: BinaryOperator, left: BinaryPart, right: BinaryPart, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
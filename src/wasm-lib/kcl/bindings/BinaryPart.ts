@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BinaryExpression } from "./BinaryExpression";
+import type { CallExpression } from "./CallExpression";
+import type { Identifier } from "./Identifier";
+import type { IfExpression } from "./IfExpression";
+import type { Literal } from "./Literal";
+import type { MemberExpression } from "./MemberExpression";
+import type { UnaryExpression } from "./UnaryExpression";
+
+export type BinaryPart = { "type": "Literal" } & Literal | { "type": "Identifier" } & Identifier | { "type": "BinaryExpression" } & BinaryExpression | { "type": "CallExpression" } & CallExpression | { "type": "UnaryExpression" } & UnaryExpression | { "type": "MemberExpression" } & MemberExpression | { "type": "IfExpression" } & IfExpression;
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExpressionStatement } from "./ExpressionStatement";
+import type { ImportStatement } from "./ImportStatement";
+import type { ReturnStatement } from "./ReturnStatement";
+import type { VariableDeclaration } from "./VariableDeclaration";
+
+export type BodyItem = { "type": "ImportStatement" } & ImportStatement | { "type": "ExpressionStatement" } & ExpressionStatement | { "type": "VariableDeclaration" } & VariableDeclaration | { "type": "ReturnStatement" } & ReturnStatement;
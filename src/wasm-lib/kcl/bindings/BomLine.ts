@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A line of a bill of materials.
+ */
+export type BomLine = { 
+/**
+ * The name of the part.
+ */
+name: string, 
+/**
+ * The part number.
+ */
+partNumber: string | null, 
+/**
+ * The material the part is made of.
+ */
+material: string | null, 
+/**
+ * How many of the part there are.
+ */
+quantity: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: boundingBox
+Get the smallest box lined up with the global axes which an extruded
+solid fits in, as `{ min: [x, y, z], max: [x, y, z] }`. Revolved and lofted solids, copies made by patterns and solids which have had features added to them aren't supported.*/
+export type BoundingBox = Record<string, never>;
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The smallest box, lined up with the global axes, which a solid fits in.
+ */
+export type BoundingBox3d = { 
+/**
+ * The corner with the smallest X, Y and Z.
+ */
+min: [number, number, number], 
+/**
+ * The corner with the largest X, Y and Z.
+ */
+max: [number, number, number], };
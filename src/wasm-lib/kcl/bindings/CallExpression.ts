@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expr } from "./Expr";
+import type { Identifier } from "./Identifier";
+
+export type CallExpression = { "type": "CallExpression", start: number, end: number, callee: Identifier, arguments: Array<Expr>, optional: boolean, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CapSubType } from "./CapSubType";
+
+/**
+ * The start or end face of a solid.
+ */
+export type CapArtifact = { id: string, subType: CapSubType, 
+/**
+ * The path whose sweep made it.
+ */
+sweepId: string, 
+/**
+ * The paths sketched on it.
+ */
+pathIds: Array<string>, };
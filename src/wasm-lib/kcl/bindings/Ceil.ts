@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: ceil
+Compute the smallest integer greater than or equal to a number.*/
+export type Ceil = Record<string, never>;
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: chamfer
+Cut a straight transitional edge along a tagged path.
+Chamfer is similar in function and use to a fillet, except a fillet will blend the transition along an edge, rather than cut a sharp, straight transitional edge.
+
+The engine cuts the same length back along both faces of the edge, so chamfers with a different distance on each face, or a distance and an angle, can't be made yet.*/
+export type Chamfer = Record<string, never>;
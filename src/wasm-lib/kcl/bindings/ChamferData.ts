@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EdgeReference } from "./EdgeReference";
+
+/**
+ * Data for chamfers.
+ */
+export type ChamferData = { 
+/**
+ * The length of the chamfer.
+ */
+length: number, 
+/**
+ * The tags of the paths you want to chamfer.
+ */
+tags: Array<EdgeReference>, };
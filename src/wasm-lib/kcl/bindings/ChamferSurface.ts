@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+import type { TagDeclarator } from "./TagDeclarator";
+
+export type ChamferSurface = { 
+/**
+ * The id for the chamfer surface.
+ */
+faceId: string, 
+/**
+ * The tag.
+ */
+tag: TagDeclarator | null, 
+/**
+ * The id of the geometry.
+ */
+id: string, 
+/**
+ * The source range.
+ */
+sourceRange: SourceRange, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: circle
+Construct a 2-dimensional circle, of the specified radius, centered at
+the provided (x, y) origin point.*/
+export type Circle = Record<string, never>;
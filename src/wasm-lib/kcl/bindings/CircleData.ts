@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data for drawing an circle
+ */
+export type CircleData = { 
+/**
+ * The center of the circle.
+ */
+center: [number, number], 
+/**
+ * The circle radius
+ */
+radius: number, };
@@ -0,0 +1,37 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatternAxis } from "./PatternAxis";
+import type { Uint } from "./Uint";
+
+/**
+ * Data for a circular pattern on a 3D model.
+ */
+export type CircularPattern3dData = { 
+/**
+ * The number of total instances. Must be greater than or equal to 1.
+ * This includes the original entity. For example, if instances is 2,
+ * there will be two copies -- the original, and one new copy.
+ * If instances is 1, this has no effect.
+ */
+instances: Uint, 
+/**
+ * The axis around which to make the pattern. This is a 3D vector, or a datum axis.
+ */
+axis: PatternAxis, 
+/**
+ * The center about which to make the pattern. This is a 3D vector. It defaults to the
+ * origin of a datum axis, or the global origin for an axis which is a vector.
+ */
+center: [number, number, number] | null, 
+/**
+ * The arc angle (in degrees) to place the repetitions. Must be greater than 0.
+ */
+arcDegrees: number, 
+/**
+ * Whether or not to rotate the duplicates as they are copied.
+ */
+rotateDuplicates: boolean, 
+/**
+ * Indices of instances to leave out of the pattern. Index 0 is the
+ * original entity, which cannot be skipped.
+ */
+skip: Array<Uint> | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: close
+Construct a line segment from the current origin back to the profile's
+origin, ensuring the resulting 2-dimensional sketch is not open-ended.*/
+export type Close = Record<string, never>;
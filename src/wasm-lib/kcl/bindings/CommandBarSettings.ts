@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DefaultTrue } from "./DefaultTrue";
+
+/**
+ * Settings that affect the behavior of the command bar.
+ */
+export type CommandBarSettings = { 
+/**
+ * Whether to include settings in the command bar.
+ */
+include_settings: DefaultTrue, };
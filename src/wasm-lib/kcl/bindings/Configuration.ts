@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Settings } from "./Settings";
+
+/**
+ * High level configuration.
+ */
+export type Configuration = { 
+/**
+ * The settings for the modeling app.
+ */
+settings: Settings, };
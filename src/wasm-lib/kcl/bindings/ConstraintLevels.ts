@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ConstraintLevel } from "./ConstraintLevel";
+
+/**
+ * A vector of constraint levels.
+ */
+export type ConstraintLevels = Array<ConstraintLevel>;
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Position in a text document expressed as zero-based line and character offset.
+ * A position is between two characters like an 'insert' cursor in a editor.
+ */
+export type CopilotPosition = { 
+/**
+ * Line position in a document (zero-based).
+ */
+line: number, 
+/**
+ * Character offset on a line in a document (zero-based). The meaning of this
+ * offset is determined by the negotiated `PositionEncodingKind`.
+ *
+ * If the character value is greater than the line length it defaults back
+ * to the line length.
+ */
+character: number, };
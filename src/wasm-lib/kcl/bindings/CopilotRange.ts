@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CopilotPosition } from "./CopilotPosition";
+
+/**
+ * A range in a text document expressed as (zero-based) start and end positions.
+ * A range is comparable to a selection in an editor. Therefore the end position is exclusive.
+ */
+export type CopilotRange = { 
+/**
+ * The range's start position.
+ */
+start: CopilotPosition, 
+/**
+ * The range's end position.
+ */
+end: CopilotPosition, };
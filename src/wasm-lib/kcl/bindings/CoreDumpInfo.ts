@@ -0,0 +1,54 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+import type { OsInfo } from "./OsInfo";
+import type { WebrtcStats } from "./WebrtcStats";
+
+/**
+ * The app info structure.
+ * The Core Dump Info structure.
+ */
+export type CoreDumpInfo = { 
+/**
+ * The unique id for the core dump - this helps correlate uploaded files with coredump data.
+ */
+id: string, 
+/**
+ * The version of the app.
+ */
+version: string, 
+/**
+ * The git revision of the app.
+ */
+git_rev: string, 
+/**
+ * A timestamp of the core dump.
+ */
+timestamp: string, 
+/**
+ * If the app is running in desktop or the browser.
+ */
+desktop: boolean, 
+/**
+ * The os info.
+ */
+os: OsInfo, 
+/**
+ * The webrtc stats.
+ */
+webrtc_stats: WebrtcStats, 
+/**
+ * A GitHub issue url to report the core dump.
+ */
+github_issue_url: string | null, 
+/**
+ * The kcl code the user is using.
+ */
+kcl_code: string, 
+/**
+ * Engine pool the client is connected to.
+ */
+pool: string, 
+/**
+ * The client state (singletons and xstate).
+ */
+client_state: JsonValue, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The recess of a counterbored hole.
+ */
+export type Counterbore = { 
+/**
+ * The diameter of the counterbore.
+ */
+diameter: number, 
+/**
+ * The depth of the counterbore.
+ */
+depth: number, };
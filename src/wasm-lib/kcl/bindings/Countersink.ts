@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The cone of a countersunk hole.
+ */
+export type Countersink = { 
+/**
+ * The outer diameter of the countersink.
+ */
+diameter: number, 
+/**
+ * The included angle of the countersink, in degrees.
+ */
+angle: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: datumAxis
+Make a datum axis, either through two points or through a point in a
+direction.
+
+The axis is an object with the `origin` and the unit length `direction` of the line, which `revolve` takes as its `axis` and `planeAtAngle` turns planes about.*/
+export type DatumAxis = Record<string, never>;
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Axis3d } from "./Axis3d";
+
+/**
+ * Data for a datum axis.
+ */
+export type DatumAxisData = { 
+/**
+ * The two points.
+ */
+through: [[number, number, number], [number, number, number]], } | Axis3d;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: datumPoint
+Make a datum point, either from global coordinates or from a position
+on a plane. The point is an `[x, y, z]` array, so it can be used anywhere a 3D point is expected, such as the `center` of `patternCircular3d` or as one of the points of `planeThroughPoints`.*/
+export type DatumPoint = Record<string, never>;
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlaneData } from "./PlaneData";
+
+/**
+ * Data for a datum point.
+ */
+export type DatumPointData = [number, number, number] | { 
+/**
+ * The plane.
+ */
+plane: PlaneData, 
+/**
+ * Where on the plane, along its X and Y axes.
+ */
+at: [number, number], };
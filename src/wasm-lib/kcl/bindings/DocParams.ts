@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CopilotPosition } from "./CopilotPosition";
+
+export type DocParams = { uri: string, pos: CopilotPosition, language: string, lineBefore: string, prefix: string, suffix: string, };
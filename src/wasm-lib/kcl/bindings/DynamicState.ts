@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SolidLazyIds } from "./SolidLazyIds";
+
+/**
+ * Dynamic state that depends on the dynamic flow of the program, like the call
+ * stack.  If the language had exceptions, for example, you could store the
+ * stack of exception handlers here.
+ */
+export type DynamicState = { solid_ids: Array<SolidLazyIds>, };
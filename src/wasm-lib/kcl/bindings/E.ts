@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: e
+Return the value of Euler’s number `e`.*/
+export type E = Record<string, never>;
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EdgeSubType } from "./EdgeSubType";
+
+/**
+ * An edge of a solid which was looked up from a segment.
+ */
+export type EdgeArtifact = { id: string, segId: string, solidId: string, subType: EdgeSubType, };
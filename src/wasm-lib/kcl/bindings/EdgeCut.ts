@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TagDeclarator } from "./TagDeclarator";
+
+/**
+ * A fillet or a chamfer.
+ */
+export type EdgeCut = { "type": "fillet", 
+/**
+ * The id of the engine command that called this fillet.
+ */
+id: string, radius: number, 
+/**
+ * The engine id of the edge to fillet.
+ */
+edgeId: string, tag: TagDeclarator | null, } | { "type": "chamfer", 
+/**
+ * The id of the engine command that called this chamfer.
+ */
+id: string, length: number, 
+/**
+ * The engine id of the edge to chamfer.
+ */
+edgeId: string, tag: TagDeclarator | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EdgeCutSubType } from "./EdgeCutSubType";
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * A fillet or chamfer. If it was tagged, its id is also the id of the face it made.
+ */
+export type EdgeCutArtifact = { id: string, subType: EdgeCutSubType, 
+/**
+ * The edge it replaced.
+ */
+consumedEdgeId: string, solidId: string, sourceRange: SourceRange, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: edgeLength
+Get the length of an edge of a solid, given by the tag of a segment
+of its profile, for the edge on the face the solid was sketched on, or by the edge's id, such as those `edges` finds.
+
+Solids which are still just an extruded profile are measured from it. Solids which have been filleted, chamfered, shelled or had holes or other features added are measured by the engine, which can only measure their straight edges.*/
+export type EdgeLength = Record<string, never>;
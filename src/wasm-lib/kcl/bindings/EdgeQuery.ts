@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FaceTag } from "./FaceTag";
+import type { QueryDirection } from "./QueryDirection";
+
+/**
+ * Which edges of a solid to find.
+ */
+export type EdgeQuery = { 
+/**
+ * Only straight edges parallel to this direction, either way along it.
+ */
+parallelTo: QueryDirection | null, 
+/**
+ * Only edges around this face.
+ */
+onFace: FaceTag | null, };
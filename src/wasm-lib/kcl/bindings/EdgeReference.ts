@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TagIdentifier } from "./TagIdentifier";
+
+/**
+ * A tag or a uuid of an edge.
+ */
+export type EdgeReference = string | TagIdentifier;
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: edges
+Find the edges of an extruded solid which match a query, instead of
+tagging them. The edges can be filleted, chamfered or used anywhere else an edge can.
+
+Without a query, every edge of the solid is found. `parallelTo` keeps the straight edges parallel to an axis (`'X'`, `'Y'` or `'Z'`) or a direction, and `onFace` keeps the edges around a face.
+
+Solids which have had fillets, chamfers, holes, shells or other features added to them can't be queried, so find edges before adding those.*/
+export type Edges = Record<string, never>;
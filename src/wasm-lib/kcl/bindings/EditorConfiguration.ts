@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LanguageEntry } from "./LanguageEntry";
+
+export type EditorConfiguration = { disabledLanguages: Array<LanguageEntry>, enableAutoCompletions: boolean, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expr } from "./Expr";
+import type { Program } from "./Program";
+
+export type ElseIf = { "type": "ElseIf", start: number, end: number, cond: Expr, then_val: Program, digest: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number] | null, };
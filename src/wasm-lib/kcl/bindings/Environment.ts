@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EnvironmentRef } from "./EnvironmentRef";
+import type { KclValue } from "./KclValue";
+
+export type Environment = { bindings: { [key in string]?: KclValue }, parent: EnvironmentRef | null, };
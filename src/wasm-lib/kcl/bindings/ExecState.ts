@@ -0,0 +1,67 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ArtifactGraph } from "./ArtifactGraph";
+import type { Assembly } from "./Assembly";
+import type { DynamicState } from "./DynamicState";
+import type { IdGenerator } from "./IdGenerator";
+import type { KclValue } from "./KclValue";
+import type { Parts } from "./Parts";
+import type { ProgramMemory } from "./ProgramMemory";
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * State for executing a program.
+ */
+export type ExecState = { 
+/**
+ * Program variable bindings.
+ */
+memory: ProgramMemory, 
+/**
+ * The stable artifact ID generator.
+ */
+idGenerator: IdGenerator, 
+/**
+ * Dynamic state that follows dynamic flow of the program.
+ */
+dynamicState: DynamicState, 
+/**
+ * The current value of the pipe operator returned from the previous
+ * expression.  If we're not currently in a pipeline, this will be None.
+ */
+pipeValue: KclValue | null, 
+/**
+ * Identifiers that have been exported from the current module.
+ */
+moduleExports: Array<string>, 
+/**
+ * The stack of import statements for detecting circular module imports.
+ * If this is empty, we're not currently executing an import statement.
+ */
+importStack: Array<string>, 
+/**
+ * While a function from another file runs, the call in this file which ran it.
+ * Geometry the function makes is recorded against it in the artifact graph.
+ */
+importedSourceRange: SourceRange | null, 
+/**
+ * The directory of the current project.  This is used for resolving import
+ * paths.  If None is given, the current working directory is used.
+ */
+projectDirectory: string | null, 
+/**
+ * The parts imported and the tree of their instances.
+ */
+assembly: Assembly, 
+/**
+ * The geometry the program made and the code which made it.
+ */
+artifactGraph: ArtifactGraph, 
+/**
+ * The parts the program made, for its bill of materials.
+ */
+parts: Parts, 
+/**
+ * Solids which are a profile sketched on a plane and extruded, with nothing cut from or
+ * added to them since, so they can be measured from their profile.
+ */
+plainExtrusions: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The mode of execution.  When isolated, like during an import, attempting to
+ * send a command results in an error.
+ */
+export type ExecutionKind = "normal" | "isolated";
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ArrayExpression } from "./ArrayExpression";
+import type { ArrayRangeExpression } from "./ArrayRangeExpression";
+import type { BinaryExpression } from "./BinaryExpression";
+import type { CallExpression } from "./CallExpression";
+import type { FunctionExpression } from "./FunctionExpression";
+import type { Identifier } from "./Identifier";
+import type { IfExpression } from "./IfExpression";
+import type { KclNone } from "./KclNone";
+import type { Literal } from "./Literal";
+import type { MemberExpression } from "./MemberExpression";
+import type { ObjectExpression } from "./ObjectExpression";
+import type { PipeExpression } from "./PipeExpression";
+import type { PipeSubstitution } from "./PipeSubstitution";
+import type { TagDeclarator } from "./TagDeclarator";
+import type { UnaryExpression } from "./UnaryExpression";
+
+/**
+ * An expression can be evaluated to yield a single KCL value.
+ */
+export type Expr = { "type": "Literal" } & Literal | { "type": "Identifier" } & Identifier | { "type": "TagDeclarator" } & TagDeclarator | { "type": "BinaryExpression" } & BinaryExpression | { "type": "FunctionExpression" } & FunctionExpression | { "type": "CallExpression" } & CallExpression | { "type": "PipeExpression" } & PipeExpression | { "type": "PipeSubstitution" } & PipeSubstitution | { "type": "ArrayExpression" } & ArrayExpression | { "type": "ArrayRangeExpression" } & ArrayRangeExpression | { "type": "ObjectExpression" } & ObjectExpression | { "type": "MemberExpression" } & MemberExpression | { "type": "UnaryExpression" } & UnaryExpression | { "type": "IfExpression" } & IfExpression | { "type": "None" } & KclNone;
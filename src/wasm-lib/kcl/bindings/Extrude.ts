@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: extrude
+Extend a 2-dimensional sketch through a third dimension in order to
+create new 3-dimensional volume, or if extruded into an existing volume, cut into an existing solid.*/
+export type Extrude = Record<string, never>;
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+import type { TagDeclarator } from "./TagDeclarator";
+
+/**
+ * An extruded plane.
+ */
+export type ExtrudePlane = { 
+/**
+ * The face id for the extrude plane.
+ */
+faceId: string, 
+/**
+ * The tag.
+ */
+tag: TagDeclarator | null, 
+/**
+ * The id of the geometry.
+ */
+id: string, 
+/**
+ * The source range.
+ */
+sourceRange: SourceRange, };
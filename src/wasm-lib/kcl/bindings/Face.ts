@@ -0,0 +1,33 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Metadata } from "./Metadata";
+import type { Point3d } from "./Point3d";
+import type { Solid } from "./Solid";
+
+/**
+ * A face.
+ */
+export type Face = { 
+/**
+ * The id of the face.
+ */
+id: string, 
+/**
+ * The tag of the face.
+ */
+value: string, 
+/**
+ * What should the face’s X axis be?
+ */
+xAxis: Point3d, 
+/**
+ * What should the face’s Y axis be?
+ */
+yAxis: Point3d, 
+/**
+ * The z-axis (normal).
+ */
+zAxis: Point3d, 
+/**
+ * The solid the face is on.
+ */
+solid: Solid, __meta: Array<Metadata>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: faceArea
+Get the area of a face of a solid, given by the face's tag or
+`'start'`/`'end'`. Faces of solids which have had fillets, chamfers, holes, shells or other features added to them are measured by the engine.*/
+export type FaceArea = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: faceNormal
+Get the unit normal of a planar face of a solid, pointing out of the
+solid, as `[x, y, z]`. Faces of solids which have had features added to them are measured by the engine.*/
+export type FaceNormal = Record<string, never>;
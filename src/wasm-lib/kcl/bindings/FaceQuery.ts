@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { QueryDirection } from "./QueryDirection";
+
+/**
+ * Which faces of a solid to find.
+ */
+export type FaceQuery = { 
+/**
+ * Only planar faces whose outward normal points this way.
+ */
+normal: QueryDirection | null, 
+/**
+ * Only the largest of the faces which match, by area.
+ */
+largest: boolean, 
+/**
+ * Only the smallest of the faces which match, by area.
+ */
+smallest: boolean, };
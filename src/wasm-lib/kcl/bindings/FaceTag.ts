@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StartOrEnd } from "./StartOrEnd";
+import type { TagIdentifier } from "./TagIdentifier";
+
+/**
+ * A tag for a face.
+ */
+export type FaceTag = StartOrEnd | TagIdentifier | string;
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: faces
+Find the faces of an extruded solid which match a query, instead of
+tagging them. The faces can be sketched on, shelled or used anywhere else a face can.
+
+Without a query, every face of the solid is found: the start and end faces, then the side faces. `normal` keeps the planar faces facing an axis (`'X'`, `'-Z'` and so on) or a direction, and `largest` or `smallest` keeps only the biggest or smallest of those by area.
+
+Solids which have had fillets, chamfers, holes, shells or other features added to them can't be queried.*/
+export type Faces = Record<string, never>;
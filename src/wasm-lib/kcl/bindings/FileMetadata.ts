@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What telemetry knows about a file at `metadata_only`.
+ */
+export type FileMetadata = { 
+/**
+ * A hash of the file path, so files can be told apart without naming them.
+ */
+path_hash: string, 
+/**
+ * The number of lines in the file.
+ */
+lines: number, 
+/**
+ * The number of top-level statements, if the file parses.
+ */
+statements: number | null, 
+/**
+ * How many times each function is called.
+ */
+calls: { [key in string]?: number }, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: fillet
+Blend a transitional edge along a tagged path, smoothing the sharp edge.
+Fillet is similar in function and use to a chamfer, except a chamfer will cut a sharp transition along an edge while fillet will smoothly blend the transition.
+
+The engine rounds an edge with one radius along its whole length, so fillets whose radius varies along the edge, and setbacks where filleted edges meet at a corner, can't be made yet.*/
+export type Fillet = Record<string, never>;
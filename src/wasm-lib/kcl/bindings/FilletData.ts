@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EdgeReference } from "./EdgeReference";
+
+/**
+ * Data for fillets.
+ */
+export type FilletData = { 
+/**
+ * The radius of the fillet.
+ */
+radius: number, 
+/**
+ * The tags of the paths you want to fillet.
+ */
+tags: Array<EdgeReference>, 
+/**
+ * The tolerance for the fillet.
+ */
+tolerance: number | null, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+import type { TagDeclarator } from "./TagDeclarator";
+
+export type FilletSurface = { 
+/**
+ * The id for the fillet surface.
+ */
+faceId: string, 
+/**
+ * The tag.
+ */
+tag: TagDeclarator | null, 
+/**
+ * The id of the geometry.
+ */
+id: string, 
+/**
+ * The source range.
+ */
+sourceRange: SourceRange, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: floor
+Compute the largest integer less than or equal to a number.*/
+export type Floor = Record<string, never>;
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StdLibFnData } from "./StdLibFnData";
+
+/**
+ * A function declaration.
+ */
+export type Function = { "type": "StdLib", 
+/**
+ * The function.
+ */
+func: StdLibFnData, } | { "type": "StdLibKcl", 
+/**
+ * The function.
+ */
+func: StdLibFnData, } | { "type": "InMemory" };
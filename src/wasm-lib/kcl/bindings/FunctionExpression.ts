@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Parameter } from "./Parameter";
+import type { Program } from "./Program";
+
+export type FunctionExpression = { "type": "FunctionExpression", start: number, end: number, params: Array<Parameter>, body: Program, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
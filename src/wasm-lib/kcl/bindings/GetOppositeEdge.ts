@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: getOppositeEdge
+Get the opposite edge to the edge given.*/
+export type GetOppositeEdge = Record<string, never>;
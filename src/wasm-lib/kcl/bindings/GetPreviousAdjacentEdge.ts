@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: getPreviousAdjacentEdge
+Get the previous adjacent edge to the edge given.*/
+export type GetPreviousAdjacentEdge = Record<string, never>;
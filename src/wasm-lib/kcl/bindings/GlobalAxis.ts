@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A global axis.
+ */
+export type GlobalAxis = "X" | "Y" | "Z" | "NegX" | "NegY" | "NegZ";
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: helix
+Create a helix on a cylinder.*/
+export type Helix = Record<string, never>;
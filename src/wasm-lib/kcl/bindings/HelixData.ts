@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data for helices.
+ */
+export type HelixData = { 
+/**
+ * Number of revolutions.
+ */
+revolutions: number, 
+/**
+ * Start angle (in degrees).
+ */
+angle_start: number, 
+/**
+ * Is the helix rotation counter clockwise?
+ * The default is `false`.
+ */
+ccw: boolean, 
+/**
+ * Length of the helix. If this argument is not provided, the height of
+ * the solid is used.
+ */
+length: number | null, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: hole
+Use a 2-dimensional sketch to cut a hole in another 2-dimensional sketch.*/
+export type Hole = Record<string, never>;
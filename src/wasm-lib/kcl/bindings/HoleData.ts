@@ -0,0 +1,49 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HoleType } from "./HoleType";
+
+/**
+ * Data for a hole feature.
+ */
+export type HoleData = { 
+/**
+ * The kind of hole to cut. Defaults to a simple hole.
+ */
+type: HoleType, 
+/**
+ * A standard fastener size, e.g. `M6` or `1/4-20`. Used to look up
+ * any dimensions which are not given explicitly.
+ */
+size: string | null, 
+/**
+ * The diameter of the hole. Defaults to the clearance diameter of
+ * `size`, or the tap drill diameter for tapped holes.
+ */
+diameter: number | null, 
+/**
+ * How deep to cut the hole, measured from the face.
+ */
+depth: number, 
+/**
+ * Where to put the holes, in the coordinate system of the face.
+ */
+positions: Array<[number, number]>, 
+/**
+ * The diameter of the counterbore.
+ */
+counterboreDiameter: number | null, 
+/**
+ * The depth of the counterbore.
+ */
+counterboreDepth: number | null, 
+/**
+ * The outer diameter of the countersink.
+ */
+countersinkDiameter: number | null, 
+/**
+ * The included angle of the countersink, in degrees. Only 90 can be cut for now.
+ */
+countersinkAngle: number | null, 
+/**
+ * How deep the thread of a tapped hole runs. Defaults to the hole depth.
+ */
+threadDepth: number | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: holeFeature
+Cut simple, counterbored, countersunk or tapped holes into a face of a solid.
+Dimensions can be given explicitly, or looked up from a standard fastener `size` such as `M6` (ISO metric coarse) or `1/4-20` (unified coarse). Looked up dimensions are converted to the project units. Every hole is recorded on the returned solid, and tapped holes carry their thread designation, pitch and major diameter.
+
+Countersinks are cut as a chamfer on the edge of the hole, so only 90° countersinks can be cut for now. The size tables only have countersinks for metric sizes, as unified flat heads need an 82° countersink.*/
+export type HoleFeature = Record<string, never>;
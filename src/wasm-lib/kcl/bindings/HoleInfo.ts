@@ -0,0 +1,46 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Counterbore } from "./Counterbore";
+import type { Countersink } from "./Countersink";
+import type { HoleType } from "./HoleType";
+import type { ThreadInfo } from "./ThreadInfo";
+
+/**
+ * A hole cut into a solid by `holeFeature`.
+ */
+export type HoleInfo = { 
+/**
+ * The id of the hole's circle sketch, which the cut extruded from it shares.
+ */
+id: string, 
+/**
+ * The id of the face the hole was cut into.
+ */
+faceId: string, 
+/**
+ * The kind of hole.
+ */
+type: HoleType, 
+/**
+ * The position of the hole on the face.
+ */
+position: [number, number], 
+/**
+ * The diameter of the hole.
+ */
+diameter: number, 
+/**
+ * The depth of the hole.
+ */
+depth: number, 
+/**
+ * The counterbore, if any.
+ */
+counterbore: Counterbore | null, 
+/**
+ * The countersink, if any.
+ */
+countersink: Countersink | null, 
+/**
+ * The thread of a tapped hole.
+ */
+thread: ThreadInfo | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: hollow
+Make the inside of a 3D object hollow.
+Remove volume from a 3-dimensional shape such that a wall of the provided thickness remains around the exterior of the shape.*/
+export type Hollow = Record<string, never>;
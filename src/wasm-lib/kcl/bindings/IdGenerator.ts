@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A generator for ArtifactIds that are stable across executions.
+ *
+ * Ids are made from the statement being executed rather than from how many ids came
+ * before: each is a hash of the statement's key (the name it declares, or its position
+ * in its body if it doesn't declare one), the call path it was reached by (the keys of
+ * the statements that called the function it's in) and how many ids the statement has
+ * made so far. Editing one statement, or adding a declaration, doesn't change the ids
+ * made by the others, and a program makes the same ids on every machine.
+ *
+ * They're UUIDv8s, as RFC 9562 suggests for names hashed with SHA-256.
+ */
+export type IdGenerator = { 
+/**
+ * How many ids have been made in the current scope.
+ */
+nextId: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ElseIf } from "./ElseIf";
+import type { Expr } from "./Expr";
+import type { Program } from "./Program";
+
+export type IfExpression = { "type": "IfExpression", start: number, end: number, cond: Expr, then_val: Program, else_ifs: Array<ElseIf>, final_else: Program, digest: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number] | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Identifier } from "./Identifier";
+
+export type ImportItem = { "type": "ImportItem", 
+/**
+ * Name of the item to import.
+ */
+name: Identifier, 
+/**
+ * Rename the item using an identifier after "as".
+ */
+alias: Identifier | null, start: number, end: number, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
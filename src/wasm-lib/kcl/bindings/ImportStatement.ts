@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ImportItem } from "./ImportItem";
+
+export type ImportStatement = { "type": "ImportStatement", start: number, end: number, items: Array<ImportItem>, path: string, raw_path: string, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
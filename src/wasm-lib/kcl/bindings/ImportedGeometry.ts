@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppearanceData } from "./AppearanceData";
+import type { MaterialData } from "./MaterialData";
+import type { Metadata } from "./Metadata";
+import type { PartMetadata } from "./PartMetadata";
+import type { Transform3d } from "./Transform3d";
+
+/**
+ * Data for an imported geometry.
+ */
+export type ImportedGeometry = { 
+/**
+ * The ID of the imported geometry.
+ */
+id: string, 
+/**
+ * The original file paths.
+ */
+value: Array<string>, 
+/**
+ * Where the geometry has been moved to since it was imported.
+ */
+transform: Transform3d, 
+/**
+ * Which part the geometry is, for bills of materials, set by `partInfo`.
+ */
+part: PartMetadata | null, 
+/**
+ * How the geometry looks, set by `appearance`.
+ */
+appearance: AppearanceData | null, 
+/**
+ * What the geometry is made of, set by `material`.
+ */
+material: MaterialData | null, __meta: Array<Metadata>, };
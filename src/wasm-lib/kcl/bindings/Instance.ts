@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Metadata } from "./Metadata";
+import type { Transform3d } from "./Transform3d";
+
+/**
+ * A part built and put in place.
+ */
+export type Instance = { 
+/**
+ * The name of the instance, unique among its siblings.
+ */
+name: string, 
+/**
+ * The name of the part it is an instance of.
+ */
+part: string, 
+/**
+ * The ids of the solids or imported geometry the part was built as.
+ */
+ids: Array<string>, 
+/**
+ * Where the instance was moved to from where the part builds it.
+ */
+transform: Transform3d, 
+/**
+ * The instances made while building the part, if it is a subassembly.
+ */
+children: Array<Instance>, __meta: Array<Metadata>, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Mate } from "./Mate";
+
+/**
+ * Data for an instance of a part.
+ */
+export type InstanceData = { 
+/**
+ * The name of the instance, unique among its siblings. Defaults to the part's name and a number.
+ */
+name: string | null, 
+/**
+ * A 4x4 transform, as rows, to move the part by from where it builds it.
+ */
+transform: [[number, number, number, number], [number, number, number, number], [number, number, number, number], [number, number, number, number]] | null, 
+/**
+ * How to put the instance in place relative to other geometry, after it's been moved by
+ * `transform`.
+ */
+mate: Mate | null, };
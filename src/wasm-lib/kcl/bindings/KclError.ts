@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { KclErrorDetails } from "./KclErrorDetails";
+
+export type KclError = { "kind": "lexical" } & KclErrorDetails | { "kind": "syntax" } & KclErrorDetails | { "kind": "semantic" } & KclErrorDetails | { "kind": "import_cycle" } & KclErrorDetails | { "kind": "type" } & KclErrorDetails | { "kind": "unimplemented" } & KclErrorDetails | { "kind": "unexpected" } & KclErrorDetails | { "kind": "value_already_defined" } & KclErrorDetails | { "kind": "undefined_value" } & KclErrorDetails | { "kind": "invalid_expression" } & KclErrorDetails | { "kind": "engine" } & KclErrorDetails | { "kind": "internal" } & KclErrorDetails;
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * KCL value for an optional parameter which was not given an argument.
+ * (remember, parameters are in the function declaration,
+ * arguments are in the function call/application).
+ */
+export type KclNone = { "type": "KclNone", start: number, end: number, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Face } from "./Face";
+import type { ImportedGeometry } from "./ImportedGeometry";
+import type { Plane } from "./Plane";
+import type { Solid } from "./Solid";
+import type { TagDeclarator } from "./TagDeclarator";
+import type { TagIdentifier } from "./TagIdentifier";
+import type { UserVal } from "./UserVal";
+
+/**
+ * Any KCL value.
+ */
+export type KclValue = { "type": "UserVal" } & UserVal | { "type": "TagIdentifier" } & TagIdentifier | { "type": "TagDeclarator" } & TagDeclarator | { "type": "Plane" } & Plane | { "type": "Face" } & Face | { "type": "Solid" } & Solid | { "type": "Solids", value: Array<Solid>, } | { "type": "ImportedGeometry" } & ImportedGeometry;
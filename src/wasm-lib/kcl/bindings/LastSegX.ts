@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: lastSegX
+Extract the 'x' axis value of the last line segment in the provided 2-d
+sketch.*/
+export type LastSegX = Record<string, never>;
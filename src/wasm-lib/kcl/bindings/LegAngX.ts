@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: legAngX
+Compute the angle of the given leg for x.*/
+export type LegAngX = Record<string, never>;
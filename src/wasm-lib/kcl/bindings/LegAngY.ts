@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: legAngY
+Compute the angle of the given leg for y.*/
+export type LegAngY = Record<string, never>;
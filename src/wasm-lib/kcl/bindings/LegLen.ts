@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: legLen
+Compute the length of the given leg.*/
+export type LegLen = Record<string, never>;
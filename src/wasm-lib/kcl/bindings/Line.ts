@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: line
+Draw a line relative to the current origin to a specified (x, y) away
+from the current position.*/
+export type Line = Record<string, never>;
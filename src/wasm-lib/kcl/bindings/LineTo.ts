@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: lineTo
+Draw a line from the current origin to some absolute (x, y) point.*/
+export type LineTo = Record<string, never>;
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Uint } from "./Uint";
+
+/**
+ * Data for a linear pattern on a 2D sketch.
+ */
+export type LinearPattern2dData = { 
+/**
+ * The number of total instances. Must be greater than or equal to 1.
+ * This includes the original entity. For example, if instances is 2,
+ * there will be two copies -- the original, and one new copy.
+ * If instances is 1, this has no effect.
+ */
+instances: Uint, 
+/**
+ * The distance between each repetition. This can also be referred to as spacing.
+ */
+distance: number, 
+/**
+ * The axis of the pattern. This is a 2D vector.
+ */
+axis: [number, number], 
+/**
+ * Indices of instances to leave out of the pattern. Index 0 is the
+ * original entity, which cannot be skipped.
+ */
+skip: Array<Uint> | null, };
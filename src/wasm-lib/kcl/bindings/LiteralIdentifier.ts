@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Identifier } from "./Identifier";
+import type { Literal } from "./Literal";
+
+export type LiteralIdentifier = { "type": "Identifier" } & Identifier | { "type": "Literal" } & Literal;
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: ln
+Compute the natural logarithm of the number.*/
+export type Ln = Record<string, never>;
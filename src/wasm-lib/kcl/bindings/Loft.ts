@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: loft
+Create a 3D surface or solid by interpolating between two or more sketches.
+The sketches need to closed and on the same plane.*/
+export type Loft = Record<string, never>;
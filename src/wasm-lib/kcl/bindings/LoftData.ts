@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data for a loft.
+ */
+export type LoftData = { 
+/**
+ * Degree of the interpolation. Must be greater than zero.
+ * For example, use 2 for quadratic, or 3 for cubic interpolation in the V direction.
+ * This defaults to 2, if not specified.
+ */
+vDegree: number | null, 
+/**
+ * Attempt to approximate rational curves (such as arcs) using a bezier.
+ * This will remove banding around interpolations between arcs and non-arcs.  It may produce errors in other scenarios
+ * Over time, this field won't be necessary.
+ */
+bezApproximateRational: boolean | null, 
+/**
+ * This can be set to override the automatically determined topological base curve, which is usually the first section encountered.
+ */
+baseCurveIndex: number | null, 
+/**
+ * Tolerance for the loft operation.
+ */
+tolerance: number | null, };
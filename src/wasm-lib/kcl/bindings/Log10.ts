@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: log10
+Compute the base 10 logarithm of the number.*/
+export type Log10 = Record<string, never>;
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: log2
+Compute the base 2 logarithm of the number.*/
+export type Log2 = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: map
+Apply a function to every element of a list.
+Given a list like `[a, b, c]`, and a function like `f`, returns `[f(a), f(b), f(c)]`*/
+export type Map = Record<string, never>;
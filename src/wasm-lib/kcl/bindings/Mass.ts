@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: mass
+Get the mass of solids, in kilograms, from their volume and the density
+of the material they were given with `material`. This needs the engine, so it's an error when executing without one.*/
+export type Mass = Record<string, never>;
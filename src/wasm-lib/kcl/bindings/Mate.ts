@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Axis3d } from "./Axis3d";
+import type { PartFace } from "./PartFace";
+import type { Solid } from "./Solid";
+
+/**
+ * How to put an instance in place relative to other geometry.
+ */
+export type Mate = { "type": "coincident", 
+/**
+ * The face of the instance.
+ */
+face: PartFace, 
+/**
+ * The solid to put the instance against.
+ */
+to: Solid, 
+/**
+ * The face of that solid.
+ */
+toFace: PartFace, } | { "type": "concentric", 
+/**
+ * The axis of the instance, where the part builds it.
+ */
+axis: Axis3d, 
+/**
+ * The axis to line it up with.
+ */
+to: Axis3d, };
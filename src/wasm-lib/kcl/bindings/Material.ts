@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: material
+Say what solids or imported geometry are made of.
+The material's density is used by `mass`, and its name is the material listed in the bill of materials for parts which don't give one.*/
+export type Material = Record<string, never>;
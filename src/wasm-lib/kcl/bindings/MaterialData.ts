@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a solid is made of.
+ */
+export type MaterialData = { 
+/**
+ * The name of the material.
+ */
+name: string, 
+/**
+ * The density of the material, in kilograms per cubic meter.
+ */
+density: number, };
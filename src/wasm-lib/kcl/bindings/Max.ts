@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: max
+Compute the maximum of the given arguments.*/
+export type Max = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: measureDistance
+Measure the shortest distance between two solids or tagged edges.
+This needs the engine, so it's an error when executing without one.*/
+export type MeasureDistance = Record<string, never>;
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Solid } from "./Solid";
+import type { TagIdentifier } from "./TagIdentifier";
+
+/**
+ * Something to measure the distance to.
+ */
+export type MeasureEntity = Solid | TagIdentifier;
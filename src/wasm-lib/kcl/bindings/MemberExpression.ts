@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LiteralIdentifier } from "./LiteralIdentifier";
+import type { MemberObject } from "./MemberObject";
+
+export type MemberExpression = { "type": "MemberExpression", start: number, end: number, object: MemberObject, property: LiteralIdentifier, computed: boolean, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
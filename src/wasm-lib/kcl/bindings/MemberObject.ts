@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Identifier } from "./Identifier";
+import type { MemberExpression } from "./MemberExpression";
+
+export type MemberObject = { "type": "MemberExpression" } & MemberExpression | { "type": "Identifier" } & Identifier;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A statement both sides edited in incompatible ways.
+ */
+export type MergeConflict = { name: string, base: string | null, ours: string | null, theirs: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MergeConflict } from "./MergeConflict";
+
+/**
+ * The result of a three-way merge.
+ */
+export type MergeResult = { 
+/**
+ * The merged source. Conflicting statements are wrapped in git-style conflict markers.
+ */
+source: string, conflicts: Array<MergeConflict>, };
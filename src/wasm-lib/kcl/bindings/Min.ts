@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: min
+Compute the minimum of the given arguments.*/
+export type Min = Record<string, never>;
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: mirror2d
+Mirror a sketch.
+Only works on unclosed sketches for now.
+
+Mirror occurs around a local sketch axis rather than a global axis.*/
+export type Mirror2D = Record<string, never>;
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AxisOrEdgeReference } from "./AxisOrEdgeReference";
+
+/**
+ * Data for a mirror.
+ */
+export type Mirror2dData = { 
+/**
+ * Axis to use as mirror.
+ */
+axis: AxisOrEdgeReference, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: mirror3d
+Mirror solids across a plane or a planar face, creating a mirrored copy
+of each one.
+
+The solids come first, then the mirror. The mirror can be one of the standard planes, a plane made with `offsetPlane` or `planeOnFace`, or a face of an extruded solid given by the solid and the face's tag (or `'start'`/`'end'`), the same way as in `startSketchOn`. The original solids are left in place, only the copies are returned.
+
+The copies keep the tags of the solids they're copied from, naming the mirrored faces and edges, which can be sketched on, filleted or chamfered. As when a sketch is extruded twice, a tag names the face of the latest solid made with it, so after mirroring one solid its tags name the copy's faces.*/
+export type Mirror3D = Record<string, never>;
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: mm
+Millimeters conversion factor for current projects units.
+No matter what units the current project uses, this function will always return the conversion factor to millimeters.
+
+For example, if the current project uses inches, this function will return `(1/25.4)`. If the current project uses millimeters, this function will return `1`.
+
+**Caution**: This function is only intended to be used when you absolutely MUST have different units in your code than the project settings. Otherwise, it is a bad pattern to use this function.
+
+We merely provide these functions for convenience and readability, as `10 * mm()` is more readable that your intent is "I want 10 millimeters" than `10 * (1/25.4)`, if the project settings are in inches.*/
+export type Mm = Record<string, never>;
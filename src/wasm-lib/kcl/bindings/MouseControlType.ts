@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The types of controls for how to navigate the 3D view.
+ */
+export type MouseControlType = "kitty_cad" | "on_shape" | "trackpad_friendly" | "solidworks" | "nx" | "creo" | "auto_cad";
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonCodeNode } from "./NonCodeNode";
+
+export type NonCodeMeta = { nonCodeNodes: { [key in number]?: Array<NonCodeNode> }, start: Array<NonCodeNode>, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonCodeValue } from "./NonCodeValue";
+
+export type NonCodeNode = { "type": "NonCodeNode", start: number, end: number, value: NonCodeValue, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
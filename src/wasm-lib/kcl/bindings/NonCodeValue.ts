@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommentStyle } from "./CommentStyle";
+
+export type NonCodeValue = { "type": "shebang", value: string, } | { "type": "inlineComment", value: string, style: CommentStyle, } | { "type": "blockComment", value: string, style: CommentStyle, } | { "type": "newLineBlockComment", value: string, style: CommentStyle, } | { "type": "newLine" };
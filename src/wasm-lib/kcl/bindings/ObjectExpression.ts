@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonCodeMeta } from "./NonCodeMeta";
+import type { ObjectProperty } from "./ObjectProperty";
+
+export type ObjectExpression = { "type": "ObjectExpression", start: number, end: number, properties: Array<ObjectProperty>, nonCodeMeta: NonCodeMeta, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The types of onboarding status.
+ */
+export type OnboardingStatus = "completed" | "incomplete" | "dismissed" | "/" | "/camera" | "/streaming" | "/editor" | "/parametric-modeling" | "/interactive-numbers" | "/command-k" | "/user-menu" | "/project-menu" | "/export" | "/move" | "/sketching" | "/future-work";
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The os info structure.
+ */
+export type OsInfo = { 
+/**
+ * The platform the app is running on.
+ */
+platform: string | null, 
+/**
+ * The architecture the app is running on.
+ */
+arch: string | null, 
+/**
+ * The kernel version.
+ */
+version: string | null, 
+/**
+ * Information about the browser.
+ */
+browser: string | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Identifier } from "./Identifier";
+
+/**
+ * Parameter of a KCL function.
+ */
+export type Parameter = { "type": "Parameter", 
+/**
+ * The parameter's label or name.
+ */
+identifier: Identifier, 
+/**
+ * Is the parameter optional?
+ */
+optional: boolean, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FunctionExpression } from "./FunctionExpression";
+
+/**
+ * A part: a function imported from another file, which builds a solid.
+ */
+export type PartDefinition = { 
+/**
+ * The name the function was imported as.
+ */
+name: string, 
+/**
+ * The path of the file the function was imported from.
+ */
+path: string, 
+/**
+ * The function which builds the part.
+ */
+expression: FunctionExpression, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FaceTag } from "./FaceTag";
+
+/**
+ * A face of a part, by `'start'`, `'end'`, its id or the name of its tag.
+ *
+ * Tags made inside a part's function can't be used outside it, so they're given by name.
+ */
+export type PartFace = FaceTag | string;
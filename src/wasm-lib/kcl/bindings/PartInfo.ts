@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: partInfo
+Say what part solids or imported geometry are, so they're listed in the
+bill of materials.
+
+Each solid is counted as `quantity` of the part, which defaults to 1. Copies made by patterns, and solids made by functions imported from other files, are counted too.*/
+export type PartInfo = Record<string, never>;
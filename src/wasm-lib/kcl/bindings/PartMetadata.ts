@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What part a solid is.
+ */
+export type PartMetadata = { 
+/**
+ * The name of the part.
+ */
+name: string, 
+/**
+ * The part number.
+ */
+partNumber: string | null, 
+/**
+ * The material the part is made of. Defaults to the name of the material the solid was
+ * given with `material`.
+ */
+material: string | null, 
+/**
+ * How many of the part the solid stands for, for parts which aren't all modeled.
+ */
+quantity: number, };
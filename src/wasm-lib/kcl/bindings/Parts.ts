@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaterialData } from "./MaterialData";
+import type { PartMetadata } from "./PartMetadata";
+
+/**
+ * The parts a program has made so far, by the id of their solid or imported geometry.
+ */
+export type Parts = { parts: { [key in string]?: PartMetadata }, 
+/**
+ * What the solids and imported geometry given a material are made of.
+ */
+materials: { [key in string]?: MaterialData }, 
+/**
+ * Solids which were replaced by a moved copy, and so are no longer parts.
+ */
+replaced: Array<string>, };
@@ -0,0 +1,160 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GeoMeta } from "./GeoMeta";
+import type { TagDeclarator } from "./TagDeclarator";
+
+/**
+ * A path.
+ */
+export type Path = { "type": "ToPoint", 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, } | { "type": "TangentialArcTo", 
+/**
+ * the arc's center
+ */
+center: [number, number], 
+/**
+ * arc's direction
+ */
+ccw: boolean, 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, } | { "type": "TangentialArc", 
+/**
+ * the arc's center
+ */
+center: [number, number], 
+/**
+ * arc's direction
+ */
+ccw: boolean, 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, } | { "type": "Circle", 
+/**
+ * the arc's center
+ */
+center: [number, number], 
+/**
+ * the arc's radius
+ */
+radius: number, 
+/**
+ * arc's direction
+ */
+ccw: boolean, 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, } | { "type": "Horizontal", 
+/**
+ * The x coordinate.
+ */
+x: number, 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, } | { "type": "AngledLineTo", 
+/**
+ * The x coordinate.
+ */
+x: number | null, 
+/**
+ * The y coordinate.
+ */
+y: number | null, 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, } | { "type": "Base", 
+/**
+ * The from point.
+ */
+from: [number, number], 
+/**
+ * The to point.
+ */
+to: [number, number], 
+/**
+ * The tag of the path.
+ */
+tag: TagDeclarator | null, 
+/**
+ * Metadata.
+ */
+__geoMeta: GeoMeta, };
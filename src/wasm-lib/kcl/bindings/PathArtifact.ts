@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+import type { Sweep } from "./Sweep";
+
+/**
+ * A sketched path, and the solid made from it if it was extruded, revolved or lofted.
+ */
+export type PathArtifact = { id: string, 
+/**
+ * The plane or face it was sketched on.
+ */
+planeId: string, segIds: Array<string>, sweep: Sweep | null, 
+/**
+ * Where the path was started.
+ */
+sourceRange: SourceRange, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternAlongPath
+Repeat a 3-dimensional solid at even spacing along the segments of a
+sketch, such as a cam profile or a rail. Each instance may additionally be turned to follow the direction of the path.
+
+The solid should sit on the start of the path. Instances are moved along the path relative to that point, and when aligned to the tangent they are rotated about their own center, around the normal of the path's sketch plane.*/
+export type PatternAlongPath = Record<string, never>;
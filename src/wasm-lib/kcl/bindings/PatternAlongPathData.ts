@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Uint } from "./Uint";
+
+/**
+ * Data for a pattern along a path.
+ */
+export type PatternAlongPathData = { 
+/**
+ * The number of total instances. Must be greater than or equal to 1.
+ * This includes the original entity. On an open path the last instance
+ * sits on the end of the path, on a closed path the instances are spread
+ * evenly around it.
+ */
+instances: Uint, 
+/**
+ * Whether to turn each instance to follow the direction of the path.
+ */
+alignToTangent: boolean, 
+/**
+ * Indices of instances to leave out of the pattern. Index 0 is the
+ * original entity, which cannot be skipped.
+ */
+skip: Array<Uint> | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Axis3d } from "./Axis3d";
+
+/**
+ * The axis of a circular pattern.
+ */
+export type PatternAxis = [number, number, number] | Axis3d;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternCircular2d
+Repeat a 2-dimensional sketch some number of times along a partial or
+complete circle some specified number of times. Each object may additionally be rotated along the circle, ensuring orentation of the solid with respect to the center of the circle is maintained.*/
+export type PatternCircular2D = Record<string, never>;
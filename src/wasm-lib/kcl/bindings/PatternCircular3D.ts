@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternCircular3d
+Repeat a 3-dimensional solid some number of times along a partial or
+complete circle some specified number of times. Each object may additionally be rotated along the circle, ensuring orentation of the solid with respect to the center of the circle is maintained.*/
+export type PatternCircular3D = Record<string, never>;
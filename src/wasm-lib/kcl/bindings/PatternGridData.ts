@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Uint } from "./Uint";
+
+/**
+ * Data for a grid pattern on a face.
+ */
+export type PatternGridData = { 
+/**
+ * The number of rows, along the face's y axis. Must be greater than or equal to 1.
+ */
+rows: Uint, 
+/**
+ * The number of columns, along the face's x axis. Must be greater than or equal to 1.
+ */
+columns: Uint, 
+/**
+ * The distance between columns and between rows.
+ */
+spacing: [number, number], 
+/**
+ * Indices of instances to leave out of the pattern, counted along each
+ * row in turn, so the instance in row `r` and column `c` has the index
+ * `r * columns + c`. Index 0 is the original entity, which cannot be skipped.
+ */
+skip: Array<Uint> | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternGridOnFace
+Repeat a 3-dimensional solid in rows and columns laid out on a planar
+face of another solid, such as bosses on a plate or vents in a panel.
+
+Columns run along the face's x axis and rows along its y axis, the same axes a sketch started on the face uses. The solid should sit on the face at the first row and column.*/
+export type PatternGridOnFace = Record<string, never>;
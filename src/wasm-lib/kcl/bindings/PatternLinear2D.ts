@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternLinear2d
+Repeat a 2-dimensional sketch along some dimension, with a dynamic amount
+of distance between each repetition, some specified number of times.*/
+export type PatternLinear2D = Record<string, never>;
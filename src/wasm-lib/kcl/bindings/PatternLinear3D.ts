@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternLinear3d
+Repeat a 3-dimensional solid along a linear path, with a dynamic amount
+of distance between each repetition, some specified number of times.*/
+export type PatternLinear3D = Record<string, never>;
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: patternTransform
+Repeat a 3-dimensional solid, changing it each time.
+Replicates the 3D solid, applying a transformation function to each replica. Transformation function could alter rotation, scale, visibility, position, etc.
+
+The `patternTransform` call itself takes a number for how many total instances of the shape should be. For example, if you use a circle with `patternTransform(4, transform)` then there will be 4 circles: the original, and 3 created by replicating the original and calling the transform function on each.
+
+The transform function takes a single parameter: an integer representing which number replication the transform is for. E.g. the first replica to be transformed will be passed the argument `1`. This simplifies your math: the transform function can rely on id `0` being the original instance passed into the `patternTransform`. See the examples.
+
+Options can be given after the solid. Skipping an id with their `skip` list is the same as returning `replicate: false` from the transform function for it.
+
+The transform function returns a transform object. All properties of the object are optional, they each default to "no change". So the overall transform object defaults to "no change" too. Its properties are:
+
+ - `translate` (3D point)
+
+   Translates the replica, moving its position in space.
+
+ - `replicate` (bool)
+
+   If false, this ID will not actually copy the object. It'll be skipped.
+
+ - `scale` (3D point)
+
+   Stretches the object, multiplying its width in the given dimension by the point's component in    that direction.
+
+ - `rotation` (object, with the following properties)
+
+   - `rotation.axis` (a 3D point, defaults to the Z axis)
+
+   - `rotation.angle` (number of degrees)
+
+   - `rotation.origin` (either "local" i.e. rotate around its own center, "global" i.e. rotate around the scene's center, or a 3D point, defaults to "local")*/
+export type PatternTransform = Record<string, never>;
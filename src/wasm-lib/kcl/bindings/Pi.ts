@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: pi
+Return the value of `pi`. Archimedes’ constant (π).*/
+export type Pi = Record<string, never>;
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expr } from "./Expr";
+import type { NonCodeMeta } from "./NonCodeMeta";
+
+export type PipeExpression = { "type": "PipeExpression", start: number, end: number, body: Array<Expr>, nonCodeMeta: NonCodeMeta, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Metadata } from "./Metadata";
+import type { PlaneType } from "./PlaneType";
+import type { Point3d } from "./Point3d";
+
+/**
+ * A plane.
+ */
+export type Plane = { 
+/**
+ * The id of the plane.
+ */
+id: string, value: PlaneType, 
+/**
+ * Origin of the plane.
+ */
+origin: Point3d, 
+/**
+ * What should the plane’s X axis be?
+ */
+xAxis: Point3d, 
+/**
+ * What should the plane’s Y axis be?
+ */
+yAxis: Point3d, 
+/**
+ * The z-axis (normal).
+ */
+zAxis: Point3d, __meta: Array<Metadata>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * A plane paths were sketched on.
+ */
+export type PlaneArtifact = { id: string, 
+/**
+ * The paths sketched on it.
+ */
+pathIds: Array<string>, sourceRange: SourceRange, };
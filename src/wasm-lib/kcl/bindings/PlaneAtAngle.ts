@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: planeAtAngle
+Make a plane by turning another plane about an axis.*/
+export type PlaneAtAngle = Record<string, never>;
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Axis3d } from "./Axis3d";
+import type { PlaneData } from "./PlaneData";
+
+/**
+ * Data for a plane at an angle to another plane.
+ */
+export type PlaneAtAngleData = { 
+/**
+ * The plane to start from.
+ */
+plane: PlaneData, 
+/**
+ * The axis to turn the plane about.
+ */
+axis: Axis3d, 
+/**
+ * The angle to turn the plane by, in degrees, counterclockwise looking down the axis.
+ */
+angle: number, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Point3d } from "./Point3d";
+
+/**
+ * Data for a plane.
+ */
+export type PlaneData = "xY" | "negXY" | "xZ" | "negXZ" | "yZ" | "negYZ" | { "plane": { 
+/**
+ * Origin of the plane.
+ */
+origin: Point3d, 
+/**
+ * What should the plane’s X axis be?
+ */
+x_axis: Point3d, 
+/**
+ * What should the plane’s Y axis be?
+ */
+y_axis: Point3d, 
+/**
+ * The z-axis (normal).
+ */
+z_axis: Point3d, } };
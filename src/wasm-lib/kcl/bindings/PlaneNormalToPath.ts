@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: planeNormalToPath
+Make a plane normal to a sketch's path, at a fraction of the way along it.
+`0` is the start of the path and `1` is its end. The plane's origin is on the path, its normal points along the path, and its Y axis is the normal of the plane the path was sketched on. This is where to sketch the profile for a sweep.*/
+export type PlaneNormalToPath = Record<string, never>;
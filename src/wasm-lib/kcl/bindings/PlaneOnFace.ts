@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: planeOnFace
+Make a plane coincident with a planar face of an extruded solid, given
+by the face's tag or `'start'`/`'end'`, the same way as in `startSketchOn`. The plane's normal points out of the solid.
+
+Unlike sketching on the face itself, the plane doesn't depend on the face, so turning it with `planeAtAngle` or mirroring across it doesn't need the engine.*/
+export type PlaneOnFace = Record<string, never>;
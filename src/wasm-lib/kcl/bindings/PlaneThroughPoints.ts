@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: planeThroughPoints
+Make a plane through three points.
+The plane's origin is the first point, its X axis points toward the second, and its normal is on the side the points go counterclockwise around.*/
+export type PlaneThroughPoints = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: polar
+Convert polar/sphere (azimuth, elevation, distance) coordinates to
+cartesian (x/y/z grid) coordinates.*/
+export type Polar = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: profileStart
+Extract the provided 2-dimensional sketch's profile's origin
+value.*/
+export type ProfileStart = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: profileStartY
+Extract the provided 2-dimensional sketch's profile's origin's 'y'
+value.*/
+export type ProfileStartY = Record<string, never>;
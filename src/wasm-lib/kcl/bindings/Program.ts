@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BodyItem } from "./BodyItem";
+import type { NonCodeMeta } from "./NonCodeMeta";
+
+/**
+ * A KCL program top level, or function body.
+ */
+export type Program = { start: number, end: number, body: Array<BodyItem>, nonCodeMeta: NonCodeMeta, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Environment } from "./Environment";
+import type { EnvironmentRef } from "./EnvironmentRef";
+import type { KclValue } from "./KclValue";
+
+export type ProgramMemory = { environments: Array<Environment>, currentEnv: EnvironmentRef, return: KclValue | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PerProjectSettings } from "./PerProjectSettings";
+
+/**
+ * High level project configuration.
+ */
+export type ProjectConfiguration = { 
+/**
+ * The settings for the project.
+ */
+settings: PerProjectSettings, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GlobalAxis } from "./GlobalAxis";
+
+/**
+ * A direction to compare edges and faces with.
+ */
+export type QueryDirection = GlobalAxis | [number, number, number];
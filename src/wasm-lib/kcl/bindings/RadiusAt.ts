@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The radius of a fillet at a point along an edge.
+ */
+export type RadiusAt = { 
+/**
+ * How far along the edge, from 0 at its start to 1 at its end.
+ */
+at: number, 
+/**
+ * The radius of the fillet at this point.
+ */
+radius: number, };
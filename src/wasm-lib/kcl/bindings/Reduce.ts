@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: reduce
+Take a starting value. Then, for each element of an array, calculate the next value,
+using the previous value and the element.*/
+export type Reduce = Record<string, never>;
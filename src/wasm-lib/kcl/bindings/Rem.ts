@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: rem
+Compute the remainder after dividing `num` by `div`.
+If `num` is negative, the result will be too.*/
+export type Rem = Record<string, never>;
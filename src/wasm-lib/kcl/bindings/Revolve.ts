@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: revolve
+Rotate a sketch around some provided axis, creating a solid from its extent.
+This, like extrude, is able to create a 3-dimensional solid from a 2-dimensional sketch. However, unlike extrude, this creates a solid by using the extent of the sketch as its revolved around an axis rather than using the extent of the sketch linearly translated through a third dimension.
+
+Revolve occurs around a local sketch axis rather than a global axis.*/
+export type Revolve = Record<string, never>;
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AxisOrEdgeReference } from "./AxisOrEdgeReference";
+
+/**
+ * Data for revolution surfaces.
+ */
+export type RevolveData = { 
+/**
+ * Angle to revolve (in degrees). Default is 360.
+ */
+angle: number | null, 
+/**
+ * Axis of revolution.
+ */
+axis: AxisOrEdgeReference, 
+/**
+ * Tolerance for the revolve operation.
+ */
+tolerance: number | null, };
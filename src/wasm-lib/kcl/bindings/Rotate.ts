@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: rotate
+Rotate solids or imported geometry about an axis.
+The rotation is counterclockwise looking down the axis, about the line along the axis through `origin`, or through the global origin if no origin is given.*/
+export type Rotate = Record<string, never>;
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data for a rotation.
+ */
+export type RotateData = { 
+/**
+ * The axis to rotate around.
+ */
+axis: [number, number, number], 
+/**
+ * The angle to rotate by, in degrees.
+ */
+angle: number, 
+/**
+ * A point on the line to rotate around. Defaults to the global origin.
+ */
+origin: [number, number, number] | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: scale
+Scale solids or imported geometry about the global origin, either by
+one factor for every axis or by a factor for each of X, Y and Z.
+
+Programs can still name their own variables and functions `scale`, as many did before this function existed. Theirs is used instead of this.*/
+export type Scale = Record<string, never>;
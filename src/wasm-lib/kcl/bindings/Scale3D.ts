@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: scale3D
+Scale solids or imported geometry about the global origin, either by
+one factor for every axis or by a factor for each of X, Y and Z.*/
+export type Scale3D = Record<string, never>;
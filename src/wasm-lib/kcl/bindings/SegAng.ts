@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: segAng
+Compute the angle (in degrees) of the provided line segment.*/
+export type SegAng = Record<string, never>;
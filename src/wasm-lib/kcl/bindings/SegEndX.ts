@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: segEndX
+Compute the ending point of the provided line segment along the 'x' axis.*/
+export type SegEndX = Record<string, never>;
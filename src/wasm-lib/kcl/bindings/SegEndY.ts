@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: segEndY
+Compute the ending point of the provided line segment along the 'y' axis.*/
+export type SegEndY = Record<string, never>;
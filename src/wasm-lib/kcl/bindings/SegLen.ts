@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: segLen
+Compute the length of the provided line segment.*/
+export type SegLen = Record<string, never>;
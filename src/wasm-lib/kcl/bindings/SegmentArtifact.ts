@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * A segment of a path.
+ */
+export type SegmentArtifact = { 
+/**
+ * Also the id of the edge the segment makes on the solid's start face.
+ */
+id: string, pathId: string, 
+/**
+ * The wall swept from the segment.
+ */
+surfaceId: string | null, 
+/**
+ * The edges of the wall, other than the segment.
+ */
+edgeIds: Array<string>, 
+/**
+ * The fillet or chamfer on the segment's edge.
+ */
+edgeCutId: string | null, sourceRange: SourceRange, };
@@ -0,0 +1,36 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppSettings } from "./AppSettings";
+import type { CommandBarSettings } from "./CommandBarSettings";
+import type { ModelingSettings } from "./ModelingSettings";
+import type { ProjectSettings } from "./ProjectSettings";
+import type { TelemetrySettings } from "./TelemetrySettings";
+import type { TextEditorSettings } from "./TextEditorSettings";
+
+/**
+ * High level settings.
+ */
+export type Settings = { 
+/**
+ * The settings for the modeling app.
+ */
+app: AppSettings, 
+/**
+ * Settings that affect the behavior while modeling.
+ */
+modeling: ModelingSettings, 
+/**
+ * Settings that affect the behavior of the KCL text editor.
+ */
+text_editor: TextEditorSettings, 
+/**
+ * Settings that affect the behavior of project management.
+ */
+project: ProjectSettings, 
+/**
+ * Settings that affect the behavior of the command bar.
+ */
+command_bar: CommandBarSettings, 
+/**
+ * Settings that control what usage data is collected and where it goes.
+ */
+telemetry: TelemetrySettings, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: shell
+Remove volume from a 3-dimensional shape such that a wall of the
+provided thickness remains, taking volume starting at the provided face, leaving it open in that direction.*/
+export type Shell = Record<string, never>;
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FaceTag } from "./FaceTag";
+
+/**
+ * Data for shells.
+ */
+export type ShellData = { 
+/**
+ * The thickness of the shell.
+ */
+thickness: number, 
+/**
+ * The faces you want removed.
+ */
+faces: Array<FaceTag>, };
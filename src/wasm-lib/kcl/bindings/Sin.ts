@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: sin
+Compute the sine of a number (in radians).*/
+export type Sin = Record<string, never>;
@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BasePath } from "./BasePath";
+import type { Metadata } from "./Metadata";
+import type { Path } from "./Path";
+import type { SketchSurface } from "./SketchSurface";
+import type { TagIdentifier } from "./TagIdentifier";
+
+/**
+ * A sketch is a collection of paths.
+ */
+export type Sketch = { "type": "Sketch", 
+/**
+ * The id of the sketch (this will change when the engine's reference to it changes).
+ */
+id: string, 
+/**
+ * The paths in the sketch.
+ */
+paths: Array<Path>, 
+/**
+ * What the sketch is on (can be a plane or a face).
+ */
+on: SketchSurface, 
+/**
+ * The starting path.
+ */
+start: BasePath, 
+/**
+ * Tag identifiers that have been declared in this sketch.
+ */
+tags: { [key in string]?: TagIdentifier }, 
+/**
+ * Metadata.
+ */
+__meta: Array<Metadata>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlaneData } from "./PlaneData";
+import type { Solid } from "./Solid";
+
+/**
+ * Data for start sketch on.
+ * You can start a sketch on a plane or an solid.
+ */
+export type SketchData = PlaneData | Solid;
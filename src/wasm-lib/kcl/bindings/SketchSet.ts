@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Sketch } from "./Sketch";
+
+/**
+ * A sketch or a group of sketches.
+ */
+export type SketchSet = { "type": "sketch" } & Sketch | { "type": "sketches" } & Array<Sketch>;
@@ -0,0 +1,68 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppearanceData } from "./AppearanceData";
+import type { EdgeCut } from "./EdgeCut";
+import type { ExtrudeSurface } from "./ExtrudeSurface";
+import type { HoleInfo } from "./HoleInfo";
+import type { MaterialData } from "./MaterialData";
+import type { Metadata } from "./Metadata";
+import type { PartMetadata } from "./PartMetadata";
+import type { Sketch } from "./Sketch";
+import type { Transform3d } from "./Transform3d";
+
+/**
+ * An solid is a collection of extrude surfaces.
+ */
+export type Solid = { "type": "Solid", 
+/**
+ * The id of the solid.
+ */
+id: string, 
+/**
+ * The extrude surfaces.
+ */
+value: Array<ExtrudeSurface>, 
+/**
+ * The sketch.
+ */
+sketch: Sketch, 
+/**
+ * The height of the solid.
+ */
+height: number, 
+/**
+ * The id of the extrusion start cap
+ */
+startCapId: string | null, 
+/**
+ * The id of the extrusion end cap
+ */
+endCapId: string | null, 
+/**
+ * Chamfers or fillets on this solid.
+ */
+edgeCuts: Array<EdgeCut>, 
+/**
+ * Holes cut into this solid by hole features.
+ */
+holes: Array<HoleInfo>, 
+/**
+ * Where the solid has been moved to since it was made, by `translate`,
+ * `rotate`, `scaleBy` or `transformBy`.
+ */
+transform: Transform3d, 
+/**
+ * Which part the solid is, for bills of materials, set by `partInfo`.
+ */
+part: PartMetadata | null, 
+/**
+ * How the solid looks, set by `appearance`.
+ */
+appearance: AppearanceData | null, 
+/**
+ * What the solid is made of, set by `material`.
+ */
+material: MaterialData | null, 
+/**
+ * Metadata.
+ */
+__meta: Array<Metadata>, };
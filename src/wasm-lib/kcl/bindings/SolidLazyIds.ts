@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An solid ID and its fillet and chamfer IDs.  This is needed for lazy
+ * fillet evaluation.
+ */
+export type SolidLazyIds = { solid_id: string, sketch_id: string, 
+/**
+ * Chamfers or fillets on this solid.
+ */
+edge_cuts: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Solid } from "./Solid";
+
+/**
+ * A solid or a group of solids.
+ */
+export type SolidSet = { "type": "solid" } & Solid | { "type": "solids" } & Array<Solid>;
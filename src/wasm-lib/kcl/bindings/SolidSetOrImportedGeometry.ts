@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ImportedGeometry } from "./ImportedGeometry";
+import type { SolidSet } from "./SolidSet";
+
+/**
+ * Solids, or geometry imported from a file.
+ */
+export type SolidSetOrImportedGeometry = SolidSet | ImportedGeometry;
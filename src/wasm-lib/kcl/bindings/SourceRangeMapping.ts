@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * Where an edited node was before and after an edit.
+ */
+export type SourceRangeMapping = { before: SourceRange, after: SourceRange, };
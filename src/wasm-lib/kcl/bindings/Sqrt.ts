@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: sqrt
+Compute the square root of a number.*/
+export type Sqrt = Record<string, never>;
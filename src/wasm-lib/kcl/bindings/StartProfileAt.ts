@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: startProfileAt
+Start a new profile at a given point.*/
+export type StartProfileAt = Record<string, never>;
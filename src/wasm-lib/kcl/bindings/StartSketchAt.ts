@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: startSketchAt
+Start a new 2-dimensional sketch at a given point on the 'XY' plane.*/
+export type StartSketchAt = Record<string, never>;
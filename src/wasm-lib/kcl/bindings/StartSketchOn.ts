@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: startSketchOn
+Start a new 2-dimensional sketch on a specific plane or face.*/
+export type StartSketchOn = Record<string, never>;
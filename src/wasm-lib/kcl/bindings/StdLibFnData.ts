@@ -0,0 +1,61 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * This struct defines a single argument to a stdlib function.
+ */
+export type StdLibFnArg = { 
+/**
+ * The name of the argument.
+ */
+name: string, 
+/**
+ * The type of the argument.
+ */
+type: string, 
+/**
+ * The schema of the argument.
+ */
+schema: any, 
+/**
+ * If the argument is required.
+ */
+required: boolean, };
+
+export type StdLibFnData = { 
+/**
+ * The name of the function.
+ */
+name: string, 
+/**
+ * The summary of the function.
+ */
+summary: string, 
+/**
+ * The description of the function.
+ */
+description: string, 
+/**
+ * The tags of the function.
+ */
+tags: Array<string>, 
+/**
+ * The args of the function.
+ */
+args: Array<StdLibFnArg>, 
+/**
+ * The return value of the function.
+ */
+returnValue: StdLibFnArg | null, 
+/**
+ * If the function is unpublished.
+ */
+unpublished: boolean, 
+/**
+ * If the function is deprecated.
+ */
+deprecated: boolean, 
+/**
+ * Code examples.
+ * These are tested and we know they compile and execute.
+ */
+examples: Array<string>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SourceRange } from "./SourceRange";
+
+/**
+ * What made a path a solid.
+ */
+export type Sweep = { 
+/**
+ * Its walls and caps.
+ */
+surfaceIds: Array<string>, edgeIds: Array<string>, edgeCutIds: Array<string>, sourceRange: SourceRange, };
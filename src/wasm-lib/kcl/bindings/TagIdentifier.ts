@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Metadata } from "./Metadata";
+import type { TagEngineInfo } from "./TagEngineInfo";
+
+export type TagIdentifier = { "type": "TagIdentifier", value: string, info: TagEngineInfo | null, __meta: Array<Metadata>, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: tan
+Compute the tangent of a number (in radians).*/
+export type Tan = Record<string, never>;
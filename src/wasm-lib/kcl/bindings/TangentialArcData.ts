@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data to draw a tangential arc.
+ */
+export type TangentialArcData = { 
+/**
+ * Radius of the arc.
+ * Not to be confused with Raiders of the Lost Ark.
+ */
+radius: number, 
+/**
+ * Offset of the arc, in degrees.
+ */
+offset: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: tangentialArcToRelative
+Starting at the current sketch's origin, draw a curved line segment along
+some part of an imaginary circle until it reaches a point the given (x, y) distance away.*/
+export type TangentialArcToRelative = Record<string, never>;
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: tau
+Return the value of `tau`. The full circle constant (τ). Equal to 2π.*/
+export type Tau = Record<string, never>;
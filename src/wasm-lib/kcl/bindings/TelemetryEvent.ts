@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileMetadata } from "./FileMetadata";
+import type { TelemetryLevel } from "./TelemetryLevel";
+
+/**
+ * A single telemetry report.
+ */
+export type TelemetryEvent = { 
+/**
+ * When the event was created.
+ */
+created_at: string, 
+/**
+ * The level the event was collected at.
+ */
+level: TelemetryLevel, 
+/**
+ * A hash of the name of the project, so projects can be told apart without naming them.
+ */
+project_name_hash: string | null, 
+/**
+ * Metadata about every file.
+ */
+files: Array<FileMetadata>, 
+/**
+ * The redacted code of every file, keyed by path. Only collected at `full`.
+ */
+code: { [key in string]?: string }, };
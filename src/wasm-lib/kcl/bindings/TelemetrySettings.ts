@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TelemetryLevel } from "./TelemetryLevel";
+
+/**
+ * Settings that control what usage data is collected and where it goes.
+ */
+export type TelemetrySettings = { 
+/**
+ * How much to collect. Nothing is collected unless the user opts in.
+ */
+level: TelemetryLevel, 
+/**
+ * Write telemetry to this file instead of sending it to Zoo.
+ */
+local_path: string | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: text
+Sketch text as closed 2-dimensional profiles, which can then be extruded
+or used to cut into a solid, e.g. for serial numbers, labels and logos.
+
+Each glyph outline becomes its own sketch, with any holes in the glyph (like the inside of an `O`) already cut out. The outlines are read from a TrueType font file, relative to the current project directory, or from the bundled Roboto font if no font is given.*/
+export type Text = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Horizontal alignment of text around its position.
+ */
+export type TextAnchor = "start" | "middle" | "end";
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TextAnchor } from "./TextAnchor";
+
+/**
+ * Data for sketching text.
+ */
+export type TextData = { 
+/**
+ * Path to a TrueType (`.ttf`) font file. Defaults to the bundled Roboto font.
+ */
+font: string | null, 
+/**
+ * The font size, i.e. the height of the em square.
+ */
+size: number, 
+/**
+ * How the text is aligned horizontally around its position.
+ */
+anchor: TextAnchor, 
+/**
+ * Where the text's baseline starts, in sketch coordinates. Defaults to the origin.
+ */
+position: [number, number] | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Thread metadata for a tapped hole.
+ */
+export type ThreadInfo = { 
+/**
+ * The thread designation, e.g. `M6x1` or `1/4-20 UNC`.
+ */
+designation: string, 
+/**
+ * The nominal (major) diameter of the thread.
+ */
+majorDiameter: number, 
+/**
+ * The distance between threads.
+ */
+pitch: number, 
+/**
+ * How deep the thread runs.
+ */
+depth: number, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: toDegrees
+Converts a number from radians to degrees.*/
+export type ToDegrees = Record<string, never>;
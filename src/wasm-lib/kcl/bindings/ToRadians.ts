@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: toRadians
+Converts a number from degrees to radians.*/
+export type ToRadians = Record<string, never>;
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TokenType } from "./TokenType";
+
+export type Token = { type: TokenType, 
+/**
+ * Offset in the source code where this token begins.
+ */
+start: number, 
+/**
+ * Offset in the source code where this token ends.
+ */
+end: number, value: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The types of tokens.
+ */
+export type TokenType = "number" | "word" | "operator" | "string" | "keyword" | "type" | "brace" | "hash" | "bang" | "dollar" | "whitespace" | "comma" | "colon" | "period" | "doublePeriod" | "lineComment" | "blockComment" | "function" | "unknown" | "questionMark";
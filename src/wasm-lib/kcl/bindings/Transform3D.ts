@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: transform3D
+Transform solids or imported geometry by a 4x4 matrix in global
+coordinates, given as its rows.
+
+The matrix can combine any translation, rotation and scale along the geometry's axes, but it can't shear or project, so the last row must be `[0, 0, 0, 1]`.*/
+export type Transform3D = Record<string, never>;
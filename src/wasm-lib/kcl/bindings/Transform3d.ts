@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A 4x4 affine transform in global coordinates, as rows, applied to column vectors.
+ */
+export type Transform3d = [[number, number, number, number], [number, number, number, number], [number, number, number, number], [number, number, number, number]];
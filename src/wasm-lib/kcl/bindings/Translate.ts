@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: translate
+Move solids or imported geometry by an offset along the global X, Y and Z axes.
+The solid is moved, not copied: other names bound to it refer to it where it is now. The same goes for `rotate`, `scaleBy` and `transformBy`.*/
+export type Translate = Record<string, never>;
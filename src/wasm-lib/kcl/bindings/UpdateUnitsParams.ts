@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TextDocumentIdentifier } from "./TextDocumentIdentifier";
+import type { UnitLength } from "./UnitLength";
+
+export type UpdateUnitsParams = { textDocument: TextDocumentIdentifier, 
+/**
+ * The content of the text document.
+ */
+text: string, units: UnitLength, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ItemVisibility } from "./ItemVisibility";
+import type { VariableDeclarator } from "./VariableDeclarator";
+import type { VariableKind } from "./VariableKind";
+
+export type VariableDeclaration = { "type": "VariableDeclaration", start: number, end: number, declarations: Array<VariableDeclarator>, visibility: ItemVisibility, kind: VariableKind, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
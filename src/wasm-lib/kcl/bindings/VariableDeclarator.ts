@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Expr } from "./Expr";
+import type { Identifier } from "./Identifier";
+
+export type VariableDeclarator = { "type": "VariableDeclarator", start: number, end: number, 
+/**
+ * The identifier of the variable.
+ */
+id: Identifier, 
+/**
+ * The value of the variable.
+ */
+init: Expr, digest?: [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number], };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A side face of a solid, swept from a segment.
+ */
+export type WallArtifact = { id: string, segId: string, 
+/**
+ * The path whose sweep made it.
+ */
+sweepId: string, 
+/**
+ * The paths sketched on it.
+ */
+pathIds: Array<string>, };
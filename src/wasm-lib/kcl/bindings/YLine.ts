@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: yLine
+Draw a line relative to the current origin to a specified distance away
+from the current position along the 'y' axis.*/
+export type YLine = Record<string, never>;
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**Std lib function: yLineTo
+Draw a line parallel to the Y axis, that ends at the given Y.
+E.g. if the previous line ended at (1, 1), then yLineTo(4) draws a line from (1, 1) to (1, 4)*/
+export type YLineTo = Record<string, never>;
@@ -0,0 +1,530 @@
+//! The artifact graph: what geometry a program made, how it's connected, and which code made it.
+//!
+//! Artifacts are recorded as the standard library returns them, keyed by their engine ids:
+//! segments belong to paths, paths are sketched on planes or faces, extruded paths are
+//! solids, and solids have walls and caps as faces, and edges. Each artifact can be traced
+//! back to the source range which made it, so selecting geometry can select code, and the
+//! other way round.
+//!
+//! The engine keeps one id for a sketch and the solid extruded from it, so a solid is its
+//! path, with a sweep.
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::executor::{EdgeCut, ExtrudeSurface, KclValue, Plane, Sketch, SketchSurface, Solid, SourceRange};
+
+/// Every artifact the program made, by id.
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactGraph {
+    pub artifacts: HashMap<Uuid, Artifact>,
+}
+
+/// A piece of geometry the program made.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Artifact {
+    Plane(PlaneArtifact),
+    Path(PathArtifact),
+    Segment(SegmentArtifact),
+    Wall(WallArtifact),
+    Cap(CapArtifact),
+    Edge(EdgeArtifact),
+    EdgeCut(EdgeCutArtifact),
+}
+
+/// A plane paths were sketched on.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaneArtifact {
+    pub id: Uuid,
+    /// The paths sketched on it.
+    pub path_ids: Vec<Uuid>,
+    pub source_range: SourceRange,
+}
+
+/// A sketched path, and the solid made from it if it was extruded, revolved or lofted.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PathArtifact {
+    pub id: Uuid,
+    /// The plane or face it was sketched on.
+    pub plane_id: Uuid,
+    pub seg_ids: Vec<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sweep: Option<Sweep>,
+    /// Where the path was started.
+    pub source_range: SourceRange,
+}
+
+/// What made a path a solid.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Sweep {
+    /// Its walls and caps.
+    pub surface_ids: Vec<Uuid>,
+    pub edge_ids: Vec<Uuid>,
+    pub edge_cut_ids: Vec<Uuid>,
+    pub source_range: SourceRange,
+}
+
+/// A segment of a path.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentArtifact {
+    /// Also the id of the edge the segment makes on the solid's start face.
+    pub id: Uuid,
+    pub path_id: Uuid,
+    /// The wall swept from the segment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub surface_id: Option<Uuid>,
+    /// The edges of the wall, other than the segment.
+    pub edge_ids: Vec<Uuid>,
+    /// The fillet or chamfer on the segment's edge.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edge_cut_id: Option<Uuid>,
+    pub source_range: SourceRange,
+}
+
+/// A side face of a solid, swept from a segment.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct WallArtifact {
+    pub id: Uuid,
+    pub seg_id: Uuid,
+    /// The path whose sweep made it.
+    pub sweep_id: Uuid,
+    /// The paths sketched on it.
+    pub path_ids: Vec<Uuid>,
+}
+
+/// The start or end face of a solid.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CapArtifact {
+    pub id: Uuid,
+    pub sub_type: CapSubType,
+    /// The path whose sweep made it.
+    pub sweep_id: Uuid,
+    /// The paths sketched on it.
+    pub path_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub enum CapSubType {
+    Start,
+    End,
+}
+
+/// An edge of a solid which was looked up from a segment.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeArtifact {
+    pub id: Uuid,
+    pub seg_id: Uuid,
+    pub solid_id: Uuid,
+    pub sub_type: EdgeSubType,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub enum EdgeSubType {
+    /// The segment's edge on the end face.
+    Opposite,
+    /// Where the segment's wall meets the next or previous wall.
+    Adjacent,
+}
+
+/// A fillet or chamfer. If it was tagged, its id is also the id of the face it made.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeCutArtifact {
+    pub id: Uuid,
+    pub sub_type: EdgeCutSubType,
+    /// The edge it replaced.
+    pub consumed_edge_id: Uuid,
+    pub solid_id: Uuid,
+    pub source_range: SourceRange,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub enum EdgeCutSubType {
+    Fillet,
+    Chamfer,
+}
+
+impl ArtifactGraph {
+    pub fn get(&self, id: Uuid) -> Option<&Artifact> {
+        self.artifacts.get(&id)
+    }
+
+    /// The code which made an artifact. Faces and edges were made by the code which made
+    /// their segment, or for caps, their solid.
+    pub fn source_range(&self, id: Uuid) -> Option<SourceRange> {
+        match self.get(id)? {
+            Artifact::Plane(plane) => Some(plane.source_range),
+            Artifact::Path(path) => Some(
+                path.sweep
+                    .as_ref()
+                    .map_or(path.source_range, |sweep| sweep.source_range),
+            ),
+            Artifact::Segment(segment) => Some(segment.source_range),
+            Artifact::Wall(wall) => self.source_range(wall.seg_id),
+            Artifact::Cap(cap) => self.source_range(cap.sweep_id),
+            Artifact::Edge(edge) => self.source_range(edge.seg_id),
+            Artifact::EdgeCut(edge_cut) => Some(edge_cut.source_range),
+        }
+    }
+
+    /// The artifacts made by the code at a position in the source, innermost code first.
+    pub fn artifacts_at(&self, pos: usize) -> Vec<Uuid> {
+        let mut found: Vec<(SourceRange, Uuid)> = self
+            .artifacts
+            .iter()
+            .flat_map(|(id, artifact)| {
+                let mut ranges = vec![self.source_range(*id)];
+                // A solid was also made by the code which started its path.
+                if let Artifact::Path(path) = artifact {
+                    ranges.push(Some(path.source_range));
+                }
+                ranges
+                    .into_iter()
+                    .flatten()
+                    .filter(|range| range.contains(pos))
+                    .map(|range| (range, *id))
+            })
+            .collect();
+        found.sort_by_key(|(range, id)| (range.end() - range.start(), *id));
+        let mut ids: Vec<Uuid> = found.into_iter().map(|(_, id)| id).collect();
+        let mut seen = std::collections::HashSet::new();
+        ids.retain(|id| seen.insert(*id));
+        ids
+    }
+
+    /// Record the geometry in a value returned by a standard library function called at
+    /// `source_range`. Artifacts already recorded keep where they were made.
+    ///
+    /// Geometry made by a function from another file is all recorded at `imported_from`, the
+    /// call in this file which ran it, as its own source ranges are in that file.
+    pub(crate) fn record(&mut self, value: &KclValue, source_range: SourceRange, imported_from: Option<SourceRange>) {
+        let source_range = imported_from.unwrap_or(source_range);
+        match value {
+            KclValue::Plane(plane) => self.record_plane(plane, source_range, imported_from),
+            KclValue::Solid(solid) => self.record_solid(solid, source_range, imported_from),
+            KclValue::Solids { value } => {
+                for solid in value {
+                    self.record_solid(solid, source_range, imported_from);
+                }
+            }
+            KclValue::Face(face) => self.record_solid(&face.solid, source_range, imported_from),
+            _ => {}
+        }
+    }
+
+    fn record_plane(&mut self, plane: &Plane, source_range: SourceRange, imported_from: Option<SourceRange>) {
+        let source_range = imported_from
+            .or_else(|| plane.meta.first().map(|meta| meta.source_range))
+            .unwrap_or(source_range);
+        self.artifacts.entry(plane.id).or_insert_with(|| {
+            Artifact::Plane(PlaneArtifact {
+                id: plane.id,
+                path_ids: vec![],
+                source_range,
+            })
+        });
+    }
+
+    /// Record a sketch and its segments, see [`ArtifactGraph::record`] for `imported_from`.
+    pub(crate) fn record_sketch(&mut self, sketch: &Sketch, imported_from: Option<SourceRange>) {
+        let plane_id = sketch.on.id();
+        let source_range = imported_from.unwrap_or(sketch.start.geo_meta.metadata.source_range);
+        match &sketch.on {
+            SketchSurface::Plane(plane) => self.record_plane(plane, source_range, imported_from),
+            SketchSurface::Face(face) => self.record_solid(&face.solid, source_range, imported_from),
+        }
+        match self.artifacts.get_mut(&plane_id) {
+            Some(Artifact::Plane(PlaneArtifact { path_ids, .. }))
+            | Some(Artifact::Wall(WallArtifact { path_ids, .. }))
+            | Some(Artifact::Cap(CapArtifact { path_ids, .. })) => push_new(path_ids, sketch.id),
+            _ => {}
+        }
+
+        let mut seg_ids = vec![];
+        for path in &sketch.paths {
+            let base = path.get_base();
+            let id = base.geo_meta.id;
+            seg_ids.push(id);
+            self.artifacts.entry(id).or_insert_with(|| {
+                Artifact::Segment(SegmentArtifact {
+                    id,
+                    path_id: sketch.id,
+                    surface_id: None,
+                    edge_ids: vec![],
+                    edge_cut_id: None,
+                    source_range: imported_from.unwrap_or(base.geo_meta.metadata.source_range),
+                })
+            });
+        }
+        match self.artifacts.get_mut(&sketch.id) {
+            Some(Artifact::Path(path)) => path.seg_ids = seg_ids,
+            _ => {
+                self.artifacts.insert(
+                    sketch.id,
+                    Artifact::Path(PathArtifact {
+                        id: sketch.id,
+                        plane_id,
+                        seg_ids,
+                        sweep: None,
+                        source_range,
+                    }),
+                );
+            }
+        }
+    }
+
+    fn record_solid(&mut self, solid: &Solid, source_range: SourceRange, imported_from: Option<SourceRange>) {
+        if solid.id != solid.sketch.id {
+            // A copy made by a pattern, or by moving a solid, whose faces the engine doesn't
+            // tell us.
+            self.artifacts.entry(solid.id).or_insert_with(|| {
+                Artifact::Path(PathArtifact {
+                    id: solid.id,
+                    plane_id: solid.sketch.on.id(),
+                    seg_ids: vec![],
+                    sweep: Some(Sweep {
+                        surface_ids: vec![],
+                        edge_ids: vec![],
+                        edge_cut_ids: vec![],
+                        source_range,
+                    }),
+                    source_range,
+                })
+            });
+            return;
+        }
+        let sweep_id = match &solid.sketch.on {
+            // Extruding a sketch on a face adds to that face's solid and takes its id, so the
+            // sketch's own path is found from its segments.
+            SketchSurface::Face(face) if face.solid.id == solid.id => {
+                let Some(Artifact::Segment(segment)) = solid
+                    .sketch
+                    .paths
+                    .first()
+                    .and_then(|path| self.artifacts.get(&path.get_base().geo_meta.id))
+                else {
+                    return;
+                };
+                segment.path_id
+            }
+            _ => {
+                self.record_sketch(&solid.sketch, imported_from);
+                solid.id
+            }
+        };
+
+        let mut surface_ids = vec![];
+        for surface in &solid.value {
+            let (ExtrudeSurface::ExtrudePlane(_) | ExtrudeSurface::ExtrudeArc(_)) = surface else {
+                // Fillet and chamfer faces are their edge cuts.
+                continue;
+            };
+            let (id, seg_id) = (surface.face_id(), surface.get_id());
+            surface_ids.push(id);
+            self.artifacts.entry(id).or_insert_with(|| {
+                Artifact::Wall(WallArtifact {
+                    id,
+                    seg_id,
+                    sweep_id,
+                    path_ids: vec![],
+                })
+            });
+            if let Some(Artifact::Segment(segment)) = self.artifacts.get_mut(&seg_id) {
+                segment.surface_id = Some(id);
+            }
+        }
+        for (id, sub_type) in [
+            (solid.start_cap_id, CapSubType::Start),
+            (solid.end_cap_id, CapSubType::End),
+        ] {
+            let Some(id) = id else {
+                continue;
+            };
+            surface_ids.push(id);
+            self.artifacts.entry(id).or_insert_with(|| {
+                Artifact::Cap(CapArtifact {
+                    id,
+                    sub_type,
+                    sweep_id,
+                    path_ids: vec![],
+                })
+            });
+        }
+
+        let mut edge_cut_ids = vec![];
+        for edge_cut in &solid.edge_cuts {
+            let (id, consumed_edge_id) = (edge_cut.id(), edge_cut.edge_id());
+            edge_cut_ids.push(id);
+            let sub_type = match edge_cut {
+                EdgeCut::Fillet { .. } => EdgeCutSubType::Fillet,
+                EdgeCut::Chamfer { .. } => EdgeCutSubType::Chamfer,
+            };
+            self.artifacts.entry(id).or_insert_with(|| {
+                Artifact::EdgeCut(EdgeCutArtifact {
+                    id,
+                    sub_type,
+                    consumed_edge_id,
+                    solid_id: solid.id,
+                    source_range,
+                })
+            });
+            if let Some(Artifact::Segment(segment)) = self.artifacts.get_mut(&consumed_edge_id) {
+                segment.edge_cut_id = Some(id);
+            }
+        }
+
+        if let Some(Artifact::Path(path)) = self.artifacts.get_mut(&sweep_id) {
+            let sweep = path.sweep.get_or_insert_with(|| Sweep {
+                surface_ids: vec![],
+                edge_ids: vec![],
+                edge_cut_ids: vec![],
+                source_range,
+            });
+            for id in surface_ids {
+                push_new(&mut sweep.surface_ids, id);
+            }
+            for id in edge_cut_ids {
+                push_new(&mut sweep.edge_cut_ids, id);
+            }
+        }
+    }
+
+    /// Record an edge of a solid which was looked up from one of its segments.
+    pub(crate) fn record_edge(&mut self, id: Uuid, seg_id: Uuid, solid_id: Uuid, sub_type: EdgeSubType) {
+        self.artifacts.entry(id).or_insert(Artifact::Edge(EdgeArtifact {
+            id,
+            seg_id,
+            solid_id,
+            sub_type,
+        }));
+        if let Some(Artifact::Segment(segment)) = self.artifacts.get_mut(&seg_id) {
+            push_new(&mut segment.edge_ids, id);
+        }
+        if let Some(Artifact::Path(PathArtifact { sweep: Some(sweep), .. })) = self.artifacts.get_mut(&solid_id) {
+            push_new(&mut sweep.edge_ids, id);
+        }
+    }
+}
+
+fn push_new(ids: &mut Vec<Uuid>, id: Uuid) {
+    if !ids.contains(&id) {
+        ids.push(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::{ExecutorContext, IdGenerator};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_artifacts_link_to_code() {
+        let code = r#"const part = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %)
+  |> line([0, 10], %)
+  |> close(%)
+  |> extrude(5, %)
+"#;
+        let program = crate::parser::parse(code).unwrap();
+        let ctx = ExecutorContext::new_mock(Default::default()).await.unwrap();
+        let exec_state = ctx.run(&program, None, IdGenerator::default(), None).await.unwrap();
+        let graph = &exec_state.artifact_graph;
+
+        // The segment's code finds the segment, and its wall.
+        let line = code.find("line([0, 10]").unwrap();
+        let at_line = graph.artifacts_at(line + 1);
+        let Some(segment) = at_line.iter().find_map(|id| match graph.get(*id) {
+            Some(Artifact::Segment(segment)) => Some(segment),
+            _ => None,
+        }) else {
+            panic!("expected a segment at the second line");
+        };
+        assert!(at_line.contains(&segment.surface_id.unwrap()));
+        assert_eq!(graph.source_range(segment.id).unwrap().start(), line);
+
+        // Its wall leads back to it, and the solid it's part of leads to the extrude.
+        let wall = segment.surface_id.unwrap();
+        assert_eq!(graph.source_range(wall), graph.source_range(segment.id));
+        let extrude = code.find("extrude").unwrap();
+        assert_eq!(graph.source_range(segment.path_id).unwrap().start(), extrude);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_imported_geometry_links_to_the_call() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("part.kcl"),
+            r#"export fn part = () => {
+  return startSketchOn('XY')
+    |> startProfileAt([0, 0], %)
+    |> line([10, 0], %)
+    |> line([0, 10], %)
+    |> close(%)
+    |> extrude(5, %)
+}
+"#,
+        )
+        .unwrap();
+        let code = r#"import part from "part.kcl"
+
+const one = part()
+const two = instance(part)
+"#;
+        let program = crate::parser::parse(code).unwrap();
+        let ctx = ExecutorContext::new_mock(Default::default()).await.unwrap();
+        let exec_state = ctx
+            .run(
+                &program,
+                None,
+                IdGenerator::default(),
+                Some(dir.path().to_string_lossy().to_string()),
+            )
+            .await
+            .unwrap();
+        let graph = &exec_state.artifact_graph;
+
+        // Everything made in the other file is found from the call in this file which ran it.
+        let calls = [code.find("part()").unwrap(), code.find("instance").unwrap()];
+        for id in graph.artifacts.keys() {
+            let start = graph.source_range(*id).unwrap().start();
+            assert!(calls.contains(&start), "{id} was made at {start}");
+        }
+        for call in calls {
+            let paths = graph
+                .artifacts_at(call)
+                .into_iter()
+                .filter(|id| matches!(graph.get(*id), Some(Artifact::Path(_))))
+                .count();
+            assert_eq!(paths, 1);
+        }
+    }
+}
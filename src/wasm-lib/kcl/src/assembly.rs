@@ -0,0 +1,323 @@
+//! Assemblies: parts defined in other KCL files, instanced with placements and mates.
+//!
+//! Every function imported from another file is a part definition. Calling `instance` on one
+//! builds the part, moves it into place and records it in the [`Assembly`] kept in the
+//! [`ExecState`](crate::executor::ExecState). Parts which instance other parts while they're
+//! built become subassemblies, so the instances form a tree.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ast::types::FunctionExpression,
+    errors::{KclError, KclErrorDetails},
+    executor::{Metadata, Point3d, Solid, SourceRange, Transform3d},
+    std::{
+        datums::{cross, dot, face_frame, norm, normalize, point3d, sub, Axis3d, Frame},
+        sketch::FaceTag,
+    },
+};
+
+/// The parts and instances of the program.
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Assembly {
+    /// The parts which were imported.
+    pub parts: Vec<PartDefinition>,
+    /// The instances at the top of the tree, in the order they were made.
+    pub instances: Vec<Instance>,
+}
+
+impl Assembly {
+    /// Record a function imported from another file as a part. Importing the same function
+    /// again, from another file or under another name, doesn't make a new part.
+    pub(crate) fn add_part(&mut self, name: &str, path: &str, expression: &FunctionExpression) {
+        if self.part(expression).is_none() {
+            self.parts.push(PartDefinition {
+                name: name.to_string(),
+                path: path.to_string(),
+                expression: Box::new(expression.clone()),
+            });
+        }
+    }
+
+    /// The part which the function builds, if it was imported.
+    pub(crate) fn part(&self, expression: &FunctionExpression) -> Option<&PartDefinition> {
+        self.parts.iter().find(|part| *part.expression == *expression)
+    }
+
+    /// Every instance in the tree, parents before their children.
+    pub fn all_instances(&self) -> Vec<&Instance> {
+        fn walk<'a>(instances: &'a [Instance], all: &mut Vec<&'a Instance>) {
+            for instance in instances {
+                all.push(instance);
+                walk(&instance.children, all);
+            }
+        }
+        let mut all = vec![];
+        walk(&self.instances, &mut all);
+        all
+    }
+}
+
+/// A part: a function imported from another file, which builds a solid.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PartDefinition {
+    /// The name the function was imported as.
+    pub name: String,
+    /// The path of the file the function was imported from.
+    pub path: String,
+    /// The function which builds the part.
+    pub expression: Box<FunctionExpression>,
+}
+
+/// A part built and put in place.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Instance {
+    /// The name of the instance, unique among its siblings.
+    pub name: String,
+    /// The name of the part it is an instance of.
+    pub part: String,
+    /// The ids of the solids or imported geometry the part was built as.
+    pub ids: Vec<uuid::Uuid>,
+    /// Where the instance was moved to from where the part builds it.
+    #[serde(default, skip_serializing_if = "Transform3d::is_identity")]
+    pub transform: Transform3d,
+    /// The instances made while building the part, if it is a subassembly.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Instance>,
+    #[serde(rename = "__meta")]
+    pub meta: Vec<Metadata>,
+}
+
+impl Instance {
+    /// Record that the instance, and so everything in it, was moved.
+    pub(crate) fn moved(&mut self, transform: &Transform3d) {
+        self.transform = self.transform.then(transform);
+        for child in &mut self.children {
+            child.moved(transform);
+        }
+    }
+}
+
+/// A face of a part, by `'start'`, `'end'`, its id or the name of its tag.
+///
+/// Tags made inside a part's function can't be used outside it, so they're given by name.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum PartFace {
+    /// The start or end face, a face's id or a tag in scope.
+    Face(FaceTag),
+    /// The name of a tag on the part.
+    Name(String),
+}
+
+impl PartFace {
+    fn frame(&self, solid: &Solid, source_range: SourceRange) -> Result<Frame, KclError> {
+        let face = match self {
+            PartFace::Face(face) => face.clone(),
+            PartFace::Name(name) => {
+                let tag = solid.sketch.tags.get(name).ok_or_else(|| {
+                    KclError::Type(KclErrorDetails {
+                        message: format!("Expected the part to have a face tagged `{}`", name),
+                        source_ranges: vec![source_range],
+                    })
+                })?;
+                FaceTag::Tag(Box::new(tag.clone()))
+            }
+        };
+        face_frame(solid, &face, source_range)
+    }
+}
+
+/// How to put an instance in place relative to other geometry.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Mate {
+    /// Put a planar face of the instance against a planar face of another solid, facing it.
+    Coincident {
+        /// The face of the instance.
+        face: PartFace,
+        /// The solid to put the instance against.
+        to: Box<Solid>,
+        /// The face of that solid.
+        #[serde(rename = "toFace")]
+        to_face: PartFace,
+    },
+    /// Line up an axis of the instance with another axis, pointing the same way.
+    Concentric {
+        /// The axis of the instance, where the part builds it.
+        axis: Axis3d,
+        /// The axis to line it up with.
+        to: Axis3d,
+    },
+}
+
+impl Mate {
+    /// The move which puts the instance in place, once it's already been moved by `placed`.
+    pub(crate) fn solve(
+        &self,
+        solid: Option<&Solid>,
+        placed: &Transform3d,
+        source_range: SourceRange,
+    ) -> Result<Transform3d, KclError> {
+        match self {
+            Mate::Coincident { face, to, to_face } => {
+                let solid = solid.ok_or_else(|| {
+                    KclError::Semantic(KclErrorDetails {
+                        message: "Only parts which build solids can be mated by their faces".to_string(),
+                        source_ranges: vec![source_range],
+                    })
+                })?;
+                let from = face.frame(solid, source_range)?;
+                let to = to_face.frame(to, source_range)?;
+                let to = Frame {
+                    origin: to.origin,
+                    x_axis: to.x_axis,
+                    y_axis: sub(Point3d::ZERO, to.y_axis),
+                    z_axis: sub(Point3d::ZERO, to.z_axis),
+                };
+                let err = || {
+                    KclError::Semantic(KclErrorDetails {
+                        message: "The faces to mate must have area".to_string(),
+                        source_ranges: vec![source_range],
+                    })
+                };
+                Ok(rigid(&from)
+                    .ok_or_else(err)?
+                    .inverse()
+                    .then(&rigid(&to).ok_or_else(err)?.0))
+            }
+            Mate::Concentric { axis, to } => {
+                let origin = placed.transform_point(point3d(axis.origin));
+                let direction = placed.transform_vector(point3d(axis.direction));
+                let (Some(direction), Some(to_direction)) = (normalize(direction), normalize(point3d(to.direction)))
+                else {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: "The axes to mate must have directions which aren't the zero vector".to_string(),
+                        source_ranges: vec![source_range],
+                    }));
+                };
+                Ok(turn_onto(direction, to_direction, origin)
+                    .then(&Transform3d::translation(sub(point3d(to.origin), origin))))
+            }
+        }
+    }
+}
+
+/// A rigid transform, which puts the global axes onto the frame's.
+struct Rigid(Transform3d);
+
+impl Rigid {
+    fn inverse(&self) -> Transform3d {
+        let m = &self.0 .0;
+        let mut inverse = Transform3d::IDENTITY;
+        for (i, row) in inverse.0.iter_mut().take(3).enumerate() {
+            for (j, cell) in row.iter_mut().take(3).enumerate() {
+                *cell = m[j][i];
+            }
+            row[3] = -m.iter().take(3).map(|r| r[i] * r[3]).sum::<f64>();
+        }
+        inverse
+    }
+}
+
+/// The frame with its axes made unit length and square to each other, keeping its X axis and
+/// its plane.
+fn rigid(frame: &Frame) -> Option<Rigid> {
+    let x = normalize(frame.x_axis)?;
+    let z = normalize(cross(frame.x_axis, frame.y_axis))?;
+    let y = cross(z, x);
+    let o = frame.origin;
+    Some(Rigid(Transform3d([
+        [x.x, y.x, z.x, o.x],
+        [x.y, y.y, z.y, o.y],
+        [x.z, y.z, z.z, o.z],
+        [0.0, 0.0, 0.0, 1.0],
+    ])))
+}
+
+/// The smallest turn about `origin` which points the unit vector `from` along the unit vector `to`.
+fn turn_onto(from: Point3d, to: Point3d, origin: Point3d) -> Transform3d {
+    let axis = cross(from, to);
+    let angle = norm(axis).atan2(dot(from, to)).to_degrees();
+    if norm(axis) > 1e-12 {
+        return Transform3d::rotation(axis, angle, origin).unwrap_or_default();
+    }
+    if dot(from, to) > 0.0 {
+        return Transform3d::IDENTITY;
+    }
+    // Pointing opposite ways, so turn half way round about any axis square to them.
+    let other = if from.x.abs() < 0.9 {
+        Point3d::new(1.0, 0.0, 0.0)
+    } else {
+        Point3d::new(0.0, 1.0, 0.0)
+    };
+    Transform3d::rotation(cross(from, other), 180.0, origin).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Point3d, b: Point3d) {
+        assert!(norm(sub(a, b)) < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_rigid_inverse() {
+        let frame = Frame {
+            origin: Point3d::new(1.0, 2.0, 3.0),
+            x_axis: Point3d::new(0.0, 1.0, 0.0),
+            y_axis: Point3d::new(0.0, 0.0, 1.0),
+            z_axis: Point3d::new(1.0, 0.0, 0.0),
+        };
+        let rigid = rigid(&frame).unwrap();
+        let round_trip = rigid.0.then(&rigid.inverse());
+        for p in [Point3d::ZERO, Point3d::new(4.0, -5.0, 6.0)] {
+            assert_close(round_trip.transform_point(p), p);
+        }
+        assert_close(
+            rigid.0.transform_point(Point3d::new(1.0, 0.0, 0.0)),
+            Point3d::new(1.0, 3.0, 3.0),
+        );
+    }
+
+    #[test]
+    fn test_concentric() {
+        // A pin along X through [0, 0, 5], lined up with a hole along -Z through [10, 10, 0].
+        let mate = Mate::Concentric {
+            axis: Axis3d {
+                origin: [0.0, 0.0, 5.0],
+                direction: [1.0, 0.0, 0.0],
+            },
+            to: Axis3d {
+                origin: [10.0, 10.0, 0.0],
+                direction: [0.0, 0.0, -1.0],
+            },
+        };
+        let m = mate
+            .solve(None, &Transform3d::IDENTITY, SourceRange::default())
+            .unwrap();
+        assert_close(
+            m.transform_point(Point3d::new(0.0, 0.0, 5.0)),
+            Point3d::new(10.0, 10.0, 0.0),
+        );
+        assert_close(
+            m.transform_point(Point3d::new(2.0, 0.0, 5.0)),
+            Point3d::new(10.0, 10.0, -2.0),
+        );
+
+        for (from, to) in [([0.0, 0.0, 1.0], [0.0, 0.0, -1.0]), ([1.0, 0.0, 0.0], [1.0, 0.0, 0.0])] {
+            let turn = turn_onto(point3d(from), point3d(to), Point3d::ZERO);
+            assert_close(turn.transform_vector(point3d(from)), point3d(to));
+        }
+    }
+}
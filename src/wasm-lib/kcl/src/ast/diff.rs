@@ -0,0 +1,570 @@
+//! Semantic diff and three-way merge of KCL programs.
+//!
+//! Top-level statements are matched by name (variables and functions), by path (imports) or by
+//! content (bare expressions), and compared with their position-independent digests, so moving
+//! code around or reformatting it does not register as a change.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ast::types::{BodyItem, Expr, FormatOptions, NonCodeNode, Program},
+    errors::KclError,
+};
+
+/// A change to a top-level statement between two versions of a program.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AstChange {
+    /// A statement that only exists in the new program.
+    Added { name: String, source: String },
+    /// A statement that only exists in the old program.
+    Removed { name: String, source: String },
+    /// A statement that exists in both programs but differs.
+    Changed {
+        name: String,
+        before: String,
+        after: String,
+        /// The call arguments that changed, if the statement kept its shape.
+        arguments: Vec<ArgumentChange>,
+    },
+}
+
+/// A changed argument of a call inside a changed statement.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ArgumentChange {
+    /// The name of the function being called.
+    pub call: String,
+    /// The index of the argument.
+    pub index: usize,
+    pub before: String,
+    pub after: String,
+}
+
+impl fmt::Display for AstChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AstChange::Added { name, .. } => write!(f, "+ {}", name),
+            AstChange::Removed { name, .. } => write!(f, "- {}", name),
+            AstChange::Changed { name, arguments, .. } => {
+                write!(f, "~ {}", name)?;
+                for arg in arguments {
+                    write!(
+                        f,
+                        "\n    {} argument {}: {} -> {}",
+                        arg.call, arg.index, arg.before, arg.after
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The statement-level differences between two programs.
+pub fn diff(old: &Program, new: &Program) -> Vec<AstChange> {
+    let old_items = keyed_items(old);
+    let new_items = keyed_items(new);
+    let mut changes = Vec::new();
+
+    for (name, old_item) in &old_items {
+        match new_items.get(name) {
+            None => changes.push(AstChange::Removed {
+                name: name.clone(),
+                source: recast_item(&old_item.item),
+            }),
+            Some(new_item) if digest_item(&old_item.item) != digest_item(&new_item.item) => {
+                let mut arguments = Vec::new();
+                if let (Some(before), Some(after)) = (item_expr(&old_item.item), item_expr(&new_item.item)) {
+                    diff_arguments(before, after, &mut arguments);
+                }
+                changes.push(AstChange::Changed {
+                    name: name.clone(),
+                    before: recast_item(&old_item.item),
+                    after: recast_item(&new_item.item),
+                    arguments,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (name, new_item) in &new_items {
+        if !old_items.contains_key(name) {
+            changes.push(AstChange::Added {
+                name: name.clone(),
+                source: recast_item(&new_item.item),
+            });
+        }
+    }
+
+    changes
+}
+
+/// The result of a three-way merge.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeResult {
+    /// The merged source. Conflicting statements are wrapped in git-style conflict markers.
+    pub source: String,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeResult {
+    /// Did every edit merge without conflicts?
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// A statement both sides edited in incompatible ways.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeConflict {
+    pub name: String,
+    pub base: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+}
+
+/// Merge the edits `ours` and `theirs` made to `base`.
+///
+/// Statements only one side touched take that side's version. When both sides changed the same
+/// statement, calls, pipes, arrays and objects with the same shape are merged argument by argument,
+/// so edits to different arguments of the same pipeline still merge cleanly.
+pub fn merge(base: &Program, ours: &Program, theirs: &Program) -> Result<MergeResult, KclError> {
+    let base_items = keyed_items(base);
+    let our_items = keyed_items(ours);
+    let their_items = keyed_items(theirs);
+
+    // Start from our order, then slot in statements only they have right after their predecessor.
+    let mut order: Vec<String> = our_items.keys().cloned().collect();
+    for (i, name) in their_items.keys().enumerate() {
+        if order.contains(name) {
+            continue;
+        }
+        let position = their_items
+            .keys()
+            .take(i)
+            .rev()
+            .find_map(|previous| order.iter().position(|n| n == previous))
+            .map(|p| p + 1)
+            .unwrap_or(0);
+        order.insert(position, name.clone());
+    }
+
+    let mut merged: Vec<KeyedItem> = Vec::new();
+    let mut conflicts: Vec<(usize, MergeConflict)> = Vec::new();
+    for name in order {
+        let base_item = base_items.get(&name).map(|k| &k.item);
+        let our_item = our_items.get(&name);
+        let their_item = their_items.get(&name);
+
+        let resolved = match (base_item, our_item, their_item) {
+            (_, Some(o), Some(t)) => match base_item {
+                Some(b) => merge_item(b, &o.item, &t.item).map(|item| Some(o.with_item(item))),
+                None if digest_item(&o.item) == digest_item(&t.item) => Some(Some(o.clone())),
+                None => None,
+            },
+            // Deleted on their side, untouched on ours.
+            (Some(b), Some(o), None) if digest_item(b) == digest_item(&o.item) => Some(None),
+            // Deleted on our side, untouched on theirs.
+            (Some(b), None, Some(t)) if digest_item(b) == digest_item(&t.item) => Some(None),
+            (None, Some(o), None) => Some(Some(o.clone())),
+            (None, None, Some(t)) => Some(Some(t.clone())),
+            (_, None, None) => Some(None),
+            // Deleted on one side, changed on the other.
+            _ => None,
+        };
+
+        match resolved {
+            Some(Some(item)) => merged.push(item),
+            Some(None) => {}
+            None => {
+                conflicts.push((
+                    merged.len(),
+                    MergeConflict {
+                        name,
+                        base: base_item.map(recast_item),
+                        ours: our_item.map(|k| recast_item(&k.item)),
+                        theirs: their_item.map(|k| recast_item(&k.item)),
+                    },
+                ));
+                // Keep a placeholder in the program so the markers land in the right place.
+                if let Some(item) = our_item.or(their_item) {
+                    merged.push(item.clone());
+                }
+            }
+        }
+    }
+
+    let mut program = Program {
+        non_code_meta: ours.non_code_meta.clone(),
+        ..Default::default()
+    };
+    program.non_code_meta.non_code_nodes.clear();
+    for (i, keyed) in merged.into_iter().enumerate() {
+        if !keyed.trailing.is_empty() {
+            program.non_code_meta.non_code_nodes.insert(i, keyed.trailing);
+        }
+        program.body.push(keyed.item);
+    }
+
+    let mut source = program.recast(&FormatOptions::default(), 0);
+    if !conflicts.is_empty() {
+        // Re-parse to find where each conflicting statement was printed, then splice in the markers
+        // back to front so earlier ranges stay valid.
+        let reparsed = crate::parser::parse(&source)?;
+        for (index, conflict) in conflicts.iter().rev() {
+            let markers = format!(
+                "<<<<<<< ours\n{}\n=======\n{}\n>>>>>>> theirs",
+                conflict.ours.as_deref().unwrap_or_default(),
+                conflict.theirs.as_deref().unwrap_or_default()
+            );
+            match reparsed.body.get(*index) {
+                Some(item) => source.replace_range(item.start()..item.end(), &markers),
+                None => {
+                    source.push('\n');
+                    source.push_str(&markers);
+                }
+            }
+        }
+    }
+
+    Ok(MergeResult {
+        source,
+        conflicts: conflicts.into_iter().map(|(_, conflict)| conflict).collect(),
+    })
+}
+
+/// A top-level statement together with the comments that follow it.
+#[derive(Debug, Clone)]
+struct KeyedItem {
+    item: BodyItem,
+    trailing: Vec<NonCodeNode>,
+}
+
+impl KeyedItem {
+    fn with_item(&self, item: BodyItem) -> Self {
+        Self {
+            item,
+            trailing: self.trailing.clone(),
+        }
+    }
+}
+
+fn keyed_items(program: &Program) -> IndexMap<String, KeyedItem> {
+    let mut items = IndexMap::new();
+    for (i, item) in program.body.iter().enumerate() {
+        let base_name = match item {
+            BodyItem::VariableDeclaration(declaration) => declaration
+                .declarations
+                .first()
+                .map(|d| d.id.name.clone())
+                .unwrap_or_default(),
+            BodyItem::ImportStatement(import) => format!("import {}", import.path),
+            BodyItem::ExpressionStatement(_) | BodyItem::ReturnStatement(_) => {
+                let digest = digest_item(item);
+                let short = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+                format!("<expression {:08x}>", short)
+            }
+        };
+
+        // Identical bare expressions can appear more than once.
+        let mut name = base_name.clone();
+        let mut n = 1;
+        while items.contains_key(&name) {
+            n += 1;
+            name = format!("{}#{}", base_name, n);
+        }
+
+        items.insert(
+            name,
+            KeyedItem {
+                item: item.clone(),
+                trailing: program
+                    .non_code_meta
+                    .non_code_nodes
+                    .get(&i)
+                    .cloned()
+                    .unwrap_or_default(),
+            },
+        );
+    }
+    items
+}
+
+fn merge_item(base: &BodyItem, ours: &BodyItem, theirs: &BodyItem) -> Option<BodyItem> {
+    let (b, o, t) = (digest_item(base), digest_item(ours), digest_item(theirs));
+    if o == t || t == b {
+        return Some(ours.clone());
+    }
+    if o == b {
+        return Some(theirs.clone());
+    }
+
+    match (base, ours, theirs) {
+        (BodyItem::VariableDeclaration(b), BodyItem::VariableDeclaration(o), BodyItem::VariableDeclaration(t))
+            if o.kind == t.kind && o.visibility == t.visibility =>
+        {
+            let init = merge_expr(
+                &b.declarations.first()?.init,
+                &o.declarations.first()?.init,
+                &t.declarations.first()?.init,
+            )?;
+            let mut merged = o.clone();
+            merged.declarations.first_mut()?.init = init;
+            merged.digest = None;
+            Some(BodyItem::VariableDeclaration(merged))
+        }
+        _ => None,
+    }
+}
+
+fn merge_expr(base: &Expr, ours: &Expr, theirs: &Expr) -> Option<Expr> {
+    let (b, o, t) = (digest_expr(base), digest_expr(ours), digest_expr(theirs));
+    if o == t || t == b {
+        return Some(ours.clone());
+    }
+    if o == b {
+        return Some(theirs.clone());
+    }
+
+    match (base, ours, theirs) {
+        (Expr::CallExpression(b), Expr::CallExpression(o), Expr::CallExpression(t))
+            if b.callee.name == o.callee.name && o.callee.name == t.callee.name =>
+        {
+            let mut merged = o.clone();
+            merged.arguments = merge_exprs(&b.arguments, &o.arguments, &t.arguments)?;
+            merged.digest = None;
+            Some(Expr::CallExpression(merged))
+        }
+        (Expr::PipeExpression(b), Expr::PipeExpression(o), Expr::PipeExpression(t)) => {
+            let mut merged = o.clone();
+            merged.body = merge_exprs(&b.body, &o.body, &t.body)?;
+            merged.digest = None;
+            Some(Expr::PipeExpression(merged))
+        }
+        (Expr::ArrayExpression(b), Expr::ArrayExpression(o), Expr::ArrayExpression(t)) => {
+            let mut merged = o.clone();
+            merged.elements = merge_exprs(&b.elements, &o.elements, &t.elements)?;
+            merged.digest = None;
+            Some(Expr::ArrayExpression(merged))
+        }
+        (Expr::ObjectExpression(b), Expr::ObjectExpression(o), Expr::ObjectExpression(t))
+            if b.properties.len() == o.properties.len()
+                && o.properties.len() == t.properties.len()
+                && b.properties
+                    .iter()
+                    .zip(&o.properties)
+                    .zip(&t.properties)
+                    .all(|((b, o), t)| b.key.name == o.key.name && o.key.name == t.key.name) =>
+        {
+            let mut merged = o.clone();
+            for ((property, b), t) in merged.properties.iter_mut().zip(&b.properties).zip(&t.properties) {
+                property.value = merge_expr(&b.value, &property.value, &t.value)?;
+                property.digest = None;
+            }
+            merged.digest = None;
+            Some(Expr::ObjectExpression(merged))
+        }
+        _ => None,
+    }
+}
+
+/// Merge lists of the same length element by element.
+fn merge_exprs(base: &[Expr], ours: &[Expr], theirs: &[Expr]) -> Option<Vec<Expr>> {
+    if base.len() != ours.len() || ours.len() != theirs.len() {
+        return None;
+    }
+    base.iter()
+        .zip(ours)
+        .zip(theirs)
+        .map(|((b, o), t)| merge_expr(b, o, t))
+        .collect()
+}
+
+/// Record the arguments that changed between calls of the same shape.
+fn diff_arguments(before: &Expr, after: &Expr, changes: &mut Vec<ArgumentChange>) {
+    match (before, after) {
+        (Expr::CallExpression(b), Expr::CallExpression(a))
+            if b.callee.name == a.callee.name && b.arguments.len() == a.arguments.len() =>
+        {
+            for (index, (b_arg, a_arg)) in b.arguments.iter().zip(&a.arguments).enumerate() {
+                if digest_expr(b_arg) != digest_expr(a_arg) {
+                    changes.push(ArgumentChange {
+                        call: a.callee.name.clone(),
+                        index,
+                        before: recast_expr(b_arg),
+                        after: recast_expr(a_arg),
+                    });
+                }
+            }
+        }
+        (Expr::PipeExpression(b), Expr::PipeExpression(a)) if b.body.len() == a.body.len() => {
+            for (b, a) in b.body.iter().zip(&a.body) {
+                diff_arguments(b, a, changes);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn item_expr(item: &BodyItem) -> Option<&Expr> {
+    match item {
+        BodyItem::ImportStatement(_) => None,
+        BodyItem::ExpressionStatement(statement) => Some(&statement.expression),
+        BodyItem::VariableDeclaration(declaration) => declaration.declarations.first().map(|d| &d.init),
+        BodyItem::ReturnStatement(statement) => Some(&statement.argument),
+    }
+}
+
+fn digest_item(item: &BodyItem) -> [u8; 32] {
+    item.clone().compute_digest()
+}
+
+fn digest_expr(expr: &Expr) -> [u8; 32] {
+    expr.clone().compute_digest()
+}
+
+fn recast_item(item: &BodyItem) -> String {
+    Program {
+        body: vec![item.clone()],
+        ..Default::default()
+    }
+    .recast(&FormatOptions::default(), 0)
+    .trim_end()
+    .to_string()
+}
+
+fn recast_expr(expr: &Expr) -> String {
+    expr.recast(&FormatOptions::default(), 0, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const BASE: &str = r#"const width = 10
+const height = 5
+
+// The plate.
+const plate = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([width, 0], %)
+  |> line([0, height], %)
+  |> close(%)
+  |> extrude(2, %)
+"#;
+
+    fn parse(code: &str) -> Program {
+        crate::parser::parse(code).unwrap()
+    }
+
+    #[test]
+    fn test_diff_ignores_formatting_and_order() {
+        let reordered = r#"const height = 5
+const width =   10
+
+// The plate.
+const plate = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([width, 0], %)
+  |> line([0, height], %)
+  |> close(%)
+  |> extrude(2, %)
+"#;
+        assert_eq!(diff(&parse(BASE), &parse(reordered)), vec![]);
+    }
+
+    #[test]
+    fn test_diff_reports_changes() {
+        let new = BASE
+            .replace("const height = 5\n", "")
+            .replace("extrude(2, %)", "extrude(4, %)")
+            .replace("[0, height]", "[0, 5]")
+            + "const depth = 3\n";
+
+        let changes = diff(&parse(BASE), &parse(&new));
+        assert_eq!(changes.len(), 3);
+        assert_eq!(
+            changes[0],
+            AstChange::Removed {
+                name: "height".to_string(),
+                source: "height = 5".to_string(),
+            }
+        );
+        let AstChange::Changed { name, arguments, .. } = &changes[1] else {
+            panic!("expected a change, got {:?}", changes[1]);
+        };
+        assert_eq!(name, "plate");
+        assert_eq!(
+            arguments,
+            &vec![
+                ArgumentChange {
+                    call: "line".to_string(),
+                    index: 0,
+                    before: "[0, height]".to_string(),
+                    after: "[0, 5]".to_string(),
+                },
+                ArgumentChange {
+                    call: "extrude".to_string(),
+                    index: 0,
+                    before: "2".to_string(),
+                    after: "4".to_string(),
+                },
+            ]
+        );
+        assert_eq!(changes[2].to_string(), "+ depth");
+    }
+
+    #[test]
+    fn test_merge_non_overlapping_edits() {
+        let ours = BASE.replace("extrude(2, %)", "extrude(4, %)");
+        let theirs = BASE.replace("[width, 0]", "[width * 2, 0]") + "const depth = 3\n";
+
+        let result = merge(&parse(BASE), &parse(&ours), &parse(&theirs)).unwrap();
+        assert!(result.is_clean());
+        assert!(result.source.starts_with("width = 10\nheight = 5\n"));
+        assert!(result.source.contains("// The plate."));
+        assert!(result.source.contains("  |> line([width * 2, 0], %)\n"));
+        assert!(result.source.contains("  |> extrude(4, %)\n"));
+        assert!(result.source.ends_with("depth = 3\n"));
+    }
+
+    #[test]
+    fn test_merge_deletions() {
+        let ours = BASE.replace("const width = 10\n", "const width = 12\n");
+        let theirs = BASE.replace("const height = 5\n", "");
+
+        let result = merge(&parse(BASE), &parse(&ours), &parse(&theirs)).unwrap();
+        assert!(result.is_clean());
+        assert!(result.source.starts_with("width = 12\n"));
+        assert!(!result.source.contains("height = "));
+    }
+
+    #[test]
+    fn test_merge_conflict() {
+        let ours = BASE.replace("extrude(2, %)", "extrude(4, %)");
+        let theirs = BASE.replace("extrude(2, %)", "extrude(6, %)");
+
+        let result = merge(&parse(BASE), &parse(&ours), &parse(&theirs)).unwrap();
+        assert!(!result.is_clean());
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.conflicts[0].name, "plate");
+        assert!(result.source.contains("<<<<<<< ours\nplate = startSketchOn('XY')"));
+        assert!(result.source.contains("  |> extrude(4, %)\n=======\n"));
+        assert!(result.source.contains("  |> extrude(6, %)\n>>>>>>> theirs\n"));
+        assert!(result.source.starts_with("width = 10\n"));
+    }
+}
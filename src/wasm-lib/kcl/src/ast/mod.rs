@@ -1,2 +1,4 @@
+pub mod diff;
 pub mod modify;
+pub mod transaction;
 pub mod types;
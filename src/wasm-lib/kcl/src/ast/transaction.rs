@@ -0,0 +1,1014 @@
+//! Typed, bidirectional edits to a KCL program.
+//!
+//! Point-and-click features in the frontend need to turn a user action (add a fillet edge,
+//! drag a dimension, pull a value out into a variable) into a change to the source code.
+//! An [`AstTransaction`] applies a list of [`AstEdit`]s to a program's AST, recasts it once
+//! with the unparser (which keeps comments, since they live in the AST) and re-parses the
+//! result so every edit can report where its target ended up in the new source.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ast::types::{
+        BinaryExpression, BinaryPart, BodyItem, CallExpression, Expr, FormatOptions, Identifier, IfExpression,
+        ItemVisibility, Literal, LiteralIdentifier, LiteralValue, MemberExpression, MemberObject, NonCodeMeta,
+        PipeExpression, PipeSubstitution, Program, UnaryExpression, VariableDeclaration, VariableDeclarator,
+        VariableKind,
+    },
+    errors::{KclError, KclErrorDetails},
+    executor::SourceRange,
+    parser::PIPE_SUBSTITUTION_OPERATOR,
+};
+
+/// A single typed edit to a KCL program.
+///
+/// Every range refers to the program the transaction is applied to, before any of its edits.
+/// Arguments and edge references are written as KCL source, e.g. `"{ radius: 2, tags: [seg01] }"`
+/// or `"getOppositeEdge(seg02)"`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AstEdit {
+    /// Insert a call to a stdlib function into a pipe.
+    /// If the target is not a pipe yet, it becomes the first expression of a new pipe.
+    #[serde(rename_all = "camelCase")]
+    InsertPipeCall {
+        /// The pipe (or expression) to insert into.
+        pipe: SourceRange,
+        /// Where to insert the call. Defaults to the end of the pipe.
+        #[serde(default)]
+        index: Option<usize>,
+        /// The name of the function to call.
+        name: String,
+        /// The arguments of the call, as KCL source.
+        arguments: Vec<String>,
+    },
+    /// Add an edge reference to the `tags` of a `fillet` or `chamfer` call.
+    #[serde(rename_all = "camelCase")]
+    AddFilletTag {
+        /// The `fillet` or `chamfer` call.
+        call: SourceRange,
+        /// The edge reference to add, as KCL source.
+        tag: String,
+    },
+    /// Remove an edge reference from the `tags` of a `fillet` or `chamfer` call.
+    #[serde(rename_all = "camelCase")]
+    RemoveFilletTag {
+        /// The `fillet` or `chamfer` call.
+        call: SourceRange,
+        /// The edge reference to remove, as KCL source.
+        tag: String,
+    },
+    /// Change the value of a literal.
+    #[serde(rename_all = "camelCase")]
+    SetLiteral {
+        /// The literal to change.
+        range: SourceRange,
+        /// The new value.
+        value: LiteralValue,
+    },
+    /// Pull an expression out into a new constant declared just before the statement that uses it.
+    #[serde(rename_all = "camelCase")]
+    WrapInVariable {
+        /// The expression to wrap.
+        range: SourceRange,
+        /// The name of the new constant.
+        name: String,
+    },
+}
+
+/// The outcome of an [`AstTransaction`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct AstEditResult {
+    /// The new source code.
+    pub source: String,
+    /// The re-parsed program, with source ranges matching `source`.
+    pub program: Program,
+    /// For every edit, its target's range before the edit and the range of the edited node after it.
+    pub source_map: Vec<SourceRangeMapping>,
+}
+
+/// Where an edited node was before and after an edit.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceRangeMapping {
+    pub before: SourceRange,
+    pub after: SourceRange,
+}
+
+/// A list of edits applied to a program as a unit: either all of them apply, or none do.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct AstTransaction {
+    pub edits: Vec<AstEdit>,
+}
+
+impl AstTransaction {
+    pub fn new(edits: Vec<AstEdit>) -> Self {
+        Self { edits }
+    }
+
+    /// Apply every edit in order, then recast the program once. The given program is left
+    /// untouched.
+    pub fn apply(&self, program: &Program) -> Result<AstEditResult, KclError> {
+        // Find every target in the program as given, then keep the paths to them up to date
+        // as the edits change the shape of the tree.
+        let mut paths = self
+            .edits
+            .iter()
+            .map(|edit| {
+                let target = edit.target();
+                find_path(program, target).map(Some).ok_or_else(|| not_found(target))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut edited = program.clone();
+        for (i, edit) in self.edits.iter().enumerate() {
+            let target = edit.target();
+            // An earlier edit may have removed the target.
+            let path = paths[i].take().ok_or_else(|| not_found(target))?;
+            let (path, changes) = edit.apply(&mut edited, path)?;
+            for change in &changes {
+                for other in paths.iter_mut() {
+                    change.apply(other);
+                }
+            }
+            paths[i] = Some(path);
+        }
+
+        let source = edited.recast(&FormatOptions::default(), 0);
+        let program = crate::parser::parse(&source)?;
+
+        let source_map = self
+            .edits
+            .iter()
+            .zip(&paths)
+            .map(|(edit, path)| {
+                let before = edit.target();
+                let after = path
+                    .as_deref()
+                    .and_then(|path| node_at(&program, path))
+                    .map(|node| SourceRange([node.start(), node.end()]))
+                    .ok_or_else(|| {
+                        KclError::Semantic(KclErrorDetails {
+                            message: "Could not find the edited expression after recasting the program".to_string(),
+                            source_ranges: vec![before],
+                        })
+                    })?;
+                Ok(SourceRangeMapping { before, after })
+            })
+            .collect::<Result<Vec<_>, KclError>>()?;
+
+        Ok(AstEditResult {
+            source,
+            program,
+            source_map,
+        })
+    }
+}
+
+/// A change an edit made to the shape of the tree, which moves the nodes after or under it.
+#[derive(Debug, Clone, PartialEq)]
+enum PathChange {
+    /// A child was inserted at `index` of the node at `parent`.
+    Inserted { parent: Vec<usize>, index: usize },
+    /// The child at `index` of the node at `parent` was removed.
+    Removed { parent: Vec<usize>, index: usize },
+    /// The node at `path` became the first child of a new node in its place.
+    Wrapped { path: Vec<usize> },
+    /// The node at `from` moved to `to`.
+    Moved { from: Vec<usize>, to: Vec<usize> },
+}
+
+impl PathChange {
+    /// Update a path to where its node is after the change, or `None` if it's gone.
+    fn apply(&self, path: &mut Option<Vec<usize>>) {
+        let Some(p) = path else {
+            return;
+        };
+        match self {
+            PathChange::Inserted { parent, index } => {
+                if p.len() > parent.len() && p.starts_with(parent) && p[parent.len()] >= *index {
+                    p[parent.len()] += 1;
+                }
+            }
+            PathChange::Removed { parent, index } => {
+                if p.len() > parent.len() && p.starts_with(parent) {
+                    match p[parent.len()].cmp(index) {
+                        Ordering::Equal => *path = None,
+                        Ordering::Greater => p[parent.len()] -= 1,
+                        Ordering::Less => {}
+                    }
+                }
+            }
+            PathChange::Wrapped { path: wrapped } => {
+                if p.starts_with(wrapped) {
+                    p.insert(wrapped.len(), 0);
+                }
+            }
+            PathChange::Moved { from, to } => {
+                if p.starts_with(from) {
+                    *p = to.iter().chain(&p[from.len()..]).copied().collect();
+                }
+            }
+        }
+    }
+}
+
+impl AstEdit {
+    /// The range this edit targets.
+    pub fn target(&self) -> SourceRange {
+        match self {
+            AstEdit::InsertPipeCall { pipe, .. } => *pipe,
+            AstEdit::AddFilletTag { call, .. } | AstEdit::RemoveFilletTag { call, .. } => *call,
+            AstEdit::SetLiteral { range, .. } | AstEdit::WrapInVariable { range, .. } => *range,
+        }
+    }
+
+    /// Apply this edit in place to the node at `path`. Returns the path to the edited node,
+    /// and how the edit moved the other nodes.
+    fn apply(&self, program: &mut Program, mut path: Vec<usize>) -> Result<(Vec<usize>, Vec<PathChange>), KclError> {
+        let target = self.target();
+        let mut changes = Vec::new();
+
+        match self {
+            AstEdit::InsertPipeCall {
+                index, name, arguments, ..
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(|arg| parse_expr(arg, target))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let call: Expr = CallExpression::new(name, arguments)?.into();
+
+                // The first expression of a pipe has the same start as the pipe, so point at the pipe.
+                if path.len() > 1
+                    && path.last() == Some(&0)
+                    && matches!(
+                        node_at(program, &path[..path.len() - 1]),
+                        Some(Node::Expr(Expr::PipeExpression(_)))
+                    )
+                {
+                    path.pop();
+                }
+                let Some(NodeMut::Expr(expr)) = node_at_mut(program, &path) else {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: "Expected an expression which can be piped at the given source range".to_string(),
+                        source_ranges: vec![target],
+                    }));
+                };
+                if !matches!(expr, Expr::PipeExpression(_)) {
+                    let first = std::mem::replace(expr, Expr::None(Default::default()));
+                    *expr = PipeExpression::new(vec![first]).into();
+                    changes.push(PathChange::Wrapped { path: path.clone() });
+                }
+                let Expr::PipeExpression(pipe) = expr else {
+                    unreachable!("the target was just wrapped in a pipe");
+                };
+
+                let index = index.unwrap_or(pipe.body.len());
+                if index == 0 || index > pipe.body.len() {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: format!(
+                            "Cannot insert a call at index {} of a pipe with {} expressions",
+                            index,
+                            pipe.body.len()
+                        ),
+                        source_ranges: vec![target],
+                    }));
+                }
+                pipe.body.insert(index, call);
+                shift_non_code(&mut pipe.non_code_meta, index, 1);
+                changes.push(PathChange::Inserted {
+                    parent: path.clone(),
+                    index,
+                });
+                path.push(index);
+            }
+            AstEdit::AddFilletTag { tag, .. } => {
+                let new_tag = parse_expr(tag, target)?;
+                let (tags, property) = fillet_tags(program, &path, target)?;
+                if tags.elements.iter().any(|existing| same_expr(existing, &new_tag)) {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: format!("`{}` is already in the list of tags", tag),
+                        source_ranges: vec![target],
+                    }));
+                }
+                changes.push(PathChange::Inserted {
+                    parent: [path.as_slice(), &[0, property]].concat(),
+                    index: tags.elements.len(),
+                });
+                tags.elements.push(new_tag);
+            }
+            AstEdit::RemoveFilletTag { tag, .. } => {
+                let old_tag = parse_expr(tag, target)?;
+                let (tags, property) = fillet_tags(program, &path, target)?;
+                let Some(index) = tags.elements.iter().position(|existing| same_expr(existing, &old_tag)) else {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: format!("`{}` is not in the list of tags", tag),
+                        source_ranges: vec![target],
+                    }));
+                };
+                if tags.elements.len() == 1 {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: "Cannot remove the last tag, remove the whole call instead".to_string(),
+                        source_ranges: vec![target],
+                    }));
+                }
+                tags.elements.remove(index);
+                tags.non_code_meta.non_code_nodes.remove(&index);
+                shift_non_code(&mut tags.non_code_meta, index + 1, -1);
+                changes.push(PathChange::Removed {
+                    parent: [path.as_slice(), &[0, property]].concat(),
+                    index,
+                });
+            }
+            AstEdit::SetLiteral { value, .. } => {
+                let literal = match node_at_mut(program, &path) {
+                    Some(NodeMut::Expr(Expr::Literal(literal)))
+                    | Some(NodeMut::Operand(BinaryPart::Literal(literal)))
+                    | Some(NodeMut::Property(LiteralIdentifier::Literal(literal))) => literal,
+                    _ => {
+                        return Err(KclError::Semantic(KclErrorDetails {
+                            message: "Expected a literal at the given source range".to_string(),
+                            source_ranges: vec![target],
+                        }))
+                    }
+                };
+                **literal = Literal::new(value.clone());
+            }
+            AstEdit::WrapInVariable { name, .. } => {
+                // The new constant goes in the innermost body around the expression, e.g. a
+                // function's body, so it can use the names the expression uses.
+                let body_path = (0..path.len())
+                    .rev()
+                    .find(|&n| matches!(node_at(program, &path[..n]), Some(Node::Body(_))))
+                    .map(|n| path[..n].to_vec())
+                    .unwrap_or_default();
+                if path.len() < body_path.len() + 2 {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: "Only expressions nested inside a statement can be wrapped in a variable".to_string(),
+                        source_ranges: vec![target],
+                    }));
+                }
+                let defined =
+                    |node: Option<Node>| matches!(node, Some(Node::Body(body)) if body.get_variable(name).is_some());
+                if program.get_variable(name).is_some() || defined(node_at(program, &body_path)) {
+                    return Err(KclError::Semantic(KclErrorDetails {
+                        message: format!("Cannot redefine `{}`", name),
+                        source_ranges: vec![target],
+                    }));
+                }
+
+                let identifier = Box::new(Identifier::new(name));
+                let value = match node_at_mut(program, &path) {
+                    Some(NodeMut::Expr(expr)) => std::mem::replace(expr, Expr::Identifier(identifier)),
+                    Some(NodeMut::Operand(part)) => {
+                        operand_expr(std::mem::replace(part, BinaryPart::Identifier(identifier)))
+                    }
+                    Some(NodeMut::Property(property @ LiteralIdentifier::Literal(_))) => {
+                        match std::mem::replace(property, LiteralIdentifier::Identifier(identifier)) {
+                            LiteralIdentifier::Literal(literal) => Expr::Literal(literal),
+                            LiteralIdentifier::Identifier(_) => {
+                                unreachable!("the property was just matched as a literal")
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(KclError::Semantic(KclErrorDetails {
+                            message: "Expected an expression which can be wrapped in a variable".to_string(),
+                            source_ranges: vec![target],
+                        }))
+                    }
+                };
+
+                let statement = path[body_path.len()];
+                let Some(NodeMut::Body(body)) = node_at_mut(program, &body_path) else {
+                    unreachable!("the body was found above");
+                };
+                body.body.insert(
+                    statement,
+                    BodyItem::VariableDeclaration(Box::new(VariableDeclaration::new(
+                        vec![VariableDeclarator::new(name, value)],
+                        ItemVisibility::Default,
+                        VariableKind::Const,
+                    ))),
+                );
+                shift_non_code(&mut body.non_code_meta, statement, 1);
+
+                let declaration = [body_path.as_slice(), &[statement]].concat();
+                path[body_path.len()] += 1;
+                changes.push(PathChange::Inserted {
+                    parent: body_path,
+                    index: statement,
+                });
+                changes.push(PathChange::Moved {
+                    from: path,
+                    to: declaration.clone(),
+                });
+                path = declaration;
+            }
+        }
+
+        Ok((path, changes))
+    }
+}
+
+fn not_found(range: SourceRange) -> KclError {
+    KclError::Semantic(KclErrorDetails {
+        message: "No expression found at the given source range".to_string(),
+        source_ranges: vec![range],
+    })
+}
+
+/// Parse a snippet of KCL source into a single expression.
+fn parse_expr(source: &str, range: SourceRange) -> Result<Expr, KclError> {
+    if source.trim() == PIPE_SUBSTITUTION_OPERATOR {
+        return Ok(PipeSubstitution::new().into());
+    }
+
+    let program = crate::parser::parse(&format!("const __edit = {}", source))?;
+    match program.body.as_slice() {
+        [BodyItem::VariableDeclaration(declaration)] if declaration.declarations.len() == 1 => {
+            Ok(declaration.declarations[0].init.clone())
+        }
+        _ => Err(KclError::Syntax(KclErrorDetails {
+            message: format!("Expected a single expression, found `{}`", source),
+            source_ranges: vec![range],
+        })),
+    }
+}
+
+/// Two expressions are the same if they recast to the same source.
+fn same_expr(a: &Expr, b: &Expr) -> bool {
+    let options = FormatOptions::default();
+    a.recast(&options, 0, false) == b.recast(&options, 0, false)
+}
+
+/// Shift the keys of non-code nodes at or after `from` by `delta`.
+fn shift_non_code(meta: &mut NonCodeMeta, from: usize, delta: isize) {
+    meta.non_code_nodes = std::mem::take(&mut meta.non_code_nodes)
+        .into_iter()
+        .map(|(key, nodes)| {
+            if key >= from {
+                ((key as isize + delta) as usize, nodes)
+            } else {
+                (key, nodes)
+            }
+        })
+        .collect::<HashMap<_, _>>();
+}
+
+/// The `tags` array in the first argument of a `fillet` or `chamfer` call, and the index of
+/// the `tags` property in that argument.
+fn fillet_tags<'a>(
+    program: &'a mut Program,
+    path: &[usize],
+    range: SourceRange,
+) -> Result<(&'a mut crate::ast::types::ArrayExpression, usize), KclError> {
+    let wrong_call = || {
+        KclError::Semantic(KclErrorDetails {
+            message: "Expected a `fillet` or `chamfer` call with a `tags` array".to_string(),
+            source_ranges: vec![range],
+        })
+    };
+
+    let call = match node_at_mut(program, path) {
+        Some(NodeMut::Expr(Expr::CallExpression(call))) | Some(NodeMut::Operand(BinaryPart::CallExpression(call))) => {
+            call
+        }
+        _ => return Err(wrong_call()),
+    };
+    if call.callee.name != "fillet" && call.callee.name != "chamfer" {
+        return Err(wrong_call());
+    }
+    let Some(Expr::ObjectExpression(data)) = call.arguments.first_mut() else {
+        return Err(wrong_call());
+    };
+    match data
+        .properties
+        .iter_mut()
+        .enumerate()
+        .find(|(_, property)| property.key.name == "tags")
+    {
+        Some((i, property)) => match &mut property.value {
+            Expr::ArrayExpression(tags) => Ok((tags, i)),
+            _ => Err(wrong_call()),
+        },
+        None => Err(wrong_call()),
+    }
+}
+
+/// An operand of a binary or unary expression as an expression.
+fn operand_expr(part: BinaryPart) -> Expr {
+    match part {
+        BinaryPart::Literal(literal) => Expr::Literal(literal),
+        BinaryPart::Identifier(identifier) => Expr::Identifier(identifier),
+        BinaryPart::BinaryExpression(binary) => Expr::BinaryExpression(binary),
+        BinaryPart::CallExpression(call) => Expr::CallExpression(call),
+        BinaryPart::UnaryExpression(unary) => Expr::UnaryExpression(unary),
+        BinaryPart::MemberExpression(member) => Expr::MemberExpression(member),
+        BinaryPart::IfExpression(if_expression) => Expr::IfExpression(if_expression),
+    }
+}
+
+/// The expression at the root of a statement.
+fn statement_expr(item: &BodyItem) -> Option<&Expr> {
+    match item {
+        BodyItem::ImportStatement(_) => None,
+        BodyItem::ExpressionStatement(statement) => Some(&statement.expression),
+        BodyItem::VariableDeclaration(declaration) => declaration.declarations.first().map(|d| &d.init),
+        BodyItem::ReturnStatement(statement) => Some(&statement.argument),
+    }
+}
+
+fn statement_expr_mut(item: &mut BodyItem) -> Option<&mut Expr> {
+    match item {
+        BodyItem::ImportStatement(_) => None,
+        BodyItem::ExpressionStatement(statement) => Some(&mut statement.expression),
+        BodyItem::VariableDeclaration(declaration) => declaration.declarations.first_mut().map(|d| &mut d.init),
+        BodyItem::ReturnStatement(statement) => Some(&mut statement.argument),
+    }
+}
+
+/// A node an edit can reach. Bodies are the program itself, function bodies and the blocks
+/// of an `if`. The operands of binary and unary expressions and the parts of member
+/// expressions have their own types in the AST, so they have their own variants.
+#[derive(Clone, Copy)]
+enum Node<'a> {
+    Body(&'a Program),
+    Expr(&'a Expr),
+    Operand(&'a BinaryPart),
+    Object(&'a MemberObject),
+    Property(&'a LiteralIdentifier),
+}
+
+enum NodeMut<'a> {
+    Body(&'a mut Program),
+    Expr(&'a mut Expr),
+    Operand(&'a mut BinaryPart),
+    Object(&'a mut MemberObject),
+    Property(&'a mut LiteralIdentifier),
+}
+
+impl Node<'_> {
+    fn start(&self) -> usize {
+        match self {
+            Node::Body(body) => body.start,
+            Node::Expr(expr) => expr.start(),
+            Node::Operand(part) => part.start(),
+            Node::Object(object) => object.start(),
+            Node::Property(property) => property.start(),
+        }
+    }
+
+    fn end(&self) -> usize {
+        match self {
+            Node::Body(body) => body.end,
+            Node::Expr(expr) => expr.end(),
+            Node::Operand(part) => part.end(),
+            Node::Object(object) => object.end(),
+            Node::Property(property) => property.end(),
+        }
+    }
+}
+
+/// The children an edit can reach: statements, call arguments, pipe bodies, array elements,
+/// object values, operands, member expressions' objects and properties, function bodies,
+/// and the conditions and blocks of an `if`. Statements which aren't expressions are `None`,
+/// so the indices of the rest match the body.
+fn children(node: Node<'_>) -> Vec<Option<Node<'_>>> {
+    fn binary(binary: &BinaryExpression) -> Vec<Option<Node<'_>>> {
+        vec![Some(Node::Operand(&binary.left)), Some(Node::Operand(&binary.right))]
+    }
+    fn member(member: &MemberExpression) -> Vec<Option<Node<'_>>> {
+        vec![
+            Some(Node::Object(&member.object)),
+            Some(Node::Property(&member.property)),
+        ]
+    }
+    fn exprs(exprs: &[Expr]) -> Vec<Option<Node<'_>>> {
+        exprs.iter().map(|expr| Some(Node::Expr(expr))).collect()
+    }
+    fn if_else(if_expression: &IfExpression) -> Vec<Option<Node<'_>>> {
+        let mut nodes = vec![
+            Some(Node::Expr(&if_expression.cond)),
+            Some(Node::Body(&if_expression.then_val)),
+        ];
+        for else_if in &if_expression.else_ifs {
+            nodes.push(Some(Node::Expr(&else_if.cond)));
+            nodes.push(Some(Node::Body(&else_if.then_val)));
+        }
+        nodes.push(Some(Node::Body(&if_expression.final_else)));
+        nodes
+    }
+
+    match node {
+        Node::Body(body) => body
+            .body
+            .iter()
+            .map(|item| statement_expr(item).map(Node::Expr))
+            .collect(),
+        Node::Expr(expr) => match expr {
+            Expr::CallExpression(call) => exprs(&call.arguments),
+            Expr::PipeExpression(pipe) => exprs(&pipe.body),
+            Expr::ArrayExpression(array) => exprs(&array.elements),
+            Expr::ArrayRangeExpression(range) => vec![
+                Some(Node::Expr(&range.start_element)),
+                Some(Node::Expr(&range.end_element)),
+            ],
+            Expr::ObjectExpression(object) => object.properties.iter().map(|p| Some(Node::Expr(&p.value))).collect(),
+            Expr::BinaryExpression(expr) => binary(expr),
+            Expr::UnaryExpression(unary) => vec![Some(Node::Operand(&unary.argument))],
+            Expr::MemberExpression(expr) => member(expr),
+            Expr::FunctionExpression(function) => vec![Some(Node::Body(&function.body))],
+            Expr::IfExpression(expr) => if_else(expr),
+            _ => Vec::new(),
+        },
+        Node::Operand(part) => match part {
+            BinaryPart::CallExpression(call) => exprs(&call.arguments),
+            BinaryPart::BinaryExpression(expr) => binary(expr),
+            BinaryPart::UnaryExpression(unary) => vec![Some(Node::Operand(&unary.argument))],
+            BinaryPart::MemberExpression(expr) => member(expr),
+            BinaryPart::IfExpression(expr) => if_else(expr),
+            BinaryPart::Literal(_) | BinaryPart::Identifier(_) => Vec::new(),
+        },
+        Node::Object(MemberObject::MemberExpression(expr)) => member(expr),
+        Node::Object(MemberObject::Identifier(_)) | Node::Property(_) => Vec::new(),
+    }
+}
+
+/// The child at `index`, as from [`children`].
+fn child_mut(node: NodeMut<'_>, index: usize) -> Option<NodeMut<'_>> {
+    fn binary(binary: &mut BinaryExpression, index: usize) -> Option<NodeMut<'_>> {
+        match index {
+            0 => Some(NodeMut::Operand(&mut binary.left)),
+            1 => Some(NodeMut::Operand(&mut binary.right)),
+            _ => None,
+        }
+    }
+    fn member(member: &mut MemberExpression, index: usize) -> Option<NodeMut<'_>> {
+        match index {
+            0 => Some(NodeMut::Object(&mut member.object)),
+            1 => Some(NodeMut::Property(&mut member.property)),
+            _ => None,
+        }
+    }
+    fn if_else(if_expression: &mut IfExpression, index: usize) -> Option<NodeMut<'_>> {
+        match index {
+            0 => Some(NodeMut::Expr(&mut if_expression.cond)),
+            1 => Some(NodeMut::Body(&mut if_expression.then_val)),
+            _ => {
+                let else_ifs = if_expression.else_ifs.len();
+                if index == 2 + 2 * else_ifs {
+                    return Some(NodeMut::Body(&mut if_expression.final_else));
+                }
+                let else_if = if_expression.else_ifs.get_mut((index - 2) / 2)?;
+                if index % 2 == 0 {
+                    Some(NodeMut::Expr(&mut else_if.cond))
+                } else {
+                    Some(NodeMut::Body(&mut else_if.then_val))
+                }
+            }
+        }
+    }
+    fn unary(unary: &mut UnaryExpression, index: usize) -> Option<NodeMut<'_>> {
+        (index == 0).then_some(NodeMut::Operand(&mut unary.argument))
+    }
+
+    match node {
+        NodeMut::Body(body) => statement_expr_mut(body.body.get_mut(index)?).map(NodeMut::Expr),
+        NodeMut::Expr(expr) => match expr {
+            Expr::CallExpression(call) => call.arguments.get_mut(index).map(NodeMut::Expr),
+            Expr::PipeExpression(pipe) => pipe.body.get_mut(index).map(NodeMut::Expr),
+            Expr::ArrayExpression(array) => array.elements.get_mut(index).map(NodeMut::Expr),
+            Expr::ArrayRangeExpression(range) => match index {
+                0 => Some(NodeMut::Expr(&mut range.start_element)),
+                1 => Some(NodeMut::Expr(&mut range.end_element)),
+                _ => None,
+            },
+            Expr::ObjectExpression(object) => object.properties.get_mut(index).map(|p| NodeMut::Expr(&mut p.value)),
+            Expr::BinaryExpression(expr) => binary(expr, index),
+            Expr::UnaryExpression(expr) => unary(expr, index),
+            Expr::MemberExpression(expr) => member(expr, index),
+            Expr::FunctionExpression(function) => (index == 0).then_some(NodeMut::Body(&mut function.body)),
+            Expr::IfExpression(expr) => if_else(expr, index),
+            _ => None,
+        },
+        NodeMut::Operand(part) => match part {
+            BinaryPart::CallExpression(call) => call.arguments.get_mut(index).map(NodeMut::Expr),
+            BinaryPart::BinaryExpression(expr) => binary(expr, index),
+            BinaryPart::UnaryExpression(expr) => unary(expr, index),
+            BinaryPart::MemberExpression(expr) => member(expr, index),
+            BinaryPart::IfExpression(expr) => if_else(expr, index),
+            BinaryPart::Literal(_) | BinaryPart::Identifier(_) => None,
+        },
+        NodeMut::Object(MemberObject::MemberExpression(expr)) => member(expr, index),
+        NodeMut::Object(MemberObject::Identifier(_)) | NodeMut::Property(_) => None,
+    }
+}
+
+/// Find the path to the node spanning exactly `range`, as child indices from the program.
+/// The first step is the statement index.
+fn find_path(program: &Program, range: SourceRange) -> Option<Vec<usize>> {
+    fn search(node: Node<'_>, range: SourceRange, path: &mut Vec<usize>) -> bool {
+        if node.start() > range.start() || node.end() < range.end() {
+            return false;
+        }
+        // Prefer the innermost match, e.g. a call over a single-argument wrapper around it.
+        for (i, child) in children(node).into_iter().enumerate() {
+            let Some(child) = child else {
+                continue;
+            };
+            path.push(i);
+            if search(child, range, path) {
+                return true;
+            }
+            path.pop();
+        }
+        !matches!(node, Node::Body(_)) && node.start() == range.start() && node.end() == range.end()
+    }
+
+    let mut path = Vec::new();
+    // The program's own range is ignored, only the statements in it are searched.
+    for (i, child) in children(Node::Body(program)).into_iter().enumerate() {
+        let Some(child) = child else {
+            continue;
+        };
+        path.push(i);
+        if search(child, range, &mut path) {
+            return Some(path);
+        }
+        path.pop();
+    }
+    None
+}
+
+fn node_at<'a>(program: &'a Program, path: &[usize]) -> Option<Node<'a>> {
+    let mut node = Node::Body(program);
+    for i in path {
+        node = children(node).into_iter().nth(*i)??;
+    }
+    Some(node)
+}
+
+fn node_at_mut<'a>(program: &'a mut Program, path: &[usize]) -> Option<NodeMut<'a>> {
+    let mut node = NodeMut::Body(program);
+    for i in path {
+        node = child_mut(node, *i)?;
+    }
+    Some(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn range_of(code: &str, needle: &str) -> SourceRange {
+        let start = code.find(needle).unwrap();
+        SourceRange([start, start + needle.len()])
+    }
+
+    fn apply(code: &str, edits: Vec<AstEdit>) -> Result<AstEditResult, KclError> {
+        let program = crate::parser::parse(code).unwrap();
+        AstTransaction::new(edits).apply(&program)
+    }
+
+    const PART: &str = r#"// The base plate.
+const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %, $seg01)
+  |> line([0, 10], %, $seg02)
+  |> close(%)
+  |> extrude(5, %)
+  |> fillet({ radius: 1, tags: [seg01] }, %)
+"#;
+
+    #[test]
+    fn test_insert_pipe_call() {
+        let result = apply(
+            PART,
+            vec![AstEdit::InsertPipeCall {
+                pipe: range_of(PART, "startSketchOn('XY')"),
+                index: Some(4),
+                name: "line".to_string(),
+                arguments: vec!["[-5, 0]".to_string(), "%".to_string()],
+            }],
+        )
+        .unwrap();
+
+        assert!(result.source.starts_with("// The base plate.\n"));
+        assert!(result
+            .source
+            .contains("  |> line([0, 10], %, $seg02)\n  |> line([-5, 0], %)\n  |> close(%)"));
+        let after = result.source_map[0].after;
+        assert_eq!(&result.source[after.start()..after.end()], "line([-5, 0], %)");
+    }
+
+    #[test]
+    fn test_insert_pipe_call_bad_index() {
+        let err = apply(
+            PART,
+            vec![AstEdit::InsertPipeCall {
+                pipe: range_of(PART, "startSketchOn('XY')"),
+                index: Some(0),
+                name: "line".to_string(),
+                arguments: vec![],
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Cannot insert a call at index 0 of a pipe with 7 expressions"
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_fillet_tag() {
+        let fillet = range_of(PART, "fillet({ radius: 1, tags: [seg01] }, %)");
+        let added = apply(
+            PART,
+            vec![AstEdit::AddFilletTag {
+                call: fillet,
+                tag: "getOppositeEdge(seg02)".to_string(),
+            }],
+        )
+        .unwrap();
+        assert!(added.source.contains("tags: [seg01, getOppositeEdge(seg02)]"));
+
+        let after = added.source_map[0].after;
+        let removed = AstTransaction::new(vec![AstEdit::RemoveFilletTag {
+            call: after,
+            tag: "seg01".to_string(),
+        }])
+        .apply(&added.program)
+        .unwrap();
+        assert!(removed.source.contains("tags: [getOppositeEdge(seg02)]"));
+
+        let err = AstTransaction::new(vec![AstEdit::RemoveFilletTag {
+            call: removed.source_map[0].after,
+            tag: "getOppositeEdge(seg02)".to_string(),
+        }])
+        .apply(&removed.program)
+        .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Cannot remove the last tag, remove the whole call instead"
+        );
+    }
+
+    #[test]
+    fn test_set_literal() {
+        let result = apply(
+            PART,
+            vec![AstEdit::SetLiteral {
+                range: range_of(PART, "5"),
+                value: LiteralValue::IInteger(8),
+            }],
+        )
+        .unwrap();
+        assert!(result.source.contains("|> extrude(8, %)"));
+
+        let err = apply(
+            PART,
+            vec![AstEdit::SetLiteral {
+                range: range_of(PART, "[10, 0]"),
+                value: LiteralValue::IInteger(8),
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(err.message(), "Expected a literal at the given source range");
+    }
+
+    #[test]
+    fn test_wrap_in_variable() {
+        let result = apply(
+            PART,
+            vec![AstEdit::WrapInVariable {
+                range: range_of(PART, "5"),
+                name: "thickness".to_string(),
+            }],
+        )
+        .unwrap();
+
+        assert!(result.source.starts_with("// The base plate.\nthickness = 5\n"));
+        assert!(result.source.contains("|> extrude(thickness, %)"));
+        let after = result.source_map[0].after;
+        assert_eq!(&result.source[after.start()..after.end()], "5");
+
+        let err = apply(
+            &result.source,
+            vec![AstEdit::WrapInVariable {
+                range: range_of(&result.source, "[10, 0]"),
+                name: "thickness".to_string(),
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(err.message(), "Cannot redefine `thickness`");
+    }
+
+    #[test]
+    fn test_edits_use_original_ranges() {
+        // Every range is from PART, even though the edits before each one move the code after them.
+        let result = apply(
+            PART,
+            vec![
+                AstEdit::WrapInVariable {
+                    range: range_of(PART, "[0, 0]"),
+                    name: "origin".to_string(),
+                },
+                AstEdit::InsertPipeCall {
+                    pipe: range_of(PART, "startSketchOn('XY')"),
+                    index: Some(4),
+                    name: "line".to_string(),
+                    arguments: vec!["[-5, 0]".to_string(), "%".to_string()],
+                },
+                AstEdit::AddFilletTag {
+                    call: range_of(PART, "fillet({ radius: 1, tags: [seg01] }, %)"),
+                    tag: "seg02".to_string(),
+                },
+                AstEdit::SetLiteral {
+                    range: range_of(PART, "5"),
+                    value: LiteralValue::IInteger(8),
+                },
+            ],
+        )
+        .unwrap();
+
+        assert!(result.source.starts_with("// The base plate.\norigin = [0, 0]\n"));
+        assert!(result.source.contains("  |> startProfileAt(origin, %)\n"));
+        assert!(result.source.contains("  |> line([-5, 0], %)\n  |> close(%)"));
+        assert!(result.source.contains("|> extrude(8, %)"));
+        assert!(result.source.contains("tags: [seg01, seg02]"));
+
+        let after: Vec<&str> = result
+            .source_map
+            .iter()
+            .map(|mapping| &result.source[mapping.after.start()..mapping.after.end()])
+            .collect();
+        assert_eq!(
+            after,
+            vec![
+                "[0, 0]",
+                "line([-5, 0], %)",
+                "fillet({ radius: 1, tags: [seg01, seg02] }, %)",
+                "8"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edits_inside_functions_and_expressions() {
+        let code = r#"fn box = (size) => {
+  const half = size / 2
+  return if size > 10 {
+    half * 3
+  } else {
+    -half
+  }
+}
+const point = { x: [1, 2] }
+const y = point.x[0] + 4
+"#;
+        let two = range_of(code, "2\n").start();
+        let index = range_of(code, "0]").start();
+        let result = apply(
+            code,
+            vec![
+                AstEdit::WrapInVariable {
+                    range: SourceRange([two, two + 1]),
+                    name: "divisor".to_string(),
+                },
+                AstEdit::SetLiteral {
+                    range: range_of(code, "3"),
+                    value: LiteralValue::IInteger(4),
+                },
+                AstEdit::SetLiteral {
+                    range: SourceRange([index, index + 1]),
+                    value: LiteralValue::IInteger(1),
+                },
+                AstEdit::SetLiteral {
+                    range: range_of(code, "4"),
+                    value: LiteralValue::IInteger(5),
+                },
+            ],
+        )
+        .unwrap();
+
+        // The new constant goes in the function's body, not before the function.
+        assert!(result
+            .source
+            .starts_with("fn box = (size) => {\n  divisor = 2\n  half = size / divisor\n"));
+        assert!(result.source.contains("half * 4"));
+        assert!(result.source.contains("y = point.x[1] + 5"));
+    }
+}
@@ -314,7 +314,36 @@ impl CallExpression {
             fn_args.push(result);
         }
 
-        match ctx.stdlib.get_either(&self.callee.name) {
+        // Code from other files, called directly or passed to the standard library, is recorded
+        // in the artifact graph against this call.
+        let runs_imported_code = exec_state.imported_source_range.is_none() && {
+            let callee = match ctx.stdlib.get_either(fn_name) {
+                FunctionKind::UserDefined => exec_state.memory.get(fn_name, self.into()).ok(),
+                _ => None,
+            };
+            callee.into_iter().chain(&fn_args).any(|value| {
+                matches!(value, KclValue::Function { expression, .. } if exec_state.assembly.part(expression).is_some())
+            })
+        };
+        if !runs_imported_code {
+            return self.call(fn_args, exec_state, ctx).await;
+        }
+        exec_state.imported_source_range = Some(self.into());
+        let result = self.call(fn_args, exec_state, ctx).await;
+        exec_state.imported_source_range = None;
+        result
+    }
+
+    #[async_recursion]
+    async fn call(
+        &self,
+        fn_args: Vec<KclValue>,
+        exec_state: &mut ExecState,
+        ctx: &ExecutorContext,
+    ) -> Result<KclValue, KclError> {
+        let fn_name = &self.callee.name;
+
+        match ctx.stdlib.get_either(fn_name) {
             FunctionKind::Core(func) => {
                 // Attempt to call the function.
                 let args = crate::std::Args::new(fn_args, self.into(), ctx.clone());
@@ -330,6 +359,9 @@ impl CallExpression {
                             for (_, tag) in sketch.tags.iter() {
                                 exec_state.memory.update_tag(&tag.value, tag.clone())?;
                             }
+                            exec_state
+                                .artifact_graph
+                                .record_sketch(sketch, exec_state.imported_source_range);
                             Ok::<_, KclError>(())
                         })?;
                     }
@@ -387,6 +419,11 @@ impl CallExpression {
                     _ => {}
                 }
 
+                exec_state
+                    .artifact_graph
+                    .record(&result, self.into(), exec_state.imported_source_range);
+                exec_state.parts.record(&result);
+
                 Ok(result)
             }
             FunctionKind::Std(func) => {
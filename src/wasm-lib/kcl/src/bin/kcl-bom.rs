@@ -0,0 +1,80 @@
+//! Print the bill of materials of a KCL program.
+//!
+//! ```text
+//! kcl-bom main.kcl --format csv > bom.csv
+//! ```
+//!
+//! Imports are resolved relative to the file's directory. The program is executed against the
+//! engine when `KITTYCAD_API_TOKEN` is set, and against a mock engine otherwise. Patterns are
+//! only made by the engine, so without it patterned parts are counted once.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use kcl_lib::{
+    bom::BillOfMaterials,
+    executor::{new_zoo_client, ExecutorContext, IdGenerator},
+};
+
+#[derive(Debug, Parser)]
+#[clap(name = "kcl-bom", version, about = "Bill of materials of a KCL program")]
+struct Opts {
+    /// The KCL file to execute.
+    file: PathBuf,
+    /// The format to print the bill of materials in.
+    #[clap(short, long, value_enum, default_value = "csv")]
+    format: Format,
+    /// Where to write the bill of materials. Defaults to stdout.
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+}
+
+async fn new_executor_context() -> Result<ExecutorContext> {
+    let Some(token) = std::env::var("KITTYCAD_API_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+    else {
+        return ExecutorContext::new_mock(Default::default()).await;
+    };
+
+    let client = new_zoo_client(token, None);
+    ExecutorContext::new(&client, Default::default()).await
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let opts = Opts::parse();
+
+    let code = std::fs::read_to_string(&opts.file).with_context(|| format!("reading {}", opts.file.display()))?;
+    let program = kcl_lib::parser::parse(&code)
+        .map_err(|e| anyhow::anyhow!("parsing {}: {}", opts.file.display(), e.message()))?;
+    let project_directory = opts
+        .file
+        .parent()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .filter(|dir| !dir.is_empty());
+
+    let ctx = new_executor_context().await?;
+    let exec_state = ctx
+        .run(&program, None, IdGenerator::default(), project_directory)
+        .await
+        .map_err(|e| anyhow::anyhow!("executing {}: {}", opts.file.display(), e.message()))?;
+
+    let bom = BillOfMaterials::from_exec_state(&exec_state);
+    let out = match opts.format {
+        Format::Csv => bom.to_csv(),
+        Format::Json => serde_json::to_string_pretty(&bom)? + "\n",
+    };
+    match opts.output {
+        Some(path) => std::fs::write(&path, out).with_context(|| format!("writing {}", path.display()))?,
+        None => print!("{}", out),
+    }
+    Ok(())
+}
@@ -0,0 +1,205 @@
+//! The KCL language server, for editors outside the modeling app.
+//!
+//! Over stdin and stdout, which is what most editors expect:
+//!
+//! ```text
+//! kcl-language-server --stdio
+//! ```
+//!
+//! Or over a tcp socket, with `--socket 8080`. Pass `--copilot` to serve the copilot
+//! completions server instead of the language server. Its completions come from the Zoo API,
+//! or from an OpenAI-compatible server such as a local llama.cpp given with
+//! `--completion-url http://localhost:8080 --model <model>`.
+//!
+//! Code is executed against the engine when `KITTYCAD_API_TOKEN` is set, and against a mock
+//! engine otherwise, so everything but the engine's responses still works without an account.
+
+use std::{future::Future, sync::Arc};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use kcl_lib::{
+    executor::{new_zoo_client, ContextType, ExecutorContext},
+    lsp::{
+        copilot::provider::{CompletionProvider, OpenAiCompatibleProvider, ZooCompletionProvider},
+        kcl::Server,
+    },
+};
+use tower_lsp::{ClientSocket, LanguageServer, LspService};
+
+#[derive(Debug, Parser)]
+#[clap(name = "kcl-language-server", version, about = "Language server for KCL")]
+struct Opts {
+    #[clap(flatten)]
+    server: Server,
+
+    /// Serve copilot completions instead of the language server.
+    /// Needs `KITTYCAD_API_TOKEN`, unless completions come from `--completion-url`.
+    #[clap(long, default_value = "false")]
+    copilot: bool,
+
+    /// Get copilot completions from this OpenAI-compatible server instead of the Zoo API.
+    #[clap(long, requires = "model")]
+    completion_url: Option<String>,
+
+    /// The model to ask the `--completion-url` server for.
+    #[clap(long, requires = "completion_url")]
+    model: Option<String>,
+
+    /// The bearer token for the `--completion-url` server, if it needs one.
+    #[clap(long, requires = "completion_url")]
+    completion_api_key: Option<String>,
+}
+
+fn api_token() -> Option<String> {
+    std::env::var("KITTYCAD_API_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+async fn kcl_service() -> Result<(LspService<kcl_lib::lsp::kcl::Backend>, ClientSocket)> {
+    let stdlib = kcl_lib::std::StdLib::new();
+    let stdlib_completions = kcl_lib::lsp::kcl::get_completions_from_stdlib(&stdlib)?;
+    let stdlib_signatures = kcl_lib::lsp::kcl::get_signatures_from_stdlib(&stdlib)?;
+
+    let token = api_token();
+    let zoo_client = new_zoo_client(token.clone().unwrap_or_default(), None);
+    let fs = Arc::new(kcl_lib::fs::FileManager::new());
+
+    let (executor_ctx, can_send_telemetry) = if token.is_some() {
+        let executor_ctx = ExecutorContext::new(&zoo_client, Default::default()).await?;
+        let can_send_telemetry = zoo_client
+            .users()
+            .get_privacy_settings()
+            .await
+            .map(|privacy_settings| privacy_settings.can_train_on_data)
+            .unwrap_or(false);
+        (executor_ctx, can_send_telemetry)
+    } else {
+        let executor_ctx = ExecutorContext {
+            engine: Arc::new(Box::new(kcl_lib::engine::conn_mock::EngineConnection::new().await?)),
+            fs: fs.clone(),
+            stdlib: Arc::new(stdlib),
+            settings: Default::default(),
+            context_type: ContextType::Mock,
+        };
+        (executor_ctx, false)
+    };
+
+    Ok(LspService::build(|client| kcl_lib::lsp::kcl::Backend {
+        client,
+        fs,
+        workspace_folders: Default::default(),
+        stdlib_completions,
+        stdlib_signatures,
+        token_map: Default::default(),
+        ast_map: Default::default(),
+        memory_map: Default::default(),
+        code_map: Default::default(),
+        diagnostics_map: Default::default(),
+        symbols_map: Default::default(),
+        semantic_tokens_map: Default::default(),
+        sent_semantic_tokens: Default::default(),
+        zoo_client,
+        can_send_telemetry,
+        telemetry: Default::default(),
+        text_editor: Default::default(),
+        executor_ctx: Arc::new(tokio::sync::RwLock::new(Some(executor_ctx))),
+        can_execute: Arc::new(tokio::sync::RwLock::new(true)),
+        is_initialized: Default::default(),
+    })
+    .custom_method("kcl/updateUnits", kcl_lib::lsp::kcl::Backend::update_units)
+    .custom_method("kcl/updateCanExecute", kcl_lib::lsp::kcl::Backend::update_can_execute)
+    .custom_method("kcl/updateTelemetry", kcl_lib::lsp::kcl::Backend::update_telemetry)
+    .custom_method(
+        "kcl/updateTextEditorSettings",
+        kcl_lib::lsp::kcl::Backend::update_text_editor_settings,
+    )
+    .finish())
+}
+
+fn completion_provider(opts: &Opts) -> Result<Arc<dyn CompletionProvider>> {
+    if let (Some(url), Some(model)) = (&opts.completion_url, &opts.model) {
+        let mut provider = OpenAiCompatibleProvider::new(url, model);
+        if let Some(api_key) = &opts.completion_api_key {
+            provider = provider.with_api_key(api_key);
+        }
+        return Ok(Arc::new(provider));
+    }
+
+    let Some(token) = api_token() else {
+        bail!("copilot completions need KITTYCAD_API_TOKEN to be set, or a --completion-url");
+    };
+    Ok(Arc::new(ZooCompletionProvider::new(new_zoo_client(token, None))))
+}
+
+async fn copilot_service(
+    provider: Arc<dyn CompletionProvider>,
+) -> Result<(LspService<kcl_lib::lsp::copilot::Backend>, ClientSocket)> {
+    Ok(LspService::build(|client| kcl_lib::lsp::copilot::Backend {
+        client,
+        fs: Arc::new(kcl_lib::fs::FileManager::new()),
+        workspace_folders: Default::default(),
+        code_map: Default::default(),
+        provider,
+        editor_info: Arc::new(std::sync::RwLock::new(
+            kcl_lib::lsp::copilot::types::CopilotEditorInfo::default(),
+        )),
+        cache: Arc::new(kcl_lib::lsp::copilot::cache::CopilotCache::new()),
+        telemetry: Default::default(),
+        is_initialized: Default::default(),
+        diagnostics_map: Default::default(),
+        dev_mode: false,
+    })
+    .custom_method("copilot/setEditorInfo", kcl_lib::lsp::copilot::Backend::set_editor_info)
+    .custom_method(
+        "copilot/getCompletions",
+        kcl_lib::lsp::copilot::Backend::get_completions_cycling,
+    )
+    .custom_method(
+        "copilot/notifyAccepted",
+        kcl_lib::lsp::copilot::Backend::accept_completion,
+    )
+    .custom_method(
+        "copilot/notifyRejected",
+        kcl_lib::lsp::copilot::Backend::reject_completions,
+    )
+    .finish())
+}
+
+/// Serve over stdio, or over tcp with a fresh backend for every connection.
+async fn serve<S, F, Fut>(server: &Server, new_service: F) -> Result<()>
+where
+    S: LanguageServer,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<(LspService<S>, ClientSocket)>>,
+{
+    if server.stdio {
+        let (service, socket) = new_service().await?;
+        tower_lsp::Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+            .serve(service)
+            .await;
+        return Ok(());
+    }
+
+    let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{}", server.socket)).await?;
+    // Stdout might be the rpc channel for other transports, so log to stderr.
+    eprintln!("kcl-language-server listening on {}", listener.local_addr()?);
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let (service, socket) = new_service().await?;
+        let (read, write) = tokio::io::split(stream);
+        tokio::spawn(tower_lsp::Server::new(read, write, socket).serve(service));
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let opts = Opts::parse();
+    if opts.copilot {
+        let provider = completion_provider(&opts)?;
+        serve(&opts.server, || copilot_service(provider.clone())).await
+    } else {
+        serve(&opts.server, kcl_service).await
+    }
+}
@@ -0,0 +1,80 @@
+//! Semantic diff and `git merge` driver for KCL files.
+//!
+//! To let git merge `.kcl` files statement by statement instead of line by line:
+//!
+//! ```text
+//! git config merge.kcl.name "KCL semantic merge"
+//! git config merge.kcl.driver "kcl-merge merge %O %A %B --output %A"
+//! echo "*.kcl merge=kcl" >> .gitattributes
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use kcl_lib::ast::{diff, types::Program};
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "kcl-merge",
+    version,
+    about = "Semantic diff and three-way merge of KCL files"
+)]
+struct Opts {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the statements that changed between two files.
+    Diff { old: PathBuf, new: PathBuf },
+    /// Merge the edits two files made to a common ancestor.
+    /// Exits with status 1 if there are conflicts, like `git merge-file`.
+    Merge {
+        base: PathBuf,
+        ours: PathBuf,
+        theirs: PathBuf,
+        /// Where to write the result. Defaults to stdout.
+        #[clap(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn parse(path: &Path) -> Result<Program> {
+    let code = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    kcl_lib::parser::parse(&code).map_err(|e| anyhow::anyhow!("parsing {}: {}", path.display(), e.message()))
+}
+
+fn main() -> Result<()> {
+    let opts = Opts::parse();
+    match opts.command {
+        Command::Diff { old, new } => {
+            for change in diff::diff(&parse(&old)?, &parse(&new)?) {
+                println!("{}", change);
+            }
+        }
+        Command::Merge {
+            base,
+            ours,
+            theirs,
+            output,
+        } => {
+            let result = diff::merge(&parse(&base)?, &parse(&ours)?, &parse(&theirs)?)
+                .map_err(|e| anyhow::anyhow!("merging: {}", e.message()))?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, &result.source).with_context(|| format!("writing {}", path.display()))?
+                }
+                None => print!("{}", result.source),
+            }
+            for conflict in &result.conflicts {
+                eprintln!("conflict in {}", conflict.name);
+            }
+            if !result.is_clean() {
+                std::process::exit(1);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,267 @@
+//! Bills of materials: the parts an executed program made, counted.
+//!
+//! Solids and imported geometry become parts when they're given a name with `partInfo`, and what
+//! they're made of is kept whether they're parts or not, for exports.
+//! Parts are recorded as the standard library returns them, by id, so copies made by
+//! patterns are counted, and parts made without being bound to a name too. Moving a solid
+//! replaces it with a copy, which takes its place.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    executor::{ExecState, KclValue},
+    std::appearance::MaterialData,
+};
+
+/// What part a solid is.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PartMetadata {
+    /// The name of the part.
+    pub name: String,
+    /// The part number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_number: Option<String>,
+    /// The material the part is made of. Defaults to the name of the material the solid was
+    /// given with `material`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<String>,
+    /// How many of the part the solid stands for, for parts which aren't all modeled.
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+/// A bill of materials.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct BillOfMaterials {
+    /// One line for each different part, by name, part number and then material.
+    pub lines: Vec<BomLine>,
+}
+
+/// A line of a bill of materials.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct BomLine {
+    /// The name of the part.
+    pub name: String,
+    /// The part number.
+    pub part_number: Option<String>,
+    /// The material the part is made of.
+    pub material: Option<String>,
+    /// How many of the part there are.
+    pub quantity: u32,
+}
+
+/// The parts a program has made so far, by the id of their solid or imported geometry.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Parts {
+    parts: HashMap<uuid::Uuid, PartMetadata>,
+    /// What the solids and imported geometry given a material are made of.
+    materials: HashMap<uuid::Uuid, MaterialData>,
+    /// Solids which were replaced by a moved copy, and so are no longer parts.
+    replaced: HashSet<uuid::Uuid>,
+}
+
+impl Parts {
+    /// Record the parts in a value returned by a standard library function, with the
+    /// material they're made of now.
+    pub(crate) fn record(&mut self, value: &KclValue) {
+        match value {
+            KclValue::Solid(solid) => self.add(solid.id, &solid.part, &solid.material),
+            KclValue::Solids { value } => {
+                for solid in value {
+                    self.add(solid.id, &solid.part, &solid.material);
+                }
+            }
+            KclValue::ImportedGeometry(geometry) => self.add(geometry.id, &geometry.part, &geometry.material),
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, id: uuid::Uuid, part: &Option<PartMetadata>, material: &Option<MaterialData>) {
+        if self.replaced.contains(&id) {
+            return;
+        }
+        if let Some(material) = material {
+            self.materials.insert(id, material.clone());
+        }
+        if let Some(part) = part {
+            self.parts.insert(id, with_material(part.clone(), material.as_ref()));
+        }
+    }
+
+    /// The object with id `old` was replaced by its copy `new`, which is the part now.
+    pub(crate) fn replace(&mut self, old: uuid::Uuid, new: uuid::Uuid) {
+        self.replaced.insert(old);
+        if let Some(part) = self.parts.remove(&old) {
+            self.parts.insert(new, part);
+        }
+        if let Some(material) = self.materials.remove(&old) {
+            self.materials.insert(new, material);
+        }
+    }
+
+    /// What each solid or imported geometry given a material is made of, by id.
+    pub fn materials(&self) -> &HashMap<uuid::Uuid, MaterialData> {
+        &self.materials
+    }
+}
+
+impl BillOfMaterials {
+    /// The parts an executed program made. Each solid or imported geometry is counted once,
+    /// however many names it's bound to, and solids which were never given part metadata
+    /// are left out.
+    pub fn from_exec_state(exec_state: &ExecState) -> Self {
+        Self::from_parts(exec_state.parts.parts.values().cloned())
+    }
+
+    fn from_parts(parts: impl IntoIterator<Item = PartMetadata>) -> Self {
+        let mut lines: BTreeMap<(String, Option<String>, Option<String>), u32> = BTreeMap::new();
+        for part in parts {
+            *lines.entry((part.name, part.part_number, part.material)).or_default() += part.quantity;
+        }
+        Self {
+            lines: lines
+                .into_iter()
+                .map(|((name, part_number, material), quantity)| BomLine {
+                    name,
+                    part_number,
+                    material,
+                    quantity,
+                })
+                .collect(),
+        }
+    }
+
+    /// The bill of materials as CSV, with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = "name,part_number,material,quantity\n".to_string();
+        for line in &self.lines {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                csv_field(&line.name),
+                csv_field(line.part_number.as_deref().unwrap_or_default()),
+                csv_field(line.material.as_deref().unwrap_or_default()),
+                line.quantity
+            ));
+        }
+        csv
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The part, made of the solid's material if it doesn't say what it's made of.
+fn with_material(mut part: PartMetadata, material: Option<&MaterialData>) -> PartMetadata {
+    if part.material.is_none() {
+        part.material = material.map(|material| material.name.clone());
+    }
+    part
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(name: &str, material: Option<&str>, quantity: u32) -> PartMetadata {
+        PartMetadata {
+            name: name.to_string(),
+            part_number: None,
+            material: material.map(|m| m.to_string()),
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_bom_csv() {
+        let bom = BillOfMaterials::from_parts([
+            part("Screw, M3", None, 4),
+            part("Bracket", Some("6061-T6"), 1),
+            part("Screw, M3", None, 4),
+            part("Bracket", Some("Steel \"mild\""), 1),
+        ]);
+        assert_eq!(
+            bom.to_csv(),
+            r#"name,part_number,material,quantity
+Bracket,,6061-T6,1
+Bracket,,"Steel ""mild""",1
+"Screw, M3",,,8
+"#
+        );
+    }
+
+    #[test]
+    fn test_moved_parts_are_counted_once() {
+        let (old, new) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let mut parts = Parts::default();
+        parts.add(old, &Some(part("Bracket", None, 1)), &None);
+        parts.replace(old, new);
+        parts.add(new, &Some(part("Bracket", None, 1)), &None);
+        // Still bound to its old name, which is used again.
+        parts.add(old, &Some(part("Bracket", None, 1)), &None);
+        assert_eq!(parts.parts.keys().collect::<Vec<_>>(), vec![&new]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bom_counts_parts_as_made() {
+        let code = r#"const plate = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([20, 0], %)
+  |> line([0, 3], %)
+  |> line([-20, 0], %)
+  |> close(%)
+  |> extrude(10, %)
+  |> partInfo({ name: "Plate" }, %)
+const samePlate = plate
+
+startSketchOn('XZ')
+  |> circle({ center: [0, 0], radius: 1 }, %)
+  |> extrude(1, %)
+  |> partInfo({ name: "Foot" }, %)
+  |> material({ name: "EPDM", density: 1000 }, %)
+"#;
+        let program = crate::parser::parse(code).unwrap();
+        let ctx = crate::executor::ExecutorContext::new_mock(Default::default())
+            .await
+            .unwrap();
+        let exec_state = ctx
+            .run(&program, None, crate::executor::IdGenerator::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(
+            BillOfMaterials::from_exec_state(&exec_state).to_csv(),
+            r#"name,part_number,material,quantity
+Foot,,EPDM,1
+Plate,,,1
+"#
+        );
+        assert_eq!(
+            exec_state
+                .parts
+                .materials()
+                .values()
+                .map(|material| material.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["EPDM"]
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Offline core dump bundles.
+//!
+//! A bundle is a zip archive holding everything a core dump would otherwise upload, so it can be
+//! produced on air-gapped machines and handed over by hand:
+//!
+//! - `coredump.json`: the [`CoreDumpInfo`]
+//! - `engine-commands.json`: the recent engine command log
+//! - `screenshot.png`: a screenshot of the app, if one could be taken
+//! - `kcl/<path>`: the KCL code of every open file
+
+use std::io::{Read, Write};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde_json::Value as JValue;
+
+use crate::coredump::CoreDumpInfo;
+
+const INFO_FILE: &str = "coredump.json";
+const ENGINE_COMMANDS_FILE: &str = "engine-commands.json";
+const SCREENSHOT_FILE: &str = "screenshot.png";
+const KCL_DIR: &str = "kcl/";
+
+/// The file replayed when a bundle has no file with this name.
+pub const MAIN_FILE: &str = "main.kcl";
+
+/// A self-contained core dump that never leaves the machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreDumpBundle {
+    pub info: CoreDumpInfo,
+    /// The KCL code of every open file, keyed by path.
+    pub files: IndexMap<String, String>,
+    /// The recent engine command log.
+    pub engine_commands: JValue,
+    /// A PNG screenshot of the app.
+    pub screenshot: Option<Vec<u8>>,
+}
+
+impl CoreDumpBundle {
+    /// Pack the bundle into a zip archive.
+    pub fn to_zip(&self) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(&mut buf));
+        let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+        zip.start_file(INFO_FILE, options)?;
+        zip.write_all(&serde_json::to_vec_pretty(&self.info)?)?;
+
+        zip.start_file(ENGINE_COMMANDS_FILE, options)?;
+        zip.write_all(&serde_json::to_vec_pretty(&self.engine_commands)?)?;
+
+        if let Some(screenshot) = &self.screenshot {
+            zip.start_file(SCREENSHOT_FILE, options)?;
+            zip.write_all(screenshot)?;
+        }
+
+        for (path, code) in &self.files {
+            zip.start_file(format!("{}{}", KCL_DIR, path.trim_start_matches('/')), options)?;
+            zip.write_all(code.as_bytes())?;
+        }
+
+        // Dropping the `ZipWriter` will have the same effect, but may silently fail
+        zip.finish()?;
+
+        Ok(buf)
+    }
+
+    /// Unpack a bundle from a zip archive.
+    pub fn from_zip(data: &[u8]) -> Result<Self> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data))?;
+
+        let mut info = None;
+        let mut engine_commands = JValue::Null;
+        let mut screenshot = None;
+        let mut files = IndexMap::new();
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            let name = file.name().to_string();
+            let mut contents = vec![];
+            file.read_to_end(&mut contents)?;
+
+            if name == INFO_FILE {
+                info = Some(serde_json::from_slice(&contents)?);
+            } else if name == ENGINE_COMMANDS_FILE {
+                engine_commands = serde_json::from_slice(&contents)?;
+            } else if name == SCREENSHOT_FILE {
+                screenshot = Some(contents);
+            } else if let Some(path) = name.strip_prefix(KCL_DIR) {
+                files.insert(path.to_string(), String::from_utf8(contents)?);
+            }
+        }
+
+        Ok(Self {
+            info: info.ok_or_else(|| anyhow::anyhow!("Core dump bundle is missing `{}`", INFO_FILE))?,
+            files,
+            engine_commands,
+            screenshot,
+        })
+    }
+
+    /// The file to replay: `main.kcl` if there is one, otherwise the first file.
+    pub fn main_file(&self) -> Option<(&str, &str)> {
+        self.files
+            .get_key_value(MAIN_FILE)
+            .or_else(|| self.files.first())
+            .map(|(path, code)| (path.as_str(), code.as_str()))
+    }
+
+    /// Write the bundle to disk.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write(&self, path: &std::path::Path) -> Result<()> {
+        std::fs::write(path, self.to_zip()?)?;
+        Ok(())
+    }
+
+    /// Read a bundle from disk.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read(path: &std::path::Path) -> Result<Self> {
+        Self::from_zip(&std::fs::read(path)?)
+    }
+
+    /// Execute the bundled KCL against the mock engine, for triage.
+    /// The files are unpacked into a temporary project directory so imports between them resolve.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn replay(&self) -> Result<crate::executor::ExecState> {
+        use std::sync::Arc;
+
+        let (main_path, code) = self
+            .main_file()
+            .ok_or_else(|| anyhow::anyhow!("Core dump bundle has no KCL files"))?;
+
+        // Removed once the replay is done.
+        let project_directory = tempfile::Builder::new().prefix("coredump-").tempdir()?;
+        for (path, code) in &self.files {
+            let file = project_directory.path().join(relative_path(path)?);
+            if let Some(parent) = file.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(file, code)?;
+        }
+
+        let program = crate::parser::parse(code)
+            .map_err(|e| anyhow::anyhow!("Failed to parse `{}`: {}", main_path, e.message()))?;
+        let ctx = crate::executor::ExecutorContext {
+            engine: Arc::new(Box::new(crate::engine::conn_mock::EngineConnection::new().await?)),
+            fs: Arc::new(crate::fs::FileManager::new()),
+            stdlib: Arc::new(crate::std::StdLib::new()),
+            settings: Default::default(),
+            context_type: crate::executor::ContextType::Mock,
+        };
+        let exec_state = ctx
+            .run(
+                &program,
+                None,
+                crate::executor::IdGenerator::default(),
+                Some(project_directory.path().display().to_string()),
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to execute `{}`: {}", main_path, e.message()))?;
+
+        Ok(exec_state)
+    }
+}
+
+/// Turn a path from a bundle into one relative to the project directory.
+/// Bundles come from other machines, so any path which would escape the project directory,
+/// like `../../.bashrc`, is refused.
+#[cfg(not(target_arch = "wasm32"))]
+fn relative_path(path: &str) -> Result<std::path::PathBuf> {
+    use std::path::Component;
+
+    let mut relative = std::path::PathBuf::new();
+    for component in std::path::Path::new(path.trim_start_matches('/')).components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                anyhow::bail!("Core dump bundle has a file outside its project: `{}`", path)
+            }
+        }
+    }
+    if relative.as_os_str().is_empty() {
+        anyhow::bail!("Core dump bundle has a file with no name: `{}`", path);
+    }
+    Ok(relative)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::coredump::{OsInfo, WebrtcStats};
+
+    fn bundle(files: &[(&str, &str)]) -> CoreDumpBundle {
+        CoreDumpBundle {
+            info: CoreDumpInfo {
+                id: uuid::Uuid::new_v4(),
+                version: "0.0.1".to_string(),
+                git_rev: "unknown".to_string(),
+                timestamp: chrono::Utc::now(),
+                desktop: true,
+                os: OsInfo {
+                    platform: Some("linux".to_string()),
+                    arch: None,
+                    version: None,
+                    browser: None,
+                },
+                webrtc_stats: WebrtcStats::default(),
+                github_issue_url: None,
+                kcl_code: files.first().map(|(_, code)| code.to_string()).unwrap_or_default(),
+                pool: "".to_string(),
+                client_state: JValue::Null,
+            },
+            files: files
+                .iter()
+                .map(|(path, code)| (path.to_string(), code.to_string()))
+                .collect(),
+            engine_commands: serde_json::json!([{ "type": "send-scene", "data": {} }]),
+            screenshot: Some(vec![0x89, b'P', b'N', b'G']),
+        }
+    }
+
+    #[test]
+    fn test_bundle_zip_roundtrip() {
+        let bundle = bundle(&[("main.kcl", "const x = 1"), ("parts/bracket.kcl", "const y = 2")]);
+        let unpacked = CoreDumpBundle::from_zip(&bundle.to_zip().unwrap()).unwrap();
+        assert_eq!(unpacked, bundle);
+        assert_eq!(unpacked.main_file(), Some(("main.kcl", "const x = 1")));
+    }
+
+    #[test]
+    fn test_bundle_missing_info() {
+        let mut buf = vec![];
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(&mut buf));
+        zip.start_file("kcl/main.kcl", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"const x = 1").unwrap();
+        zip.finish().unwrap();
+
+        let err = CoreDumpBundle::from_zip(&buf).unwrap_err();
+        assert_eq!(err.to_string(), "Core dump bundle is missing `coredump.json`");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bundle_replay() {
+        let bundle = bundle(&[
+            ("lib.kcl", "export fn double = (x) => {\n  return x * 2\n}"),
+            ("main.kcl", "import double from \"lib.kcl\"\nconst doubled = double(4)"),
+        ]);
+        let exec_state = bundle.replay().await.unwrap();
+        assert_eq!(
+            exec_state
+                .memory
+                .get("doubled", crate::executor::SourceRange::default())
+                .unwrap()
+                .get_json_value()
+                .unwrap()
+                .as_f64(),
+            Some(8.0)
+        );
+    }
+
+    #[test]
+    fn test_bundle_relative_path() {
+        assert_eq!(
+            relative_path("/parts/./bracket.kcl").unwrap(),
+            std::path::PathBuf::from("parts/bracket.kcl")
+        );
+        assert_eq!(
+            relative_path("parts/../../evil.kcl").unwrap_err().to_string(),
+            "Core dump bundle has a file outside its project: `parts/../../evil.kcl`"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bundle_replay_refuses_escaping_paths() {
+        let bundle = bundle(&[("main.kcl", "const x = 1"), ("../evil.kcl", "const y = 2")]);
+        let err = bundle.replay().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Core dump bundle has a file outside its project: `../evil.kcl`"
+        );
+    }
+}
@@ -65,7 +65,7 @@ impl CoreDump for CoreDumper {
     }
 
     async fn screenshot(&self) -> Result<String> {
-        // Take a screenshot of the engine.
-        todo!()
+        // TODO: Take a screenshot of the engine.
+        anyhow::bail!("Screenshots are not supported outside the app")
     }
 }
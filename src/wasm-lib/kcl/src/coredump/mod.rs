@@ -1,5 +1,6 @@
 //! Core dump related structures and functions.
 
+pub mod bundle;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod local;
 #[cfg(target_arch = "wasm32")]
@@ -9,6 +10,8 @@ use std::path::Path;
 
 use anyhow::Result;
 use base64::Engine;
+use bundle::CoreDumpBundle;
+use indexmap::IndexMap;
 use kittycad::Client;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -27,6 +30,12 @@ pub trait CoreDump: Clone {
 
     fn kcl_code(&self) -> Result<String>;
 
+    /// Return the KCL code of every open file, keyed by path.
+    /// Defaults to the current file as `main.kcl`.
+    async fn kcl_files(&self) -> Result<IndexMap<String, String>> {
+        Ok(IndexMap::from([(bundle::MAIN_FILE.to_string(), self.kcl_code()?)]))
+    }
+
     fn pool(&self) -> Result<String>;
 
     fn os(&self) -> Result<OsInfo>;
@@ -40,13 +49,18 @@ pub trait CoreDump: Clone {
     /// Return a screenshot of the app.
     async fn screenshot(&self) -> Result<String>;
 
-    /// Get a screenshot of the app and upload it to public cloud storage.
-    async fn upload_screenshot(&self, coredump_id: &Uuid, zoo_client: &Client) -> Result<String> {
+    /// Get a screenshot of the app as PNG bytes.
+    async fn screenshot_png(&self) -> Result<Vec<u8>> {
         let screenshot = self.screenshot().await?;
         let cleaned = screenshot.trim_start_matches("data:image/png;base64,");
 
         // Base64 decode the screenshot.
-        let data = base64::engine::general_purpose::STANDARD.decode(cleaned)?;
+        Ok(base64::engine::general_purpose::STANDARD.decode(cleaned)?)
+    }
+
+    /// Get a screenshot of the app and upload it to public cloud storage.
+    async fn upload_screenshot(&self, coredump_id: &Uuid, zoo_client: &Client) -> Result<String> {
+        let data = self.screenshot_png().await?;
         // Upload the screenshot.
         let links = zoo_client
             .meta()
@@ -73,24 +87,8 @@ pub trait CoreDump: Clone {
         zoo_client.set_base_url(&self.base_api_url()?);
 
         let coredump_id = uuid::Uuid::new_v4();
-        let client_state = self.get_client_state().await?;
-        let webrtc_stats = self.get_webrtc_stats().await?;
-        let os = self.os()?;
         let screenshot_url = self.upload_screenshot(&coredump_id, &zoo_client).await?;
-
-        let mut core_dump_info = CoreDumpInfo {
-            id: coredump_id,
-            version: self.version()?,
-            git_rev: git_rev::try_revision_string!().map_or_else(|| "unknown".to_string(), |s| s.to_string()),
-            timestamp: chrono::Utc::now(),
-            desktop: self.is_desktop()?,
-            kcl_code: self.kcl_code()?,
-            os,
-            webrtc_stats,
-            github_issue_url: None,
-            pool: self.pool()?,
-            client_state,
-        };
+        let mut core_dump_info = self.info(coredump_id).await?;
 
         // pretty-printed JSON byte vector of the coredump.
         let data = serde_json::to_vec_pretty(&core_dump_info)?;
@@ -117,6 +115,43 @@ pub trait CoreDump: Clone {
 
         Ok(core_dump_info)
     }
+
+    /// Dump the app info into a bundle that stays on this machine.
+    /// Nothing is uploaded and no auth token is needed, so this works on air-gapped machines.
+    async fn dump_local(&self) -> Result<CoreDumpBundle> {
+        let info = self.info(uuid::Uuid::new_v4()).await?;
+        let engine_commands = info
+            .client_state
+            .pointer("/engine_command_manager/command_logs")
+            .cloned()
+            .unwrap_or_else(|| JValue::Array(vec![]));
+        // A missing screenshot should not stop the rest of the dump.
+        let screenshot = self.screenshot_png().await.ok();
+
+        Ok(CoreDumpBundle {
+            files: self.kcl_files().await?,
+            engine_commands,
+            screenshot,
+            info,
+        })
+    }
+
+    /// Collect the app info, without uploading anything.
+    async fn info(&self, coredump_id: Uuid) -> Result<CoreDumpInfo> {
+        Ok(CoreDumpInfo {
+            id: coredump_id,
+            version: self.version()?,
+            git_rev: git_rev::try_revision_string!().map_or_else(|| "unknown".to_string(), |s| s.to_string()),
+            timestamp: chrono::Utc::now(),
+            desktop: self.is_desktop()?,
+            kcl_code: self.kcl_code()?,
+            os: self.os()?,
+            webrtc_stats: self.get_webrtc_stats().await?,
+            github_issue_url: None,
+            pool: self.pool()?,
+            client_state: self.get_client_state().await?,
+        })
+    }
 }
 
 /// The app info structure.
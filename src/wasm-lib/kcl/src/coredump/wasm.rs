@@ -1,6 +1,7 @@
 //! Functions for getting core dump information via wasm.
 
 use anyhow::Result;
+use indexmap::IndexMap;
 use serde_json::Value as JValue;
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -26,6 +27,9 @@ extern "C" {
     #[wasm_bindgen(method, js_name = kclCode, catch)]
     fn kcl_code(this: &CoreDumpManager) -> Result<String, js_sys::Error>;
 
+    #[wasm_bindgen(method, js_name = kclFiles, catch)]
+    fn kcl_files(this: &CoreDumpManager) -> Result<js_sys::Promise, js_sys::Error>;
+
     #[wasm_bindgen(method, js_name = getOsInfo, catch)]
     fn get_os_info(this: &CoreDumpManager) -> Result<String, js_sys::Error>;
 
@@ -82,6 +86,27 @@ impl CoreDump for CoreDumper {
             .map_err(|e| anyhow::anyhow!("Failed to get response from kcl code: {:?}", e))
     }
 
+    async fn kcl_files(&self) -> Result<IndexMap<String, String>> {
+        let promise = self
+            .manager
+            .kcl_files()
+            .map_err(|e| anyhow::anyhow!("Failed to get promise from kcl files: {:?}", e))?;
+
+        let value = JsFuture::from(promise)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get response from kcl files: {:?}", e))?;
+
+        // Parse the value as a string.
+        let s = value
+            .as_string()
+            .ok_or_else(|| anyhow::anyhow!("Failed to get string from response from kcl files: `{:?}`", value))?;
+
+        let files: IndexMap<String, String> =
+            serde_json::from_str(&s).map_err(|e| anyhow::anyhow!("Failed to parse kcl files: {:?}", e))?;
+
+        Ok(files)
+    }
+
     fn pool(&self) -> Result<String> {
         self.manager
             .pool()
@@ -311,12 +311,15 @@ fn generate_function(internal_fn: Box<dyn StdLibFn>) -> Result<BTreeMap<String,
     let fn_name = internal_fn.name();
     let snake_case_name = clean_function_name(&fn_name);
 
+    let examples_without_images = internal_fn.examples_without_images();
     let examples: Vec<serde_json::Value> = internal_fn
         .examples()
         .iter()
         .enumerate()
         .map(|(index, example)| {
-            let image_base64 = if !internal_fn.tags().contains(&"utilities".to_string()) {
+            let image_base64 = if !internal_fn.tags().contains(&"utilities".to_string())
+                && !examples_without_images.contains(&index)
+            {
                 let image_path = format!(
                     "{}/tests/outputs/serial_test_example_{}{}.png",
                     env!("CARGO_MANIFEST_DIR"),
@@ -333,6 +336,7 @@ fn generate_function(internal_fn: Box<dyn StdLibFn>) -> Result<BTreeMap<String,
             json!({
                 "content": example,
                 "image_base64": image_base64,
+                "no_image": examples_without_images.contains(&index),
             })
         })
         .collect();
@@ -527,6 +531,8 @@ fn generate_type(
     if !(name.is_case(convert_case::Case::Pascal)
         || name == "Point3d"
         || name == "Point2d"
+        || name == "BoundingBox3d"
+        || name == "Axis3d"
         || name == "CircularPattern2dData"
         || name == "CircularPattern3dData"
         || name == "LinearPattern2dData"
@@ -92,6 +92,14 @@ impl StdLibFnArg {
     pub fn description(&self) -> Option<String> {
         get_description_string_from_schema(&self.schema.clone())
     }
+
+    /// The properties of the argument, if it is an object, with their descriptions.
+    /// For enums of objects this is every property of every variant.
+    pub fn properties(&self) -> Vec<(String, Option<String>)> {
+        let mut properties = Vec::new();
+        get_properties_from_schema(&self.schema.schema.clone().into(), &mut properties);
+        properties
+    }
 }
 
 impl From<StdLibFnArg> for ParameterInformation {
@@ -138,6 +146,9 @@ pub trait StdLibFn: std::fmt::Debug + Send + Sync {
     /// Any example code blocks.
     fn examples(&self) -> Vec<String>;
 
+    /// The indices of the examples marked `no_image`, which have no rendered image.
+    fn examples_without_images(&self) -> Vec<usize>;
+
     /// The function itself.
     fn std_lib_fn(&self) -> crate::std::StdFn;
 
@@ -595,6 +606,35 @@ fn get_autocomplete_snippet_from_schema(
     }
 }
 
+fn get_properties_from_schema(schema: &schemars::schema::Schema, properties: &mut Vec<(String, Option<String>)>) {
+    let schemars::schema::Schema::Object(o) = schema else {
+        return;
+    };
+
+    if let Some(obj_val) = &o.object {
+        for (prop_name, prop) in obj_val.properties.iter() {
+            if prop_name.starts_with('_') || properties.iter().any(|(name, _)| name == prop_name) {
+                continue;
+            }
+            let description = match prop {
+                schemars::schema::Schema::Object(prop) => prop.metadata.as_ref().and_then(|m| m.description.clone()),
+                schemars::schema::Schema::Bool(_) => None,
+            };
+            properties.push((prop_name.to_string(), description));
+        }
+    }
+
+    if let Some(subschemas) = &o.subschemas {
+        for subschema in [&subschemas.one_of, &subschemas.any_of, &subschemas.all_of]
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            get_properties_from_schema(subschema, properties);
+        }
+    }
+}
+
 fn get_autocomplete_string_from_schema(schema: &schemars::schema::Schema) -> Result<String> {
     match schema {
         schemars::schema::Schema::Object(o) => {
@@ -861,10 +901,9 @@ mod tests {
             r#"patternCircular3d({
 	instances: ${0:10},
 	axis: [${1:3.14}, ${2:3.14}, ${3:3.14}],
-	center: [${4:3.14}, ${5:3.14}, ${6:3.14}],
-	arcDegrees: ${7:3.14},
-	rotateDuplicates: ${8:false},
-}, ${9:%})${}"#
+	arcDegrees: ${4:3.14},
+	rotateDuplicates: ${5:false},
+}, ${6:%})${}"#
         );
     }
 
@@ -928,6 +967,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_properties_shell() {
+        let shell_fn: Box<dyn StdLibFn> = Box::new(crate::std::shell::Shell);
+        let properties = shell_fn.args(true)[0].properties();
+        assert_eq!(
+            properties,
+            vec![
+                ("thickness".to_string(), Some("The thickness of the shell.".to_string())),
+                ("faces".to_string(), Some("The faces you want removed.".to_string())),
+            ]
+        );
+    }
+
     // We want to test the snippets we compile at lsp start.
     #[test]
     fn get_all_stdlib_autocomplete_snippets() {
@@ -19,6 +19,7 @@ use parse_display::{Display, FromStr};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JValue;
+use sha2::{Digest as _, Sha256};
 use tower_lsp::lsp_types::{Position as LspPosition, Range as LspRange};
 
 type Point2D = kcmc::shared::Point2d<f64>;
@@ -26,14 +27,18 @@ type Point3D = kcmc::shared::Point3d<f64>;
 
 use crate::{
     ast::types::{
-        human_friendly_type, BodyItem, Expr, ExpressionStatement, FunctionExpression, ImportStatement, ItemVisibility,
-        KclNone, Program, ReturnStatement, TagDeclarator,
+        digest::Digest, human_friendly_type, BodyItem, Expr, ExpressionStatement, FunctionExpression, ImportStatement,
+        ItemVisibility, KclNone, Program, ReturnStatement, TagDeclarator,
     },
     engine::{EngineManager, ExecutionKind},
     errors::{KclError, KclErrorDetails},
     fs::{FileManager, FileSystem},
     settings::types::UnitLength,
-    std::{FnAsArg, StdLib},
+    std::{
+        appearance::{AppearanceData, MaterialData},
+        holes::HoleInfo,
+        FnAsArg, StdLib,
+    },
 };
 
 /// State for executing a program.
@@ -55,9 +60,21 @@ pub struct ExecState {
     /// The stack of import statements for detecting circular module imports.
     /// If this is empty, we're not currently executing an import statement.
     pub import_stack: Vec<std::path::PathBuf>,
+    /// While a function from another file runs, the call in this file which ran it.
+    /// Geometry the function makes is recorded against it in the artifact graph.
+    pub imported_source_range: Option<SourceRange>,
     /// The directory of the current project.  This is used for resolving import
     /// paths.  If None is given, the current working directory is used.
     pub project_directory: Option<String>,
+    /// The parts imported and the tree of their instances.
+    pub assembly: crate::assembly::Assembly,
+    /// The geometry the program made and the code which made it.
+    pub artifact_graph: crate::artifact_graph::ArtifactGraph,
+    /// The parts the program made, for its bill of materials.
+    pub parts: crate::bom::Parts,
+    /// Solids which are a profile sketched on a plane and extruded, with nothing cut from or
+    /// added to them since, so they can be measured from their profile.
+    pub plain_extrusions: HashSet<uuid::Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
@@ -128,6 +145,33 @@ impl ProgramMemory {
         }))
     }
 
+    /// Update every binding of a solid which has been moved to the solid where it is now.
+    /// The engine replaces a moved solid with a copy, so the solid it was moved from is gone.
+    pub fn update_moved_solid(&mut self, from: uuid::Uuid, solid: &Solid) {
+        for item in self.environments.iter_mut().flat_map(|env| env.bindings.values_mut()) {
+            match item {
+                KclValue::Solid(eg) if eg.id == from => *eg = Box::new(solid.clone()),
+                KclValue::Solids { value } => {
+                    for eg in value.iter_mut().filter(|eg| eg.id == from) {
+                        *eg = Box::new(solid.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Update every binding of imported geometry which has been moved, like [`Self::update_moved_solid`].
+    pub fn update_moved_geometry(&mut self, from: uuid::Uuid, geometry: &ImportedGeometry) {
+        for item in self.environments.iter_mut().flat_map(|env| env.bindings.values_mut()) {
+            if let KclValue::ImportedGeometry(g) = item {
+                if g.id == from {
+                    *g = Box::new(geometry.clone());
+                }
+            }
+        }
+    }
+
     /// Find all solids in the memory that are on a specific sketch id.
     /// This does not look inside closures.  But as long as we do not allow
     /// mutation of variables in KCL, closure memory should be a subset of this.
@@ -235,6 +279,11 @@ impl Environment {
         self.bindings.contains_key(key)
     }
 
+    /// Every name bound in this environment, with its value.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &KclValue)> {
+        self.bindings.iter()
+    }
+
     pub fn update_sketch_tags(&mut self, sg: &Sketch) {
         if sg.tags.is_empty() {
             return;
@@ -305,13 +354,39 @@ impl DynamicState {
     }
 }
 
-/// A generator for ArtifactIds that can be stable across executions.
+/// A generator for ArtifactIds that are stable across executions.
+///
+/// Ids are made from the statement being executed rather than from how many ids came
+/// before: each is a hash of the statement's key (the name it declares, or its position
+/// in its body if it doesn't declare one), the call path it was reached by (the keys of
+/// the statements that called the function it's in) and how many ids the statement has
+/// made so far. Editing one statement, or adding a declaration, doesn't change the ids
+/// made by the others, and a program makes the same ids on every machine.
+///
+/// They're UUIDv8s, as RFC 9562 suggests for names hashed with SHA-256.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
 #[ts(export)]
 #[serde(rename_all = "camelCase")]
 pub struct IdGenerator {
+    /// How many ids have been made in the current scope.
+    next_id: usize,
+    /// The key of the statement ids are being made for, hashed with the call path it was
+    /// reached by. `None` outside any statement.
+    #[serde(skip)]
+    #[ts(skip)]
+    scope: Option<Digest>,
+    /// How many times each statement has been entered from each scope, so repeated
+    /// statements, and functions called more than once from one statement, get their own ids.
+    #[serde(skip)]
+    #[ts(skip)]
+    entered: HashMap<(Option<Digest>, Digest), u64>,
+}
+
+/// Where an [`IdGenerator`] was before it entered a statement.
+#[derive(Debug)]
+pub(crate) struct IdScope {
+    scope: Option<Digest>,
     next_id: usize,
-    ids: Vec<uuid::Uuid>,
 }
 
 impl IdGenerator {
@@ -320,22 +395,78 @@ impl IdGenerator {
     }
 
     pub fn next_uuid(&mut self) -> uuid::Uuid {
-        if let Some(id) = self.ids.get(self.next_id) {
-            self.next_id += 1;
-            *id
-        } else {
-            let id = uuid::Uuid::new_v4();
-            self.ids.push(id);
-            self.next_id += 1;
-            id
+        let mut hasher = Sha256::new();
+        hasher.update(b"kcl artifact id");
+        hasher.update(self.scope.unwrap_or_default());
+        hasher.update((self.next_id as u64).to_le_bytes());
+        self.next_id += 1;
+
+        let hash: Digest = hasher.finalize().into();
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&hash[..16]);
+        uuid::Builder::from_custom_bytes(bytes).into_uuid()
+    }
+
+    /// Start making ids for the statement at `index` of the body being run. Hand what's
+    /// returned back to [`IdGenerator::exit`] once the statement has run.
+    pub(crate) fn enter(&mut self, statement: &BodyItem, index: usize) -> IdScope {
+        let statement = statement_key(statement, index);
+        let entered = self.entered.entry((self.scope, statement)).or_default();
+        let mut hasher = Sha256::new();
+        hasher.update(self.scope.unwrap_or_default());
+        hasher.update(statement);
+        hasher.update(entered.to_le_bytes());
+        *entered += 1;
+
+        IdScope {
+            scope: std::mem::replace(&mut self.scope, Some(hasher.finalize().into())),
+            next_id: std::mem::take(&mut self.next_id),
+        }
+    }
+
+    /// Go back to making ids for the scope a statement was entered from.
+    pub(crate) fn exit(&mut self, scope: IdScope) {
+        self.scope = scope.scope;
+        self.next_id = scope.next_id;
+    }
+
+    /// Forget the statements earlier runs entered, so running a program again makes the same
+    /// ids. Ids already made outside any statement, like the default planes', aren't made again.
+    fn start_program(&mut self) {
+        self.scope = None;
+        self.entered.clear();
+    }
+}
+
+/// What tells a statement apart from the others in its body: the names it declares, or its
+/// position if it doesn't declare any. Unlike a digest of the statement, this doesn't change
+/// when the statement is edited.
+fn statement_key(statement: &BodyItem, index: usize) -> Digest {
+    let mut hasher = Sha256::new();
+    match statement {
+        BodyItem::VariableDeclaration(declaration) => {
+            hasher.update(b"declaration");
+            for declarator in &declaration.declarations {
+                hasher.update(declarator.id.name.as_bytes());
+                hasher.update([0]);
+            }
+        }
+        _ => {
+            hasher.update(b"statement");
+            hasher.update((index as u64).to_le_bytes());
         }
     }
+    hasher.finalize().into()
 }
 
+// UserVal is used in too many places to box, and with ImportedGeometry boxed it's the only
+// variant left over the lint's threshold.
+
 /// Any KCL value.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
 #[ts(export)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum KclValue {
     UserVal(UserVal),
     TagIdentifier(Box<TagIdentifier>),
@@ -347,7 +478,7 @@ pub enum KclValue {
     Solids {
         value: Vec<Box<Solid>>,
     },
-    ImportedGeometry(ImportedGeometry),
+    ImportedGeometry(Box<ImportedGeometry>),
     #[ts(skip)]
     Function {
         #[serde(skip)]
@@ -637,6 +768,18 @@ pub struct ImportedGeometry {
     pub id: uuid::Uuid,
     /// The original file paths.
     pub value: Vec<String>,
+    /// Where the geometry has been moved to since it was imported.
+    #[serde(default, skip_serializing_if = "Transform3d::is_identity")]
+    pub transform: Transform3d,
+    /// Which part the geometry is, for bills of materials, set by `partInfo`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part: Option<crate::bom::PartMetadata>,
+    /// How the geometry looks, set by `appearance`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appearance: Option<AppearanceData>,
+    /// What the geometry is made of, set by `material`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<MaterialData>,
     #[serde(rename = "__meta")]
     pub meta: Vec<Metadata>,
 }
@@ -1281,6 +1424,22 @@ pub struct Solid {
     /// Chamfers or fillets on this solid.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub edge_cuts: Vec<EdgeCut>,
+    /// Holes cut into this solid by hole features.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub holes: Vec<HoleInfo>,
+    /// Where the solid has been moved to since it was made, by `translate`,
+    /// `rotate`, `scaleBy` or `transformBy`.
+    #[serde(default, skip_serializing_if = "Transform3d::is_identity")]
+    pub transform: Transform3d,
+    /// Which part the solid is, for bills of materials, set by `partInfo`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part: Option<crate::bom::PartMetadata>,
+    /// How the solid looks, set by `appearance`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appearance: Option<AppearanceData>,
+    /// What the solid is made of, set by `material`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub material: Option<MaterialData>,
     /// Metadata.
     #[serde(rename = "__meta")]
     pub meta: Vec<Metadata>,
@@ -1514,6 +1673,101 @@ impl From<Point3d> for kittycad_modeling_cmds::shared::Point3d<LengthUnit> {
     }
 }
 
+/// A 4x4 affine transform in global coordinates, as rows, applied to column vectors.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+pub struct Transform3d(pub [[f64; 4]; 4]);
+
+impl Default for Transform3d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform3d {
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Move by the offset.
+    pub fn translation(offset: Point3d) -> Self {
+        let mut m = Self::IDENTITY;
+        m.0[0][3] = offset.x;
+        m.0[1][3] = offset.y;
+        m.0[2][3] = offset.z;
+        m
+    }
+
+    /// Scale along each global axis, about the global origin.
+    pub fn scale(factors: Point3d) -> Self {
+        let mut m = Self::IDENTITY;
+        m.0[0][0] = factors.x;
+        m.0[1][1] = factors.y;
+        m.0[2][2] = factors.z;
+        m
+    }
+
+    /// Rotate by the angle in degrees, counterclockwise looking down the axis, about the
+    /// line along the axis through `origin`. Returns `None` if the axis is the zero vector.
+    pub fn rotation(axis: Point3d, angle: f64, origin: Point3d) -> Option<Self> {
+        let len = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+        if len <= f64::EPSILON {
+            return None;
+        }
+        let (x, y, z) = (axis.x / len, axis.y / len, axis.z / len);
+        let (s, c) = angle.to_radians().sin_cos();
+        let t = 1.0 - c;
+        let rotation = Self([
+            [t * x * x + c, t * x * y - s * z, t * x * z + s * y, 0.0],
+            [t * x * y + s * z, t * y * y + c, t * y * z - s * x, 0.0],
+            [t * x * z - s * y, t * y * z + s * x, t * z * z + c, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Some(
+            Self::translation(Point3d::new(-origin.x, -origin.y, -origin.z))
+                .then(&rotation)
+                .then(&Self::translation(origin)),
+        )
+    }
+
+    /// This transform followed by `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut m = [[0.0; 4]; 4];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| next.0[i][k] * self.0[k][j]).sum();
+            }
+        }
+        Self(m)
+    }
+
+    pub fn transform_point(&self, p: Point3d) -> Point3d {
+        let m = &self.0;
+        Point3d::new(
+            m[0][0] * p.x + m[0][1] * p.y + m[0][2] * p.z + m[0][3],
+            m[1][0] * p.x + m[1][1] * p.y + m[1][2] * p.z + m[1][3],
+            m[2][0] * p.x + m[2][1] * p.y + m[2][2] * p.z + m[2][3],
+        )
+    }
+
+    /// Transform a direction, which unlike a point isn't moved by the translation.
+    pub fn transform_vector(&self, v: Point3d) -> Point3d {
+        let m = &self.0;
+        Point3d::new(
+            m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z,
+            m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z,
+            m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z,
+        )
+    }
+}
+
 /// Metadata.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema, Eq)]
 #[ts(export)]
@@ -1786,6 +2040,15 @@ impl ExtrudeSurface {
         }
     }
 
+    pub fn face_id(&self) -> uuid::Uuid {
+        match self {
+            ExtrudeSurface::ExtrudePlane(ep) => ep.face_id,
+            ExtrudeSurface::ExtrudeArc(ea) => ea.face_id,
+            ExtrudeSurface::Fillet(f) => f.face_id,
+            ExtrudeSurface::Chamfer(c) => c.face_id,
+        }
+    }
+
     pub fn get_tag(&self) -> Option<TagDeclarator> {
         match self {
             ExtrudeSurface::ExtrudePlane(ep) => ep.tag.clone(),
@@ -1888,6 +2151,36 @@ impl From<crate::settings::types::ModelingSettings> for ExecutorSettings {
     }
 }
 
+/// Create a client for the Zoo API and engine. The engine address is `engine_addr` if given,
+/// otherwise the `LOCAL_ENGINE_ADDR` environment variable if it's set.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn new_zoo_client(token: String, engine_addr: Option<String>) -> kittycad::Client {
+    let user_agent = concat!(env!("CARGO_PKG_NAME"), ".rs/", env!("CARGO_PKG_VERSION"));
+    let http_client = reqwest::Client::builder()
+        .user_agent(user_agent)
+        // For file conversions we need this to be long.
+        .timeout(std::time::Duration::from_secs(600))
+        .connect_timeout(std::time::Duration::from_secs(60));
+    let ws_client = reqwest::Client::builder()
+        .user_agent(user_agent)
+        // For file conversions we need this to be long.
+        .timeout(std::time::Duration::from_secs(600))
+        .connect_timeout(std::time::Duration::from_secs(60))
+        .connection_verbose(true)
+        .tcp_keepalive(std::time::Duration::from_secs(600))
+        .http1_only();
+
+    let mut client = kittycad::Client::new_from_reqwest(token, http_client, ws_client);
+    // Set a local engine address if it's set.
+    if let Ok(addr) = std::env::var("LOCAL_ENGINE_ADDR") {
+        client.set_base_url(addr);
+    }
+    if let Some(addr) = engine_addr {
+        client.set_base_url(addr);
+    }
+    client
+}
+
 impl ExecutorContext {
     /// Create a new default executor context.
     /// Also returns the response HTTP headers from the server.
@@ -1935,6 +2228,28 @@ impl ExecutorContext {
         })
     }
 
+    /// Create a new executor context connected to the engine,
+    /// authenticated with the `KITTYCAD_API_TOKEN` environment variable.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn new_with_default_client(settings: ExecutorSettings) -> Result<Self> {
+        let token = std::env::var("KITTYCAD_API_TOKEN").map_err(|_| anyhow::anyhow!("KITTYCAD_API_TOKEN not set"))?;
+        let client = new_zoo_client(token, None);
+
+        Self::new(&client, settings).await
+    }
+
+    /// Create a new executor context that mocks the engine, for when only the program memory matters.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn new_mock(settings: ExecutorSettings) -> Result<Self> {
+        Ok(Self {
+            engine: Arc::new(Box::new(crate::engine::conn_mock::EngineConnection::new().await?)),
+            fs: Arc::new(FileManager::new()),
+            stdlib: Arc::new(StdLib::new()),
+            settings,
+            context_type: ContextType::Mock,
+        })
+    }
+
     pub fn is_mock(&self) -> bool {
         self.context_type == ContextType::Mock || self.context_type == ContextType::MockCustomForwarded
     }
@@ -1942,32 +2257,8 @@ impl ExecutorContext {
     /// For executing unit tests.
     #[cfg(not(target_arch = "wasm32"))]
     pub async fn new_for_unit_test(units: UnitLength, engine_addr: Option<String>) -> Result<Self> {
-        let user_agent = concat!(env!("CARGO_PKG_NAME"), ".rs/", env!("CARGO_PKG_VERSION"));
-        let http_client = reqwest::Client::builder()
-            .user_agent(user_agent)
-            // For file conversions we need this to be long.
-            .timeout(std::time::Duration::from_secs(600))
-            .connect_timeout(std::time::Duration::from_secs(60));
-        let ws_client = reqwest::Client::builder()
-            .user_agent(user_agent)
-            // For file conversions we need this to be long.
-            .timeout(std::time::Duration::from_secs(600))
-            .connect_timeout(std::time::Duration::from_secs(60))
-            .connection_verbose(true)
-            .tcp_keepalive(std::time::Duration::from_secs(600))
-            .http1_only();
-
         let token = std::env::var("KITTYCAD_API_TOKEN").expect("KITTYCAD_API_TOKEN not set");
-
-        // Create the client.
-        let mut client = kittycad::Client::new_from_reqwest(token, http_client, ws_client);
-        // Set a local engine address if it's set.
-        if let Ok(addr) = std::env::var("LOCAL_ENGINE_ADDR") {
-            client.set_base_url(addr);
-        }
-        if let Some(addr) = engine_addr {
-            client.set_base_url(addr);
-        }
+        let client = new_zoo_client(token, engine_addr);
 
         let ctx = ExecutorContext::new(
             &client,
@@ -2027,6 +2318,7 @@ impl ExecutorContext {
             project_directory,
             ..Default::default()
         };
+        exec_state.id_generator.start_program();
         // Before we even start executing the program, set the units.
         self.engine
             .batch_modeling_cmd(
@@ -2061,7 +2353,8 @@ impl ExecutorContext {
     ) -> Result<Option<KclValue>, KclError> {
         let mut last_expr = None;
         // Iterate over the body of the program.
-        for statement in &program.body {
+        for (index, statement) in program.body.iter().enumerate() {
+            let id_scope = exec_state.id_generator.enter(statement, index);
             match statement {
                 BodyItem::ImportStatement(import_stmt) => {
                     let source_range = SourceRange::from(import_stmt);
@@ -2139,6 +2432,13 @@ impl ExecutorContext {
                             }));
                         }
 
+                        // Functions from other files are parts which can be instanced.
+                        if let KclValue::Function { expression, .. } = item {
+                            exec_state
+                                .assembly
+                                .add_part(import_item.identifier(), &path, expression);
+                        }
+
                         // Add the item to the current module.
                         exec_state.memory.add(
                             import_item.identifier(),
@@ -2206,6 +2506,7 @@ impl ExecutorContext {
                     last_expr = None;
                 }
             }
+            exec_state.id_generator.exit(id_scope);
         }
 
         if BodyType::Root == body_type {
@@ -2911,6 +3212,23 @@ let shape = layer() |> patternTransform(10, transform, %)
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_bind_scale_and_transform() {
+        let ast = r#"fn transform = (x) => {
+  return x * 2
+}
+fn grow = (scale) => {
+  return scale + 1
+}
+const doubled = transform(3)
+const grown = grow(3)
+"#;
+
+        let memory = parse_execute(ast).await.unwrap();
+        assert_eq!(serde_json::json!(6.0), mem_get_json(&memory, "doubled"));
+        assert_eq!(serde_json::json!(4.0), mem_get_json(&memory, "grown"));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_execute_function_with_parameter_redefined_outside() {
         let ast = r#"
@@ -3519,6 +3837,41 @@ let w = f() + f()
         }
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_ids_stable_across_edits() {
+        let part = r#"const part = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %)
+  |> line([0, 10], %)
+  |> close(%)
+  |> extrude(5, %)
+"#;
+        let before = parse_execute(part).await.unwrap();
+        let after = parse_execute(&format!(
+            "const other = startSketchOn('XZ')\n  |> circle({{ center: [0, 0], radius: 2 }}, %)\n{part}"
+        ))
+        .await
+        .unwrap();
+        assert_eq!(mem_get_json(&before, "part")["id"], mem_get_json(&after, "part")["id"]);
+
+        // Nor does editing the statement itself.
+        let edited = parse_execute(&part.replace("extrude(5", "extrude(8")).await.unwrap();
+        assert_eq!(mem_get_json(&before, "part")["id"], mem_get_json(&edited, "part")["id"]);
+
+        // A function called twice from one statement makes different ids each time.
+        let calls = parse_execute(
+            r#"fn make = () => {
+  return startSketchOn('XY') |> circle({ center: [0, 0], radius: 1 }, %)
+}
+const pair = [make(), make()]
+"#,
+        )
+        .await
+        .unwrap();
+        let pair = mem_get_json(&calls, "pair");
+        assert_ne!(pair[0]["id"], pair[1]["id"]);
+    }
+
     #[test]
     fn test_serialize_memory_item() {
         let mem = KclValue::Solids {
@@ -0,0 +1,167 @@
+//! Exports: writing what a program knows about its solids, but the engine doesn't, into the files
+//! the engine exports.
+//!
+//! Materials given with `material` go into glTF files, as a `kclMaterials` list in the `extras`
+//! of the root, with the id of each solid or imported geometry and the name and density of what
+//! it's made of. Other formats have nowhere to put them.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+
+use crate::std::appearance::MaterialData;
+
+/// The magic number a binary glTF file starts with, `glTF`.
+const GLB_MAGIC: u32 = 0x4654_6c67;
+/// The type of the JSON chunk of a binary glTF file, `JSON`.
+const GLB_JSON_CHUNK: u32 = 0x4e4f_534a;
+
+/// The exported file, with the materials written into it if it's glTF.
+pub fn add_materials(name: &str, contents: Vec<u8>, materials: &HashMap<uuid::Uuid, MaterialData>) -> Result<Vec<u8>> {
+    if materials.is_empty() {
+        return Ok(contents);
+    }
+    let extension = name.rsplit('.').next().unwrap_or_default().to_ascii_lowercase();
+    match extension.as_str() {
+        "gltf" => {
+            let mut json: serde_json::Value = serde_json::from_slice(&contents)?;
+            add_materials_to_json(&mut json, materials)?;
+            Ok(serde_json::to_vec(&json)?)
+        }
+        "glb" => add_materials_to_glb(&contents, materials),
+        _ => Ok(contents),
+    }
+}
+
+fn add_materials_to_json(json: &mut serde_json::Value, materials: &HashMap<uuid::Uuid, MaterialData>) -> Result<()> {
+    let Some(root) = json.as_object_mut() else {
+        bail!("Expected the glTF to be a JSON object");
+    };
+    let extras = root.entry("extras").or_insert_with(|| serde_json::json!({}));
+    let Some(extras) = extras.as_object_mut() else {
+        bail!("Expected the glTF's extras to be a JSON object");
+    };
+
+    let mut ids: Vec<_> = materials.keys().collect();
+    ids.sort();
+    let list = ids
+        .into_iter()
+        .map(|id| {
+            serde_json::json!({
+                "id": id,
+                "name": materials[id].name,
+                "density": materials[id].density,
+            })
+        })
+        .collect();
+    extras.insert("kclMaterials".to_string(), serde_json::Value::Array(list));
+    Ok(())
+}
+
+/// A binary glTF file is a header and then chunks, the first of which is the JSON.
+fn add_materials_to_glb(contents: &[u8], materials: &HashMap<uuid::Uuid, MaterialData>) -> Result<Vec<u8>> {
+    let word = |at: usize| {
+        contents
+            .get(at..at + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+    if word(0) != Some(GLB_MAGIC) {
+        bail!("Expected a binary glTF file");
+    }
+    let (Some(json_length), Some(GLB_JSON_CHUNK)) = (word(12), word(16)) else {
+        bail!("Expected the binary glTF file to start with its JSON");
+    };
+    let json_end = 20 + json_length as usize;
+    let Some(json) = contents.get(20..json_end) else {
+        bail!("The binary glTF file is shorter than its JSON");
+    };
+
+    let mut json: serde_json::Value = serde_json::from_slice(json)?;
+    add_materials_to_json(&mut json, materials)?;
+    let mut json = serde_json::to_vec(&json)?;
+    // Chunks are a multiple of four bytes long, and the JSON is padded with spaces.
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+
+    let rest = &contents[json_end..];
+    let length = 20 + json.len() + rest.len();
+    let mut glb = Vec::with_capacity(length);
+    // The magic number and version stay the same.
+    glb.extend_from_slice(&contents[..8]);
+    glb.extend_from_slice(&(length as u32).to_le_bytes());
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(&GLB_JSON_CHUNK.to_le_bytes());
+    glb.extend_from_slice(&json);
+    glb.extend_from_slice(rest);
+    Ok(glb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steel() -> HashMap<uuid::Uuid, MaterialData> {
+        HashMap::from([(
+            uuid::Uuid::nil(),
+            MaterialData {
+                name: "Steel".to_string(),
+                density: 7850.0,
+            },
+        )])
+    }
+
+    fn glb(json: &[u8], bin: &[u8]) -> Vec<u8> {
+        let mut glb = vec![];
+        glb.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&((20 + json.len() + 8 + bin.len()) as u32).to_le_bytes());
+        glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        glb.extend_from_slice(&GLB_JSON_CHUNK.to_le_bytes());
+        glb.extend_from_slice(json);
+        glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"BIN\0");
+        glb.extend_from_slice(bin);
+        glb
+    }
+
+    #[test]
+    fn test_materials_in_gltf() {
+        let gltf = br#"{"asset": {"version": "2.0"}, "extras": {"author": "me"}}"#.to_vec();
+        let gltf = add_materials("output.gltf", gltf, &steel()).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&gltf).unwrap();
+        assert_eq!(
+            json["extras"],
+            serde_json::json!({
+                "author": "me",
+                "kclMaterials": [{ "id": uuid::Uuid::nil(), "name": "Steel", "density": 7850.0 }],
+            })
+        );
+
+        // Formats without anywhere to put them are left alone.
+        assert_eq!(
+            add_materials("output.stl", b"solid".to_vec(), &steel()).unwrap(),
+            b"solid"
+        );
+    }
+
+    #[test]
+    fn test_materials_in_glb() {
+        let bin = [1, 2, 3, 4];
+        let exported = add_materials("output.glb", glb(br#"{"asset":{"version":"2.0"}}"#, &bin), &steel()).unwrap();
+
+        let json_length = u32::from_le_bytes(exported[12..16].try_into().unwrap()) as usize;
+        assert_eq!(json_length % 4, 0);
+        let json: serde_json::Value = serde_json::from_slice(&exported[20..20 + json_length]).unwrap();
+        assert_eq!(json["extras"]["kclMaterials"][0]["name"], "Steel");
+        // The binary chunk is still there after the JSON, and the length counts it.
+        assert_eq!(
+            &exported[exported.len() - 12..],
+            [4, 0, 0, 0, b'B', b'I', b'N', 0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            u32::from_le_bytes(exported[8..12].try_into().unwrap()) as usize,
+            exported.len()
+        );
+    }
+}
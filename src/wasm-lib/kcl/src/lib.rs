@@ -13,19 +13,26 @@ macro_rules! println {
     }
 }
 
+pub mod artifact_graph;
+pub mod assembly;
 pub mod ast;
+pub mod bom;
 pub mod coredump;
 pub mod docs;
 pub mod engine;
 pub mod errors;
 pub mod executor;
+pub mod export;
 pub mod fs;
 mod function_param;
 pub mod lint;
 pub mod lsp;
 pub mod parser;
+#[cfg(feature = "pyo3")]
+pub mod python;
 pub mod settings;
 pub mod std;
+pub mod telemetry;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod test_server;
 pub mod thread;
@@ -48,8 +48,9 @@ where
     /// Get the current diagnostics map.
     fn current_diagnostics_map(&self) -> &DashMap<String, Vec<Diagnostic>>;
 
-    /// On change event.
-    async fn inner_on_change(&self, params: TextDocumentItem, force: bool);
+    /// On change event. `previous_code` is the code of the document before the change, if
+    /// there was any.
+    async fn inner_on_change(&self, params: TextDocumentItem, previous_code: Option<String>, force: bool);
 
     /// Check if the file has diagnostics.
     async fn has_diagnostics(&self, uri: &str) -> bool {
@@ -64,15 +65,17 @@ where
         // Check if the document is in the current code map and if it is the same as what we have
         // stored.
         let filename = params.uri.to_string();
+        let mut previous_code = None;
         if let Some(current_code) = self.code_map().get(&filename) {
             if *current_code == params.text.as_bytes() && !self.has_diagnostics(&filename).await {
                 return;
             }
+            previous_code = Some(String::from_utf8_lossy(&current_code).into_owned());
         }
 
         self.insert_code_map(params.uri.to_string(), params.text.as_bytes().to_vec())
             .await;
-        self.inner_on_change(params, false).await;
+        self.inner_on_change(params, previous_code, false).await;
     }
 
     async fn update_from_disk<P: AsRef<std::path::Path> + std::marker::Send>(&self, path: P) -> Result<()> {
@@ -209,10 +212,16 @@ where
         self.on_change(new_params).await;
     }
 
-    async fn do_did_change(&self, mut params: DidChangeTextDocumentParams) {
+    async fn do_did_change(&self, params: DidChangeTextDocumentParams) {
+        // Changes are relative to the code we have, unless they replace all of it.
+        let current_code = self
+            .code_map()
+            .get(params.text_document.uri.as_str())
+            .map(|code| String::from_utf8_lossy(&code).to_string())
+            .unwrap_or_default();
         let new_params = TextDocumentItem {
+            text: crate::lsp::util::apply_content_changes(&current_code, params.content_changes),
             uri: params.text_document.uri,
-            text: std::mem::take(&mut params.content_changes[0].text),
             version: params.text_document.version,
             language_id: Default::default(),
         };
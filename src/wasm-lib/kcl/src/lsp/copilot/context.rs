@@ -0,0 +1,164 @@
+//! Context for completion prompts.
+//!
+//! The model only sees the prompt, so we prepend what a person would look at while writing
+//! the code: the signatures of the stdlib functions it calls and the modules it imports.
+//! Everything is written as comments so the prompt is still valid KCL.
+
+use crate::ast::types::BodyItem;
+
+/// How much of an imported module to include, in characters.
+const MAX_IMPORT_LEN: usize = 2000;
+
+/// A module imported by the document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedModule {
+    /// The path as written in the import statement.
+    pub path: String,
+    /// The code of the module.
+    pub code: String,
+}
+
+/// Everything we prepend to the prompt.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompletionContext {
+    /// The signatures of the stdlib functions the document calls.
+    pub signatures: Vec<String>,
+    /// The modules the document imports.
+    pub imports: Vec<ImportedModule>,
+}
+
+impl CompletionContext {
+    /// Prepend the context to the text before the cursor.
+    pub fn prompt(&self, prefix: &str) -> String {
+        let mut prompt = String::new();
+        if !self.signatures.is_empty() {
+            prompt.push_str("// Standard library functions used in this file:\n");
+            for signature in &self.signatures {
+                prompt.push_str(&format!("// {}\n", signature));
+            }
+            prompt.push_str("//\n");
+        }
+        for import in &self.imports {
+            prompt.push_str(&format!("// Imported from \"{}\":\n", import.path));
+            let code = match import.code.char_indices().nth(MAX_IMPORT_LEN) {
+                Some((end, _)) => &import.code[..end],
+                None => &import.code,
+            };
+            for line in code.lines() {
+                if line.is_empty() {
+                    prompt.push_str("//\n");
+                } else {
+                    prompt.push_str(&format!("// {}\n", line));
+                }
+            }
+            prompt.push_str("//\n");
+        }
+        prompt.push_str(prefix);
+        prompt
+    }
+}
+
+/// The signatures of the stdlib functions called in the code, in the order they first appear.
+pub fn stdlib_signatures(code: &str) -> Vec<String> {
+    let mut names: Vec<&str> = Vec::new();
+    let mut start = None;
+    for (i, c) in code.char_indices() {
+        if c.is_alphanumeric() || c == '_' {
+            start.get_or_insert(i);
+            continue;
+        }
+        if let Some(s) = start.take() {
+            let name = &code[s..i];
+            if code[i..].trim_start().starts_with('(') && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .filter_map(crate::std::get_stdlib_fn)
+        .map(|f| f.fn_signature())
+        .collect()
+}
+
+/// The paths imported by the code.
+/// The document is usually mid-edit and won't parse, so each import is parsed on its own.
+pub fn import_paths(code: &str) -> Vec<String> {
+    code.lines()
+        .filter(|line| line.trim_start().starts_with("import "))
+        .filter_map(|line| crate::parser::parse(line.trim()).ok())
+        .filter_map(|program| match program.body.into_iter().next() {
+            Some(BodyItem::ImportStatement(import)) => Some(import.path),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Resolve an import path against the uri of the importing document.
+pub fn resolve_import(uri: &str, path: &str) -> String {
+    match uri.rsplit_once('/') {
+        Some((dir, _)) => format!("{}/{}", dir, path),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_stdlib_signatures() {
+        let code = r#"const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %)
+  |> line ([0, 10], %)
+  |> myHelper(%)
+"#;
+        let signatures = stdlib_signatures(code);
+        assert_eq!(signatures.len(), 3);
+        assert!(signatures[0].starts_with("startSketchOn("));
+        assert!(signatures[1].starts_with("startProfileAt("));
+        assert!(signatures[2].starts_with("line("));
+    }
+
+    #[test]
+    fn test_import_paths_mid_edit() {
+        let code = r#"import width, height from "dims.kcl"
+import bolt from "bolt.kcl"
+
+const part001 = startSketchOn('XY')
+  |> line([width, "#;
+        assert_eq!(import_paths(code), vec!["dims.kcl", "bolt.kcl"]);
+        assert_eq!(
+            resolve_import("file:///project/main.kcl", "bolt.kcl"),
+            "file:///project/bolt.kcl"
+        );
+    }
+
+    #[test]
+    fn test_prompt() {
+        let context = CompletionContext {
+            signatures: vec!["close(sketch_group: SketchGroup) -> SketchGroup".to_string()],
+            imports: vec![ImportedModule {
+                path: "dims.kcl".to_string(),
+                code: "export const width = 10\n\nexport const height = 5\n".to_string(),
+            }],
+        };
+        assert_eq!(
+            context.prompt("import width from \"dims.kcl\"\n"),
+            r#"// Standard library functions used in this file:
+// close(sketch_group: SketchGroup) -> SketchGroup
+//
+// Imported from "dims.kcl":
+// export const width = 10
+//
+// export const height = 5
+//
+import width from "dims.kcl"
+"#
+        );
+    }
+}
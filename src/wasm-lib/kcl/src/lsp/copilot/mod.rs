@@ -1,6 +1,8 @@
 //! The copilot lsp server for ghost text.
 
 pub mod cache;
+pub mod context;
+pub mod provider;
 pub mod types;
 
 use std::{
@@ -24,11 +26,18 @@ use tower_lsp::{
     LanguageServer,
 };
 
-use crate::lsp::{
-    backend::Backend as _,
-    copilot::types::{
-        CopilotAcceptCompletionParams, CopilotCompletionResponse, CopilotCompletionTelemetry, CopilotEditorInfo,
-        CopilotLspCompletionParams, CopilotRejectCompletionParams, DocParams,
+use crate::{
+    fs::FileSystem,
+    lsp::{
+        backend::Backend as _,
+        copilot::{
+            context::CompletionContext,
+            provider::{CompletionProvider, CompletionRequest},
+            types::{
+                CopilotAcceptCompletionParams, CopilotCompletionResponse, CopilotCompletionTelemetry,
+                CopilotEditorInfo, CopilotLspCompletionParams, CopilotRejectCompletionParams, DocParams,
+            },
+        },
     },
 };
 
@@ -52,8 +61,8 @@ pub struct Backend {
     pub workspace_folders: DashMap<String, WorkspaceFolder>,
     /// Current code.
     pub code_map: DashMap<String, Vec<u8>>,
-    /// The Zoo API client.
-    pub zoo_client: kittycad::Client,
+    /// Where completions come from.
+    pub provider: Arc<dyn CompletionProvider>,
     /// The editor info is used to store information about the editor.
     pub editor_info: Arc<RwLock<CopilotEditorInfo>>,
     /// The cache is used to store the results of previous requests.
@@ -125,46 +134,66 @@ impl crate::lsp::backend::Backend for Backend {
         &self.diagnostics_map
     }
 
-    async fn inner_on_change(&self, _params: TextDocumentItem, _force: bool) {
+    async fn inner_on_change(&self, _params: TextDocumentItem, _previous_code: Option<String>, _force: bool) {
         // We don't need to do anything here.
     }
 }
 
 impl Backend {
-    /// Get completions from the kittycad api.
+    /// Get completions from the provider.
     pub async fn get_completions(&self, language: String, prompt: String, suffix: String) -> Result<Vec<String>> {
-        let body = kittycad::types::KclCodeCompletionRequest {
-            extra: Some(kittycad::types::KclCodeCompletionParams {
-                language: Some(language.to_string()),
-                next_indent: None,
-                trim_by_indentation: true,
-                prompt_tokens: Some(prompt.len() as u32),
-                suffix_tokens: Some(suffix.len() as u32),
-            }),
-            prompt: Some(prompt),
-            suffix: Some(suffix),
-            max_tokens: Some(500),
-            temperature: Some(1.0),
-            top_p: Some(1.0),
-            // We only handle one completion at a time, for now so don't even waste the tokens.
-            n: Some(1),
-            stop: Some(["unset".to_string()].to_vec()),
-            nwo: None,
-            // We haven't implemented streaming yet.
-            stream: false,
+        let request = CompletionRequest {
+            language,
+            prompt,
+            suffix,
+        };
+
+        // In wasm the provider's futures aren't Send, so they run on the local executor
+        // and the result comes back over a channel.
+        #[cfg(target_arch = "wasm32")]
+        let result = {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            let provider = self.provider.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = tx.send(provider.complete(&request).await.map_err(|err| err.to_string()));
+            });
+            rx.await.map_err(|err| err.to_string()).and_then(|result| result)
         };
+        #[cfg(not(target_arch = "wasm32"))]
+        let result = self.provider.complete(&request).await.map_err(|err| err.to_string());
 
-        let resp = self
-            .zoo_client
-            .ml()
-            .create_kcl_code_completions(&body)
-            .await
-            .map_err(|err| Error {
-                code: tower_lsp::jsonrpc::ErrorCode::from(69),
-                data: None,
-                message: Cow::from(format!("Failed to get completions from zoo api: {}", err)),
-            })?;
-        Ok(resp.completions)
+        result.map_err(|err| Error {
+            code: tower_lsp::jsonrpc::ErrorCode::from(69),
+            data: None,
+            message: Cow::from(format!("Failed to get completions: {}", err)),
+        })
+    }
+
+    /// Gather the context for a completion in the given document: the signatures of the stdlib
+    /// functions it calls and the code of the modules it imports, from the editor if they are
+    /// open and from disk otherwise.
+    pub async fn get_completion_context(&self, uri: &str, source: &str) -> CompletionContext {
+        let mut imports = Vec::new();
+        for path in crate::lsp::copilot::context::import_paths(source) {
+            let import_uri = crate::lsp::copilot::context::resolve_import(uri, &path);
+            let code = match self.code_map.get(&import_uri) {
+                Some(code) => String::from_utf8_lossy(&code).to_string(),
+                None => {
+                    let file = import_uri.strip_prefix("file://").unwrap_or(&import_uri);
+                    match self.fs.read_to_string(file, Default::default()).await {
+                        Ok(code) => code,
+                        // Not everything will resolve mid-edit, that's fine.
+                        Err(_) => continue,
+                    }
+                }
+            };
+            imports.push(crate::lsp::copilot::context::ImportedModule { path, code });
+        }
+
+        CompletionContext {
+            signatures: crate::lsp::copilot::context::stdlib_signatures(source),
+            imports,
+        }
     }
 
     pub async fn set_editor_info(&self, params: CopilotEditorInfo) -> Result<Success> {
@@ -206,22 +235,16 @@ impl Backend {
             return Ok(cached_result);
         }
 
-        let doc_params = self.get_doc_params(&params)?;
         let line_before = doc_params.line_before.to_string();
 
-        // Let's not call it yet since it's not our model.
-        // We will need to wrap in spawn_local like we do in kcl/mod.rs for wasm only.
-        #[cfg(test)]
+        let context = self.get_completion_context(&doc_params.uri, &params.doc.source).await;
         let mut completion_list = self
-            .get_completions(doc_params.language, doc_params.prefix, doc_params.suffix)
-            .await
-            .map_err(|err| Error {
-                code: tower_lsp::jsonrpc::ErrorCode::from(69),
-                data: None,
-                message: Cow::from(format!("Failed to get completions: {}", err)),
-            })?;
-        #[cfg(not(test))]
-        let mut completion_list = vec![];
+            .get_completions(
+                doc_params.language.to_string(),
+                context.prompt(&doc_params.prefix),
+                doc_params.suffix.to_string(),
+            )
+            .await?;
 
         // if self.dev_mode
         if false {
@@ -0,0 +1,223 @@
+//! Where completions come from.
+
+use std::{fmt::Debug, sync::Mutex};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::lsp::copilot::types::Choices;
+
+/// The maximum number of tokens to ask a provider for.
+const MAX_TOKENS: u16 = 500;
+
+/// A request for completions at a cursor.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct CompletionRequest {
+    /// The language of the document.
+    pub language: String,
+    /// Everything before the cursor, including any assembled context.
+    pub prompt: String,
+    /// Everything after the cursor.
+    pub suffix: String,
+}
+
+/// A source of completions.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait CompletionProvider: Debug + Send + Sync {
+    /// Get the completions for the request.
+    async fn complete(&self, request: &CompletionRequest) -> Result<Vec<String>>;
+}
+
+/// Completions from the Zoo ML API.
+#[derive(Debug, Clone)]
+pub struct ZooCompletionProvider {
+    client: kittycad::Client,
+}
+
+impl ZooCompletionProvider {
+    pub fn new(client: kittycad::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl CompletionProvider for ZooCompletionProvider {
+    async fn complete(&self, request: &CompletionRequest) -> Result<Vec<String>> {
+        let body = kittycad::types::KclCodeCompletionRequest {
+            extra: Some(kittycad::types::KclCodeCompletionParams {
+                language: Some(request.language.to_string()),
+                next_indent: None,
+                trim_by_indentation: true,
+                prompt_tokens: Some(request.prompt.len() as u32),
+                suffix_tokens: Some(request.suffix.len() as u32),
+            }),
+            prompt: Some(request.prompt.to_string()),
+            suffix: Some(request.suffix.to_string()),
+            max_tokens: Some(MAX_TOKENS),
+            temperature: Some(1.0),
+            top_p: Some(1.0),
+            // We only handle one completion at a time, for now so don't even waste the tokens.
+            n: Some(1),
+            stop: Some(["unset".to_string()].to_vec()),
+            nwo: None,
+            // We haven't implemented streaming yet.
+            stream: false,
+        };
+
+        let resp = self
+            .client
+            .ml()
+            .create_kcl_code_completions(&body)
+            .await
+            .map_err(|err| anyhow::anyhow!("Failed to get completions from zoo api: {}", err))?;
+        Ok(resp.completions)
+    }
+}
+
+/// Completions from a server speaking the OpenAI completions API, such as a local llama.cpp
+/// server.
+#[derive(Debug, Clone)]
+pub struct OpenAiCompatibleProvider {
+    client: reqwest::Client,
+    /// The base url of the server, e.g. `http://localhost:8080`.
+    base_url: String,
+    /// The model to ask for.
+    model: String,
+    /// The bearer token, if the server needs one.
+    api_key: Option<String>,
+}
+
+impl OpenAiCompatibleProvider {
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: base_url.into(),
+            model: model.into(),
+            api_key: None,
+        }
+    }
+
+    /// Authenticate with a bearer token.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAiCompletionRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+    suffix: &'a str,
+    max_tokens: u16,
+    temperature: f64,
+    n: u32,
+    stream: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiCompletionResponse {
+    choices: Vec<Choices>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl CompletionProvider for OpenAiCompatibleProvider {
+    async fn complete(&self, request: &CompletionRequest) -> Result<Vec<String>> {
+        let body = OpenAiCompletionRequest {
+            model: &self.model,
+            prompt: &request.prompt,
+            suffix: &request.suffix,
+            max_tokens: MAX_TOKENS,
+            temperature: 0.2,
+            n: 1,
+            stream: false,
+        };
+
+        let mut req = self
+            .client
+            .post(format!("{}/v1/completions", self.base_url.trim_end_matches('/')))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&body)?);
+        if let Some(api_key) = &self.api_key {
+            req = req.bearer_auth(api_key);
+        }
+
+        let resp = req.send().await?;
+        let status = resp.status();
+        let bytes = resp.bytes().await?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "Failed to get completions from {}: {}: {}",
+                self.base_url,
+                status,
+                String::from_utf8_lossy(&bytes)
+            );
+        }
+
+        let resp: OpenAiCompletionResponse = serde_json::from_slice(&bytes)?;
+        let mut choices = resp.choices;
+        choices.sort_by_key(|choice| choice.index);
+        Ok(choices.into_iter().map(|choice| choice.text).collect())
+    }
+}
+
+/// Always returns the same completions, and remembers what it was asked. For tests.
+#[derive(Debug, Default)]
+pub struct StaticCompletionProvider {
+    completions: Vec<String>,
+    requests: Mutex<Vec<CompletionRequest>>,
+}
+
+impl StaticCompletionProvider {
+    pub fn new(completions: Vec<String>) -> Self {
+        Self {
+            completions,
+            requests: Default::default(),
+        }
+    }
+
+    /// The requests made so far, oldest first.
+    pub fn requests(&self) -> Vec<CompletionRequest> {
+        self.requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl CompletionProvider for StaticCompletionProvider {
+    async fn complete(&self, request: &CompletionRequest) -> Result<Vec<String>> {
+        self.requests
+            .lock()
+            .map_err(|err| anyhow::anyhow!("Failed lock: {}", err))?
+            .push(request.clone());
+        Ok(self.completions.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_static_provider() {
+        let provider = StaticCompletionProvider::new(vec!["line([0, 10], %)".to_string()]);
+        let request = CompletionRequest {
+            language: "kcl".to_string(),
+            prompt: "startSketchOn('XY')\n  |> ".to_string(),
+            suffix: String::new(),
+        };
+        assert_eq!(
+            provider.complete(&request).await.unwrap(),
+            vec!["line([0, 10], %)".to_string()]
+        );
+        assert_eq!(provider.requests(), vec![request]);
+    }
+}
@@ -0,0 +1,394 @@
+//! Working out what makes sense to complete at the cursor.
+//!
+//! Completions are asked for mid-edit, when the code usually doesn't parse, so this works off
+//! the tokens before the cursor rather than the AST.
+
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Documentation, MarkupContent, MarkupKind};
+
+use crate::{
+    ast::types::TagDeclarator,
+    docs::StdLibFn,
+    token::{Token, TokenType},
+};
+
+/// Argument types that can be filled in with `%` in a pipe.
+const PIPEABLE_TYPES: [&str; 6] = [
+    "Sketch",
+    "SketchSet",
+    "Solid",
+    "SolidSet",
+    "SketchSurface",
+    "SketchOrSurface",
+];
+
+/// Where the cursor is, as far as completions are concerned.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompletionContext {
+    /// Right after `|>`.
+    Pipe {
+        /// The type of the value being piped in, if we know it.
+        piped_type: Option<String>,
+    },
+    /// Where a property name goes, in an object literal passed to a function.
+    ObjectProperty {
+        /// The function being called.
+        function: String,
+        /// Which argument the object is.
+        arg_index: usize,
+        /// The properties already in the object.
+        existing: Vec<String>,
+    },
+    /// Right after `$`.
+    Tag,
+    /// Anywhere else.
+    Other,
+}
+
+impl CompletionContext {
+    /// Work out the context at the given character position.
+    pub fn at(code: &str, pos: usize) -> Self {
+        let pos = code.char_indices().nth(pos).map(|(i, _)| i).unwrap_or(code.len());
+        let Ok(tokens) = crate::token::lexer(&code[..pos]) else {
+            return CompletionContext::Other;
+        };
+        // Nothing to complete in a comment.
+        if tokens
+            .last()
+            .map(|t| matches!(t.token_type, TokenType::LineComment | TokenType::BlockComment))
+            .unwrap_or(false)
+        {
+            return CompletionContext::Other;
+        }
+        let mut tokens: Vec<Token> = tokens
+            .into_iter()
+            .filter(|t| {
+                !matches!(
+                    t.token_type,
+                    TokenType::Whitespace | TokenType::LineComment | TokenType::BlockComment
+                )
+            })
+            .collect();
+
+        // Ignore the word being typed.
+        if tokens
+            .last()
+            .map(|t| t.end == pos && matches!(t.token_type, TokenType::Word | TokenType::Keyword))
+            .unwrap_or(false)
+        {
+            tokens.pop();
+        }
+
+        let Some(last) = tokens.last() else {
+            return CompletionContext::Other;
+        };
+        match (last.token_type, last.value.as_str()) {
+            (TokenType::Dollar, _) => CompletionContext::Tag,
+            (TokenType::Operator, "|>") => CompletionContext::Pipe {
+                piped_type: piped_type(&tokens[..tokens.len() - 1]),
+            },
+            (TokenType::Brace, "{") | (TokenType::Comma, _) => object_property(&tokens),
+            _ => CompletionContext::Other,
+        }
+    }
+}
+
+/// The type a stdlib call at the end of the tokens returns.
+fn piped_type(tokens: &[Token]) -> Option<String> {
+    let last = tokens.last()?;
+    if !is_brace(last, ")") {
+        return None;
+    }
+    let open = unclosed_open(tokens, tokens.len() - 1)?;
+    let callee = tokens.get(open.checked_sub(1)?)?;
+    if callee.token_type != TokenType::Word {
+        return None;
+    }
+    crate::std::get_stdlib_fn(&callee.value)?
+        .return_value(false)
+        .map(|r| r.type_)
+}
+
+/// Whether the cursor is where a property name goes in an object literal passed to a function.
+fn object_property(tokens: &[Token]) -> CompletionContext {
+    let Some(object) = unclosed_open(tokens, tokens.len()) else {
+        return CompletionContext::Other;
+    };
+    if !is_brace(&tokens[object], "{") {
+        return CompletionContext::Other;
+    }
+
+    // The properties already in the object.
+    let mut existing = Vec::new();
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(object + 1) {
+        match (token.token_type, token.value.as_str()) {
+            (TokenType::Brace, "{" | "(" | "[") => depth += 1,
+            (TokenType::Brace, "}" | ")" | "]") => depth -= 1,
+            (TokenType::Word, name)
+                if depth == 0 && tokens.get(i + 1).map(|t| t.token_type) == Some(TokenType::Colon) =>
+            {
+                existing.push(name.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    // The object has to be an argument to a call.
+    let Some(call) = unclosed_open(tokens, object) else {
+        return CompletionContext::Other;
+    };
+    if !is_brace(&tokens[call], "(") {
+        return CompletionContext::Other;
+    }
+    let Some(function) = call
+        .checked_sub(1)
+        .and_then(|i| tokens.get(i))
+        .filter(|t| t.token_type == TokenType::Word)
+    else {
+        return CompletionContext::Other;
+    };
+
+    let mut arg_index = 0;
+    let mut depth = 0;
+    for token in &tokens[call + 1..object] {
+        match (token.token_type, token.value.as_str()) {
+            (TokenType::Brace, "{" | "(" | "[") => depth += 1,
+            (TokenType::Brace, "}" | ")" | "]") => depth -= 1,
+            (TokenType::Comma, _) if depth == 0 => arg_index += 1,
+            _ => {}
+        }
+    }
+
+    CompletionContext::ObjectProperty {
+        function: function.value.to_string(),
+        arg_index,
+        existing,
+    }
+}
+
+fn is_brace(token: &Token, value: &str) -> bool {
+    token.token_type == TokenType::Brace && token.value == value
+}
+
+/// The index of the innermost brace opened before `end` and not closed before it.
+fn unclosed_open(tokens: &[Token], end: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, token) in tokens[..end].iter().enumerate().rev() {
+        if token.token_type != TokenType::Brace {
+            continue;
+        }
+        match token.value.as_str() {
+            "}" | ")" | "]" => depth += 1,
+            _ if depth == 0 => return Some(i),
+            _ => depth -= 1,
+        }
+    }
+    None
+}
+
+/// The argument a piped value fills in: the first one that can be `%`.
+fn pipe_arg_type(function: &dyn StdLibFn) -> Option<String> {
+    function
+        .args(false)
+        .into_iter()
+        .map(|arg| arg.type_)
+        .find(|type_| PIPEABLE_TYPES.contains(&type_.as_str()))
+}
+
+/// How well a value of type `piped` fits an argument of type `arg`: `Some(0)` if exactly,
+/// `Some(1)` if it fits with a conversion (e.g. a `Solid` into a `SolidSet`), `None` if not.
+fn pipe_fit(arg: &str, piped: &str) -> Option<u8> {
+    if arg == piped {
+        return Some(0);
+    }
+    match (arg, piped) {
+        ("SketchSet", "Sketch") | ("Sketch", "SketchSet") => Some(1),
+        ("SolidSet", "Solid") | ("Solid", "SolidSet") => Some(1),
+        ("SketchOrSurface", "Sketch" | "SketchSet" | "SketchSurface") => Some(1),
+        _ => None,
+    }
+}
+
+/// Filter and rank the stdlib completions for a pipe.
+/// Functions that take the piped type exactly sort before those that need a conversion.
+pub fn pipe_completions<'a>(
+    completions: impl Iterator<Item = &'a CompletionItem>,
+    piped_type: Option<&str>,
+) -> Vec<CompletionItem> {
+    completions
+        .filter_map(|item| {
+            let function = crate::std::get_stdlib_fn(&item.label)?;
+            let arg = pipe_arg_type(function.as_ref())?;
+            let rank = match piped_type {
+                Some(piped) => pipe_fit(&arg, piped)?,
+                None => 0,
+            };
+            Some(CompletionItem {
+                sort_text: Some(format!("{}{}", rank, item.label)),
+                ..item.clone()
+            })
+        })
+        .collect()
+}
+
+/// The properties a function's object argument takes, minus the ones already there.
+pub fn object_property_completions(function: &str, arg_index: usize, existing: &[String]) -> Vec<CompletionItem> {
+    let Some(function) = crate::std::get_stdlib_fn(function) else {
+        return vec![];
+    };
+    let Some(arg) = function.args(true).into_iter().nth(arg_index) else {
+        return vec![];
+    };
+
+    arg.properties()
+        .into_iter()
+        .filter(|(name, _)| !existing.contains(name))
+        .map(|(name, description)| CompletionItem {
+            label: name.to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            detail: Some(format!("{}: {}", arg.name, arg.type_)),
+            documentation: description.map(|value| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value,
+                })
+            }),
+            insert_text: Some(format!("{}: ", name)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Every tag declared in the code, except the one at the cursor.
+pub fn tag_completions(code: &str, pos: usize) -> Vec<CompletionItem> {
+    let pos = code.char_indices().nth(pos).map(|(i, _)| i).unwrap_or(code.len());
+    let Ok(tokens) = crate::token::lexer(code) else {
+        return vec![];
+    };
+
+    let mut names: Vec<&str> = Vec::new();
+    for pair in tokens.windows(2) {
+        let (dollar, name) = (&pair[0], &pair[1]);
+        if dollar.token_type == TokenType::Dollar
+            && name.token_type == TokenType::Word
+            && name.start == dollar.end
+            && !(name.start..=name.end).contains(&pos)
+            && !names.contains(&name.value.as_str())
+        {
+            names.push(&name.value);
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| (&TagDeclarator::new(name)).into())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// The context at the `^` in the code.
+    fn context(code: &str) -> CompletionContext {
+        let pos = code.find('^').unwrap();
+        CompletionContext::at(&code.replace('^', ""), pos)
+    }
+
+    #[test]
+    fn test_context_pipe() {
+        assert_eq!(
+            context("const part001 = startSketchOn('XY')\n  |> ^"),
+            CompletionContext::Pipe {
+                piped_type: Some("SketchSurface".to_string())
+            }
+        );
+        assert_eq!(
+            context("const part001 = startSketchOn('XY')\n  |> startProfileAt([0, 0], %)\n  |> li^"),
+            CompletionContext::Pipe {
+                piped_type: Some("Sketch".to_string())
+            }
+        );
+        assert_eq!(
+            context("const part002 = part001\n  |> ^"),
+            CompletionContext::Pipe { piped_type: None }
+        );
+    }
+
+    #[test]
+    fn test_context_object_property() {
+        assert_eq!(
+            context("const part002 = shell({ thickness: 1, ^ }, part001)"),
+            CompletionContext::ObjectProperty {
+                function: "shell".to_string(),
+                arg_index: 0,
+                existing: vec!["thickness".to_string()],
+            }
+        );
+        assert_eq!(
+            context("const part002 = revolve({ ^"),
+            CompletionContext::ObjectProperty {
+                function: "revolve".to_string(),
+                arg_index: 0,
+                existing: vec![],
+            }
+        );
+        // In a value, not a key.
+        assert_eq!(context("const part002 = shell({ faces: [^"), CompletionContext::Other);
+    }
+
+    #[test]
+    fn test_context_tag() {
+        assert_eq!(context("  |> line([0, 10], %, $seg^"), CompletionContext::Tag);
+        assert_eq!(context("  |> line([0, 10], %, \"$^\")"), CompletionContext::Other);
+    }
+
+    #[test]
+    fn test_pipe_completions() {
+        let stdlib = crate::std::StdLib::new();
+        let all = crate::lsp::kcl::get_completions_from_stdlib(&stdlib).unwrap();
+
+        let solid = pipe_completions(all.values(), Some("SolidSet"));
+        let labels = solid.iter().map(|c| c.label.as_str()).collect::<Vec<_>>();
+        assert!(labels.contains(&"fillet"));
+        assert!(labels.contains(&"shell"));
+        assert!(!labels.contains(&"line"));
+        assert!(!labels.contains(&"cos"));
+        // Exact matches sort first.
+        let shell = solid.iter().find(|c| c.label == "shell").unwrap();
+        let fillet = solid.iter().find(|c| c.label == "fillet").unwrap();
+        assert!(shell.sort_text < fillet.sort_text);
+
+        let sketch = pipe_completions(all.values(), Some("Sketch"));
+        let labels = sketch.iter().map(|c| c.label.as_str()).collect::<Vec<_>>();
+        assert!(labels.contains(&"line"));
+        assert!(labels.contains(&"extrude"));
+        assert!(!labels.contains(&"fillet"));
+    }
+
+    #[test]
+    fn test_object_property_completions() {
+        let completions = object_property_completions("shell", 0, &["thickness".to_string()]);
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].label, "faces");
+        assert_eq!(completions[0].insert_text, Some("faces: ".to_string()));
+    }
+
+    #[test]
+    fn test_tag_completions() {
+        let code = r#"const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([0, 10], %, $seg01)
+  |> line([10, 0], %, $seg02)
+  |> line([0, -10], %, $se)
+"#;
+        let pos = code.find("$se)").unwrap() + 3;
+        let labels = tag_completions(code, pos)
+            .into_iter()
+            .map(|c| c.label)
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["seg01", "seg02"]);
+    }
+}
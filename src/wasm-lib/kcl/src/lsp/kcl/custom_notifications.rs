@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 use tower_lsp::lsp_types::notification::Notification;
 
-use crate::settings::types::UnitLength;
+use crate::settings::types::{TelemetrySettings, TextEditorSettings, UnitLength};
 
 /// A notification that the AST has changed.
 #[derive(Debug)]
@@ -56,3 +56,25 @@ pub struct UpdateCanExecuteParams {
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize, ts_rs::TS)]
 #[ts(export)]
 pub struct UpdateCanExecuteResponse {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize, ts_rs::TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateTelemetryParams {
+    pub telemetry: TelemetrySettings,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct UpdateTelemetryResponse {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize, ts_rs::TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct UpdateTextEditorSettingsParams {
+    pub text_editor: TextEditorSettings,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize, ts_rs::TS)]
+#[ts(export)]
+pub struct UpdateTextEditorSettingsResponse {}
@@ -0,0 +1,200 @@
+//! Inlay hints: parameter names next to arguments, and the values of calls that compute numbers.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+
+use crate::{
+    ast::types::{CallExpression, Expr, Program},
+    executor::SourceRange,
+};
+
+/// Hints with the parameter name for every positional argument in the program.
+pub fn parameter_hints(program: &Program, code: &str) -> Vec<InlayHint> {
+    let user_fns = user_fn_params(program);
+    let hints = Mutex::new(Vec::new());
+    let _ = crate::walk::walk(program, &|node: crate::walk::Node| {
+        let crate::walk::Node::CallExpression(call) = node else {
+            return Ok(true);
+        };
+        let names = match crate::std::get_stdlib_fn(&call.callee.name) {
+            Some(f) => f.args(false).into_iter().map(|arg| arg.name).collect(),
+            None => user_fns.get(&call.callee.name).cloned().unwrap_or_default(),
+        };
+
+        let mut hints = hints.lock().map_err(|_| anyhow::anyhow!("mutex"))?;
+        for (arg, name) in call.arguments.iter().zip(names) {
+            match arg {
+                // `%` is always the piped value, naming it is noise.
+                Expr::PipeSubstitution(_) => continue,
+                // Neither is naming an argument after itself.
+                Expr::Identifier(identifier) if identifier.name == name => continue,
+                _ => {}
+            }
+            hints.push(InlayHint {
+                position: SourceRange::from(arg).start_to_lsp_position(code),
+                label: InlayHintLabel::String(format!("{}:", name)),
+                kind: Some(InlayHintKind::PARAMETER),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: Some(true),
+                data: None,
+            });
+        }
+        Ok(true)
+    });
+    hints.into_inner().unwrap_or_default()
+}
+
+/// The parameter types a call can take and still be evaluated on its own. Anything else, e.g. a
+/// solid, is measured from what the real execution kept or by asking the engine.
+const VALUE_HINT_ARG_TYPES: [&str; 4] = ["number", "[number]", "TagIdentifier", "Sketch"];
+
+/// The calls worth showing a value for: stdlib calls that return a number and can be run again
+/// without the engine, because they only take plain values and their arguments are literals or
+/// names rather than expressions that would have to be run too.
+pub fn value_hint_calls(program: &Program) -> Vec<CallExpression> {
+    let calls = Mutex::new(Vec::new());
+    let _ = crate::walk::walk(program, &|node: crate::walk::Node| {
+        if let crate::walk::Node::CallExpression(call) = node {
+            let pure = crate::std::get_stdlib_fn(&call.callee.name)
+                .map(|f| {
+                    f.return_value(false).map(|r| r.type_ == "number").unwrap_or(false)
+                        && f.args(false)
+                            .iter()
+                            .all(|arg| VALUE_HINT_ARG_TYPES.contains(&arg.type_.as_str()))
+                })
+                .unwrap_or(false);
+            if pure && call.arguments.iter().all(is_plain_value) {
+                calls.lock().map_err(|_| anyhow::anyhow!("mutex"))?.push(call.clone());
+            }
+        }
+        Ok(true)
+    });
+    calls.into_inner().unwrap_or_default()
+}
+
+/// Whether the argument is a literal, a name, or an array of those.
+fn is_plain_value(arg: &Expr) -> bool {
+    match arg {
+        Expr::Literal(_) | Expr::Identifier(_) => true,
+        Expr::ArrayExpression(array) => array.elements.iter().all(is_plain_value),
+        _ => false,
+    }
+}
+
+/// A hint with the value of a call, after the call.
+pub fn value_hint(call: &CallExpression, value: f64, code: &str) -> InlayHint {
+    InlayHint {
+        position: SourceRange::from(call).end_to_lsp_position(code),
+        label: InlayHintLabel::String(format!("= {}", format_number(value))),
+        kind: None,
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    }
+}
+
+/// Whether the hint falls in the range the client asked about.
+pub fn in_range(hint: &InlayHint, range: &Range) -> bool {
+    let position = (hint.position.line, hint.position.character);
+    (range.start.line, range.start.character) <= position && position <= (range.end.line, range.end.character)
+}
+
+/// The parameter names of every function the program declares.
+fn user_fn_params(program: &Program) -> HashMap<String, Vec<String>> {
+    let params = Mutex::new(HashMap::new());
+    let _ = crate::walk::walk(program, &|node: crate::walk::Node| {
+        if let crate::walk::Node::VariableDeclarator(declarator) = node {
+            if let Expr::FunctionExpression(function) = &declarator.init {
+                params.lock().map_err(|_| anyhow::anyhow!("mutex"))?.insert(
+                    declarator.id.name.to_string(),
+                    function.params.iter().map(|p| p.identifier.name.to_string()).collect(),
+                );
+            }
+        }
+        Ok(true)
+    });
+    params.into_inner().unwrap_or_default()
+}
+
+/// Numbers are shown to at most 3 decimal places, without trailing zeros.
+fn format_number(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    if formatted == "-0" {
+        "0".to_string()
+    } else {
+        formatted.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn labels(hints: &[InlayHint]) -> Vec<String> {
+        hints
+            .iter()
+            .map(|hint| match &hint.label {
+                InlayHintLabel::String(label) => label.to_string(),
+                InlayHintLabel::LabelParts(_) => panic!("expected a string label"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parameter_hints() {
+        let code = r#"fn twice = (x) => {
+  return x * 2
+}
+const x = 1
+const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([twice(x), legLen(5, 3)], %)
+"#;
+        let program = crate::parser::parse(code).unwrap();
+        let hints = parameter_hints(&program, code);
+        assert_eq!(labels(&hints), vec!["data:", "to:", "delta:", "hypotenuse:", "leg:"]);
+        // On the first argument to `startSketchOn`.
+        assert_eq!(hints[0].position.line, 4);
+        assert_eq!(hints[0].position.character, 30);
+    }
+
+    #[test]
+    fn test_value_hint_calls() {
+        let code = r#"const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([0, legLen(5, 3)], %, $seg01)
+  |> line([legLen(segLen(seg01), 3), 0], %)
+  |> close(%)
+const length = segLen(seg01) * 2
+const ext = extrude(5, part001)
+const area = faceArea(ext, seg01)
+const longest = max([length, 4])
+"#;
+        let program = crate::parser::parse(code).unwrap();
+        let calls = value_hint_calls(&program);
+        // Neither the call with a call as an argument, nor the one that takes a solid.
+        assert_eq!(
+            calls.iter().map(|c| c.callee.name.as_str()).collect::<Vec<_>>(),
+            vec!["legLen", "segLen", "segLen", "max"]
+        );
+        let hint = value_hint(&calls[0], 4.0, code);
+        assert_eq!(labels(&[hint.clone()]), vec!["= 4"]);
+        assert_eq!((hint.position.line, hint.position.character), (2, 26));
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(4.0), "4");
+        assert_eq!(format_number(1.23456), "1.235");
+        assert_eq!(format_number(-0.0001), "0");
+        assert_eq!(format_number(10.5), "10.5");
+    }
+}
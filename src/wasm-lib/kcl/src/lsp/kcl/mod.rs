@@ -3,23 +3,26 @@
 use std::{
     collections::HashMap,
     io::Write,
-    str::FromStr,
     sync::{Arc, Mutex},
 };
 
 use tokio::sync::RwLock;
 
+pub mod completion;
 pub mod custom_notifications;
+pub mod hints;
+pub mod workspace;
 
 use anyhow::Result;
 #[cfg(feature = "cli")]
 use clap::Parser;
 use dashmap::DashMap;
-use sha2::Digest;
 use tower_lsp::{
     jsonrpc::Result as RpcResult,
     lsp_types::{
-        CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse, CreateFilesParams,
+        CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem, CallHierarchyOutgoingCall,
+        CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, CallHierarchyServerCapability, CompletionItem,
+        CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse, CreateFilesParams,
         DeleteFilesParams, Diagnostic, DiagnosticOptions, DiagnosticServerCapabilities, DiagnosticSeverity,
         DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
         DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
@@ -29,12 +32,14 @@ use tower_lsp::{
         Hover, HoverContents, HoverParams, HoverProviderCapability, InitializeParams, InitializeResult,
         InitializedParams, InlayHint, InlayHintParams, InsertTextFormat, MarkupContent, MarkupKind, MessageType, OneOf,
         Position, RelatedFullDocumentDiagnosticReport, RenameFilesParams, RenameParams, SemanticToken,
-        SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensFullOptions, SemanticTokensLegend,
-        SemanticTokensOptions, SemanticTokensParams, SemanticTokensRegistrationOptions, SemanticTokensResult,
+        SemanticTokenModifier, SemanticTokenType, SemanticTokens, SemanticTokensDelta, SemanticTokensDeltaParams,
+        SemanticTokensFullDeltaResult, SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
+        SemanticTokensParams, SemanticTokensRegistrationOptions, SemanticTokensResult,
         SemanticTokensServerCapabilities, ServerCapabilities, SignatureHelp, SignatureHelpOptions, SignatureHelpParams,
-        StaticRegistrationOptions, TextDocumentItem, TextDocumentRegistrationOptions, TextDocumentSyncCapability,
-        TextDocumentSyncKind, TextDocumentSyncOptions, TextEdit, WorkDoneProgressOptions, WorkspaceEdit,
-        WorkspaceFolder, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+        StaticRegistrationOptions, SymbolInformation, TextDocumentItem, TextDocumentRegistrationOptions,
+        TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, TextEdit, WorkDoneProgressOptions,
+        WorkspaceEdit, WorkspaceFolder, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+        WorkspaceSymbolParams,
     },
     Client, LanguageServer,
 };
@@ -44,6 +49,7 @@ use crate::{
     executor::{IdGenerator, SourceRange},
     lsp::{backend::Backend as _, util::IntoDiagnostic},
     parser::PIPE_OPERATOR,
+    telemetry::TelemetrySink,
     token::TokenType,
 };
 
@@ -110,10 +116,16 @@ pub struct Backend {
     pub symbols_map: DashMap<String, Vec<DocumentSymbol>>,
     /// Semantic tokens map.
     pub semantic_tokens_map: DashMap<String, Vec<SemanticToken>>,
+    /// The semantic tokens last sent to the client, with their result id, to send deltas against.
+    pub sent_semantic_tokens: DashMap<String, (String, Vec<SemanticToken>)>,
     /// The Zoo API client.
     pub zoo_client: kittycad::Client,
     /// If we can send telemetry for this user.
     pub can_send_telemetry: bool,
+    /// What telemetry to collect and where to send it.
+    pub telemetry: Arc<RwLock<crate::settings::types::TelemetrySettings>>,
+    /// The text editor settings, for which inlay hints to show.
+    pub text_editor: Arc<RwLock<crate::settings::types::TextEditorSettings>>,
     /// Optional executor context to use if we want to execute the code.
     pub executor_ctx: Arc<RwLock<Option<crate::executor::ExecutorContext>>>,
     /// If we are currently allowed to execute the ast.
@@ -177,18 +189,31 @@ impl crate::lsp::backend::Backend for Backend {
         self.diagnostics_map.clear();
         self.symbols_map.clear();
         self.semantic_tokens_map.clear();
+        self.sent_semantic_tokens.clear();
     }
 
     fn current_diagnostics_map(&self) -> &DashMap<String, Vec<Diagnostic>> {
         &self.diagnostics_map
     }
 
-    async fn inner_on_change(&self, params: TextDocumentItem, force: bool) {
+    async fn inner_on_change(&self, params: TextDocumentItem, previous_code: Option<String>, force: bool) {
         let filename = params.uri.to_string();
         // We already updated the code map in the shared backend.
 
-        // Lets update the tokens.
-        let tokens = match crate::token::lexer(&params.text) {
+        // Lets update the tokens, only relexing what changed since last time.
+        let previous_tokens = self.token_map.get(&filename);
+        let tokens = match (previous_tokens, previous_code) {
+            // The code map can be updated from disk without relexing, so only trust the tokens
+            // if they end where the previous code does.
+            (Some(previous_tokens), Some(previous_code))
+                if previous_tokens.last().map(|token| token.end).unwrap_or_default() == previous_code.len() =>
+            {
+                let edit = crate::token::Edit::between(&previous_code, &params.text);
+                crate::token::relex(&previous_tokens, &params.text, &edit)
+            }
+            _ => crate::token::lexer(&params.text),
+        };
+        let tokens = match tokens {
             Ok(tokens) => tokens,
             Err(err) => {
                 self.add_to_diagnostics(&params, &[err], true).await;
@@ -668,65 +693,40 @@ impl Backend {
     }
 
     pub async fn send_telemetry(&self) -> Result<()> {
-        // Get information about the user.
-        let user = self
-            .zoo_client
-            .users()
-            .get_self()
-            .await
-            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
-
-        // Hash the user's id.
-        // Create a SHA-256 object
-        let mut hasher = sha2::Sha256::new();
-        // Write input message
-        hasher.update(user.id);
-        // Read hash digest and consume hasher
-        let result = hasher.finalize();
-        // Get the hash as a string.
-        let user_id_hash = format!("{:x}", result);
-
-        // Get the workspace folders.
+        let settings = self.telemetry.read().await.clone();
+
+        // Collect the code we know about, keyed by path.
+        let files = self
+            .code_map
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key().replace("file://", ""),
+                    String::from_utf8_lossy(entry.value()).to_string(),
+                )
+            })
+            .collect();
         // The key of the workspace folder is the project name.
-        let workspace_folders = self.workspace_folders().await;
-        let project_names: Vec<&str> = workspace_folders.iter().map(|v| v.name.as_str()).collect::<Vec<_>>();
-        // Get the first name.
-        let project_name = project_names
-            .first()
-            .ok_or_else(|| anyhow::anyhow!("no project names"))?
-            .to_string();
-
-        // Send the telemetry data.
-        self.zoo_client
-            .meta()
-            .create_event(
-                vec![kittycad::types::multipart::Attachment {
-                    // Clean the URI part.
-                    name: "attachment".to_string(),
-                    filename: Some("attachment.zip".to_string()),
-                    content_type: Some("application/x-zip".to_string()),
-                    data: self.create_zip().await?,
-                }],
-                &kittycad::types::Event {
-                    // This gets generated server side so leave empty for now.
-                    attachment_uri: None,
-                    created_at: chrono::Utc::now(),
-                    event_type: kittycad::types::ModelingAppEventType::SuccessfulCompileBeforeClose,
-                    last_compiled_at: Some(chrono::Utc::now()),
-                    // We do not have project descriptions yet.
-                    project_description: None,
-                    project_name,
-                    // The UUID for the modeling app.
-                    // We can unwrap here because we know it will not panic.
-                    source_id: uuid::Uuid::from_str("70178592-dfca-47b3-bd2d-6fce2bcaee04").unwrap(),
-                    type_: kittycad::types::Type::ModelingAppEvent,
-                    user_id: user_id_hash,
-                },
-            )
-            .await
-            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        let project_name = self.workspace_folders().await.first().map(|folder| folder.name.clone());
 
-        Ok(())
+        let Some(event) = crate::telemetry::TelemetryEvent::new(settings.level, project_name, &files) else {
+            return Ok(());
+        };
+
+        if let Some(path) = &settings.local_path {
+            #[cfg(not(target_arch = "wasm32"))]
+            return crate::telemetry::LocalFileSink::new(path).send(&event).await;
+            #[cfg(target_arch = "wasm32")]
+            anyhow::bail!("cannot write telemetry to `{}` from the browser", path.display());
+        }
+
+        // Only send telemetry to Zoo if the user's privacy settings allow it.
+        if !self.can_send_telemetry {
+            return Ok(());
+        }
+        crate::telemetry::ZooSink::new(self.zoo_client.clone())
+            .send(&event)
+            .await
     }
 
     pub async fn update_units(
@@ -778,7 +778,8 @@ impl Backend {
         };
 
         // Force re-execution.
-        self.inner_on_change(new_params, true).await;
+        let previous_code = Some(new_params.text.clone());
+        self.inner_on_change(new_params, previous_code, true).await;
 
         // Check if we have diagnostics.
         // If we do we return early, since we failed in some way.
@@ -803,6 +804,56 @@ impl Backend {
 
         Ok(custom_notifications::UpdateCanExecuteResponse {})
     }
+
+    pub async fn update_telemetry(
+        &self,
+        params: custom_notifications::UpdateTelemetryParams,
+    ) -> RpcResult<custom_notifications::UpdateTelemetryResponse> {
+        *self.telemetry.write().await = params.telemetry;
+
+        Ok(custom_notifications::UpdateTelemetryResponse {})
+    }
+
+    pub async fn update_text_editor_settings(
+        &self,
+        params: custom_notifications::UpdateTextEditorSettingsParams,
+    ) -> RpcResult<custom_notifications::UpdateTextEditorSettingsResponse> {
+        *self.text_editor.write().await = params.text_editor;
+
+        Ok(custom_notifications::UpdateTextEditorSettingsResponse {})
+    }
+
+    /// Every kcl file we know about, parsed.
+    /// Files loaded from disk but never opened have no ast yet, so they get parsed here.
+    fn workspace_files(&self) -> Vec<workspace::WorkspaceFile> {
+        let mut files = Vec::new();
+        for entry in self.code_map.iter() {
+            let Ok(uri) = url::Url::parse(entry.key()) else {
+                continue;
+            };
+            if !uri.path().ends_with(".kcl") {
+                continue;
+            }
+            let Ok(code) = std::str::from_utf8(entry.value()) else {
+                continue;
+            };
+            let program = match self.ast_map.get(entry.key()) {
+                Some(ast) => ast.clone(),
+                None => match crate::parser::parse(code) {
+                    Ok(ast) => ast,
+                    Err(_) => continue,
+                },
+            };
+            files.push(workspace::WorkspaceFile {
+                uri,
+                program,
+                code: code.to_string(),
+            });
+        }
+        // The maps have no order, keep the results stable.
+        files.sort_by(|a, b| a.uri.as_str().cmp(b.uri.as_str()));
+        files
+    }
 }
 
 #[tower_lsp::async_trait]
@@ -827,6 +878,7 @@ impl LanguageServer for Backend {
                 document_formatting_provider: Some(OneOf::Left(true)),
                 folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
                 inlay_hint_provider: Some(OneOf::Left(true)),
                 rename_provider: Some(OneOf::Left(true)),
                 semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensRegistrationOptions(
@@ -847,7 +899,7 @@ impl LanguageServer for Backend {
                                 token_modifiers: SEMANTIC_TOKEN_MODIFIERS.clone(),
                             },
                             range: Some(false),
-                            full: Some(SemanticTokensFullOptions::Bool(true)),
+                            full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
                         },
                         static_registration_options: StaticRegistrationOptions::default(),
                     },
@@ -859,9 +911,10 @@ impl LanguageServer for Backend {
                 }),
                 text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
                     open_close: Some(true),
-                    change: Some(TextDocumentSyncKind::FULL),
+                    change: Some(TextDocumentSyncKind::INCREMENTAL),
                     ..Default::default()
                 })),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
                         supported: Some(true),
@@ -922,9 +975,8 @@ impl LanguageServer for Backend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         self.do_did_close(params).await;
 
-        // Inject telemetry if we can train on the user's code.
-        // Return early if we cannot.
-        if !self.can_send_telemetry {
+        // Send telemetry if the user opted in.
+        if self.telemetry.read().await.level == crate::settings::types::TelemetryLevel::Off {
             return;
         }
 
@@ -1041,6 +1093,41 @@ impl LanguageServer for Backend {
             tags: None,
         }];
 
+        let current_code = self
+            .code_map
+            .get(params.text_document_position.text_document.uri.as_ref())
+            .and_then(|code| String::from_utf8(code.to_vec()).ok());
+
+        // Narrow things down if we know where we are.
+        if let Some(current_code) = &current_code {
+            let position = position_to_char_index(params.text_document_position.position, current_code);
+            match completion::CompletionContext::at(current_code, position) {
+                completion::CompletionContext::Pipe { piped_type } => {
+                    return Ok(Some(CompletionResponse::Array(completion::pipe_completions(
+                        self.stdlib_completions.values(),
+                        piped_type.as_deref(),
+                    ))));
+                }
+                completion::CompletionContext::ObjectProperty {
+                    function,
+                    arg_index,
+                    existing,
+                } => {
+                    let properties = completion::object_property_completions(&function, arg_index, &existing);
+                    if !properties.is_empty() {
+                        return Ok(Some(CompletionResponse::Array(properties)));
+                    }
+                }
+                completion::CompletionContext::Tag => {
+                    return Ok(Some(CompletionResponse::Array(completion::tag_completions(
+                        current_code,
+                        position,
+                    ))));
+                }
+                completion::CompletionContext::Other => {}
+            }
+        }
+
         completions.extend(self.stdlib_completions.values().cloned());
 
         // Add more to the completions if we have more.
@@ -1051,17 +1138,11 @@ impl LanguageServer for Backend {
             return Ok(Some(CompletionResponse::Array(completions)));
         };
 
-        let Some(current_code) = self
-            .code_map
-            .get(params.text_document_position.text_document.uri.as_ref())
-        else {
-            return Ok(Some(CompletionResponse::Array(completions)));
-        };
-        let Ok(current_code) = std::str::from_utf8(&current_code) else {
+        let Some(current_code) = current_code else {
             return Ok(Some(CompletionResponse::Array(completions)));
         };
 
-        let position = position_to_char_index(params.text_document_position.position, current_code);
+        let position = position_to_char_index(params.text_document_position.position, &current_code);
         if ast.get_non_code_meta_for_position(position).is_some() {
             // If we are in a code comment we don't want to show completions.
             return Ok(None);
@@ -1161,10 +1242,51 @@ impl LanguageServer for Backend {
         }
     }
 
-    async fn inlay_hint(&self, _params: InlayHintParams) -> RpcResult<Option<Vec<InlayHint>>> {
-        // TODO: do this
+    async fn inlay_hint(&self, params: InlayHintParams) -> RpcResult<Option<Vec<InlayHint>>> {
+        let filename = params.text_document.uri.to_string();
+
+        let Some(ast) = self.ast_map.get(&filename).map(|ast| ast.clone()) else {
+            return Ok(None);
+        };
+        let Some(current_code) = self.code_map.get(&filename) else {
+            return Ok(None);
+        };
+        let Ok(current_code) = String::from_utf8(current_code.to_vec()) else {
+            return Ok(None);
+        };
+
+        let settings = self.text_editor.read().await.clone();
+        let mut inlay_hints = vec![];
 
-        Ok(None)
+        if settings.parameter_hints.0 {
+            inlay_hints.extend(hints::parameter_hints(&ast, &current_code));
+        }
+
+        // Values are only known once the code has executed. Only calls that never reach the
+        // engine are run again here, so no commands go out from this fresh state.
+        if settings.value_hints.0 {
+            let memory = self.memory_map.get(&filename).map(|memory| memory.clone());
+            let executor_ctx = self.executor_ctx().await;
+            if let (Some(memory), Some(executor_ctx)) = (memory, executor_ctx.as_ref()) {
+                for call in hints::value_hint_calls(&ast) {
+                    let mut exec_state = crate::executor::ExecState {
+                        memory: memory.clone(),
+                        ..Default::default()
+                    };
+                    // Calls that depend on a function's parameters or the piped value can't be
+                    // evaluated from the top-level memory, so they just don't get a hint.
+                    let Ok(value) = call.execute(&mut exec_state, executor_ctx).await else {
+                        continue;
+                    };
+                    if let Some(value) = value.get_json_value().ok().and_then(|v| v.as_f64()) {
+                        inlay_hints.push(hints::value_hint(&call, value, &current_code));
+                    }
+                }
+            }
+        }
+
+        inlay_hints.retain(|hint| hints::in_range(hint, &params.range));
+        Ok(Some(inlay_hints))
     }
 
     async fn semantic_tokens_full(&self, params: SemanticTokensParams) -> RpcResult<Option<SemanticTokensResult>> {
@@ -1174,12 +1296,49 @@ impl LanguageServer for Backend {
             return Ok(None);
         };
 
+        let result_id = uuid::Uuid::new_v4().to_string();
+        self.sent_semantic_tokens
+            .insert(filename, (result_id.clone(), semantic_tokens.clone()));
+
         Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
-            result_id: None,
+            result_id: Some(result_id),
             data: semantic_tokens.clone(),
         })))
     }
 
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> RpcResult<Option<SemanticTokensFullDeltaResult>> {
+        let filename = params.text_document.uri.to_string();
+
+        let Some(semantic_tokens) = self.semantic_tokens_map.get(&filename) else {
+            return Ok(None);
+        };
+
+        // We can only send a delta against the last tokens we sent.
+        let previous = self
+            .sent_semantic_tokens
+            .get(&filename)
+            .filter(|sent| sent.0 == params.previous_result_id)
+            .map(|sent| sent.1.clone());
+
+        let result_id = uuid::Uuid::new_v4().to_string();
+        self.sent_semantic_tokens
+            .insert(filename, (result_id.clone(), semantic_tokens.clone()));
+
+        Ok(Some(match previous {
+            Some(previous) => SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+                result_id: Some(result_id),
+                edits: crate::lsp::util::semantic_tokens_edits(&previous, &semantic_tokens),
+            }),
+            None => SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+                result_id: Some(result_id),
+                data: semantic_tokens.clone(),
+            }),
+        }))
+    }
+
     async fn document_symbol(&self, params: DocumentSymbolParams) -> RpcResult<Option<DocumentSymbolResponse>> {
         let filename = params.text_document.uri.to_string();
 
@@ -1190,6 +1349,48 @@ impl LanguageServer for Backend {
         Ok(Some(DocumentSymbolResponse::Nested(symbols.clone())))
     }
 
+    async fn symbol(&self, params: WorkspaceSymbolParams) -> RpcResult<Option<Vec<SymbolInformation>>> {
+        let symbols = workspace::symbols(&self.workspace_files(), &params.query);
+
+        Ok(Some(symbols))
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> RpcResult<Option<Vec<CallHierarchyItem>>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let files = self.workspace_files();
+        let Some(file) = files.iter().find(|f| f.uri == uri) else {
+            return Ok(None);
+        };
+
+        let pos = position_to_char_index(params.text_document_position_params.position, &file.code);
+        let Some(item) = workspace::prepare_call_hierarchy(&files, &uri, pos) else {
+            return Ok(None);
+        };
+
+        Ok(Some(vec![item]))
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> RpcResult<Option<Vec<CallHierarchyIncomingCall>>> {
+        let calls = workspace::incoming_calls(&self.workspace_files(), &params.item);
+
+        Ok(Some(calls))
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> RpcResult<Option<Vec<CallHierarchyOutgoingCall>>> {
+        let calls = workspace::outgoing_calls(&self.workspace_files(), &params.item);
+
+        Ok(Some(calls))
+    }
+
     async fn formatting(&self, params: DocumentFormattingParams) -> RpcResult<Option<Vec<TextEdit>>> {
         let filename = params.text_document.uri.to_string();
 
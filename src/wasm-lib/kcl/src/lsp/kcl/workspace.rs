@@ -0,0 +1,353 @@
+//! Navigating across every file in the workspace: symbol search and call hierarchy.
+//!
+//! Calls between files are resolved through import statements, so `import bracket from
+//! "parts.kcl"` makes `bracket()` a call to the `bracket` declared in `parts.kcl`.
+
+use std::sync::Mutex;
+
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Location, Range, SymbolInformation,
+    SymbolKind, Url,
+};
+
+use crate::{
+    ast::types::{BodyItem, CallExpression, Expr, Program, VariableDeclarator, VariableKind},
+    executor::SourceRange,
+};
+
+/// A file in the workspace.
+#[derive(Debug, Clone)]
+pub struct WorkspaceFile {
+    pub uri: Url,
+    pub program: Program,
+    pub code: String,
+}
+
+/// A top-level declaration.
+struct Declaration<'a> {
+    file: &'a WorkspaceFile,
+    declarator: &'a VariableDeclarator,
+    kind: SymbolKind,
+}
+
+impl Declaration<'_> {
+    fn name(&self) -> &str {
+        &self.declarator.id.name
+    }
+
+    fn range(&self) -> Range {
+        SourceRange::from(self.declarator).to_lsp_range(&self.file.code)
+    }
+
+    fn selection_range(&self) -> Range {
+        SourceRange::from(&self.declarator.id).to_lsp_range(&self.file.code)
+    }
+
+    fn to_call_hierarchy_item(&self) -> CallHierarchyItem {
+        CallHierarchyItem {
+            name: self.name().to_string(),
+            kind: self.kind,
+            tags: None,
+            detail: None,
+            uri: self.file.uri.clone(),
+            range: self.range(),
+            selection_range: self.selection_range(),
+            data: None,
+        }
+    }
+}
+
+/// Every top-level declaration in the file.
+fn declarations(file: &WorkspaceFile) -> Vec<Declaration<'_>> {
+    let mut declarations = vec![];
+    for item in &file.program.body {
+        let BodyItem::VariableDeclaration(declaration) = item else {
+            continue;
+        };
+        for declarator in &declaration.declarations {
+            let kind = match (&declarator.init, &declaration.kind) {
+                (Expr::FunctionExpression(_), _) | (_, VariableKind::Fn) => SymbolKind::FUNCTION,
+                (_, VariableKind::Const) => SymbolKind::CONSTANT,
+            };
+            declarations.push(Declaration { file, declarator, kind });
+        }
+    }
+    declarations
+}
+
+/// Search the functions and constants declared at the top level of every file.
+/// Matches are fuzzy and case-insensitive, so `brk` finds `bracket`.
+pub fn symbols(files: &[WorkspaceFile], query: &str) -> Vec<SymbolInformation> {
+    files
+        .iter()
+        .flat_map(declarations)
+        .filter(|declaration| fuzzy_match(declaration.name(), query))
+        .map(|declaration| {
+            #[allow(deprecated)]
+            SymbolInformation {
+                name: declaration.name().to_string(),
+                kind: declaration.kind,
+                tags: None,
+                deprecated: None,
+                location: Location {
+                    uri: declaration.file.uri.clone(),
+                    range: declaration.range(),
+                },
+                container_name: None,
+            }
+        })
+        .collect()
+}
+
+fn fuzzy_match(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query.chars().flat_map(char::to_lowercase).all(|q| name.any(|c| c == q))
+}
+
+/// The function at the position, either where it is declared or where it is called.
+pub fn prepare_call_hierarchy(files: &[WorkspaceFile], uri: &Url, pos: usize) -> Option<CallHierarchyItem> {
+    let file = files.iter().find(|f| &f.uri == uri)?;
+
+    // On a declaration.
+    if let Some(declaration) = declarations(file)
+        .into_iter()
+        .find(|d| d.kind == SymbolKind::FUNCTION && SourceRange::from(&d.declarator.id).contains(pos))
+    {
+        return Some(declaration.to_call_hierarchy_item());
+    }
+
+    // On a call.
+    let call = calls(&file.program)
+        .into_iter()
+        .find(|call| SourceRange::from(&call.callee).contains(pos))?;
+    resolve(files, file, &call.callee.name).map(|d| d.to_call_hierarchy_item())
+}
+
+/// Everything that calls the function, grouped by the top-level declaration the calls are in.
+pub fn incoming_calls(files: &[WorkspaceFile], item: &CallHierarchyItem) -> Vec<CallHierarchyIncomingCall> {
+    let mut incoming = vec![];
+    for file in files {
+        let callers = declarations(file);
+        for call in calls(&file.program) {
+            let Some(declaration) = resolve(files, file, &call.callee.name) else {
+                continue;
+            };
+            if declaration.file.uri != item.uri || declaration.name() != item.name {
+                continue;
+            }
+
+            let call_range = SourceRange::from(&call.callee);
+            let from_range = call_range.to_lsp_range(&file.code);
+            let from = match callers
+                .iter()
+                .find(|caller| SourceRange::from(caller.declarator).contains(call_range.start()))
+            {
+                Some(caller) => caller.to_call_hierarchy_item(),
+                // A call in a top-level expression belongs to the file itself.
+                None => file_item(file),
+            };
+
+            match incoming
+                .iter_mut()
+                .find(|c: &&mut CallHierarchyIncomingCall| c.from.uri == from.uri && c.from.range == from.range)
+            {
+                Some(existing) => existing.from_ranges.push(from_range),
+                None => incoming.push(CallHierarchyIncomingCall {
+                    from,
+                    from_ranges: vec![from_range],
+                }),
+            }
+        }
+    }
+    incoming
+}
+
+/// Every function the function calls, grouped by callee.
+/// Stdlib functions aren't declared anywhere in the workspace, so they are left out.
+pub fn outgoing_calls(files: &[WorkspaceFile], item: &CallHierarchyItem) -> Vec<CallHierarchyOutgoingCall> {
+    let Some(file) = files.iter().find(|f| f.uri == item.uri) else {
+        return vec![];
+    };
+    let Some(caller) = declarations(file).into_iter().find(|d| d.name() == item.name) else {
+        return vec![];
+    };
+    let caller_range = SourceRange::from(caller.declarator);
+
+    let mut outgoing: Vec<CallHierarchyOutgoingCall> = vec![];
+    for call in calls(&file.program) {
+        let call_range = SourceRange::from(&call.callee);
+        if !caller_range.contains(call_range.start()) {
+            continue;
+        }
+        let Some(callee) = resolve(files, file, &call.callee.name) else {
+            continue;
+        };
+
+        let to = callee.to_call_hierarchy_item();
+        let from_range = call_range.to_lsp_range(&file.code);
+        match outgoing
+            .iter_mut()
+            .find(|c| c.to.uri == to.uri && c.to.range == to.range)
+        {
+            Some(existing) => existing.from_ranges.push(from_range),
+            None => outgoing.push(CallHierarchyOutgoingCall {
+                to,
+                from_ranges: vec![from_range],
+            }),
+        }
+    }
+    outgoing
+}
+
+fn file_item(file: &WorkspaceFile) -> CallHierarchyItem {
+    let range = SourceRange([file.program.start, file.program.end]).to_lsp_range(&file.code);
+    CallHierarchyItem {
+        name: file
+            .uri
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .unwrap_or_default()
+            .to_string(),
+        kind: SymbolKind::FILE,
+        tags: None,
+        detail: None,
+        uri: file.uri.clone(),
+        range,
+        selection_range: range,
+        data: None,
+    }
+}
+
+/// Every call in the program.
+fn calls(program: &Program) -> Vec<CallExpression> {
+    let calls = Mutex::new(vec![]);
+    let _ = crate::walk::walk(program, &|node: crate::walk::Node| {
+        if let crate::walk::Node::CallExpression(call) = node {
+            calls.lock().map_err(|_| anyhow::anyhow!("mutex"))?.push(call.clone());
+        }
+        Ok(true)
+    });
+    calls.into_inner().unwrap_or_default()
+}
+
+/// The function a name refers to in a file: one declared in the file, or one it imports.
+fn resolve<'a>(files: &'a [WorkspaceFile], file: &'a WorkspaceFile, name: &str) -> Option<Declaration<'a>> {
+    if let Some(declaration) = declarations(file)
+        .into_iter()
+        .find(|d| d.kind == SymbolKind::FUNCTION && d.name() == name)
+    {
+        return Some(declaration);
+    }
+
+    for item in &file.program.body {
+        let BodyItem::ImportStatement(import) = item else {
+            continue;
+        };
+        let Some(imported) = import.items.iter().find(|i| i.identifier() == name) else {
+            continue;
+        };
+        let Ok(uri) = file.uri.join(&import.path) else {
+            continue;
+        };
+        let Some(other) = files.iter().find(|f| f.uri == uri) else {
+            continue;
+        };
+        return declarations(other)
+            .into_iter()
+            .find(|d| d.kind == SymbolKind::FUNCTION && d.name() == imported.name.name);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn file(uri: &str, code: &str) -> WorkspaceFile {
+        WorkspaceFile {
+            uri: Url::parse(uri).unwrap(),
+            program: crate::parser::parse(code).unwrap(),
+            code: code.to_string(),
+        }
+    }
+
+    fn workspace() -> Vec<WorkspaceFile> {
+        vec![
+            file(
+                "file:///project/parts.kcl",
+                r#"export fn bracket = (size) => {
+  return startSketchOn('XY')
+    |> startProfileAt([0, 0], %)
+    |> line([size, 0], %)
+    |> close(%)
+}
+
+export fn pair = (size) => {
+  const a = bracket(size)
+  const b = bracket(size * 2)
+  return [a, b]
+}
+"#,
+            ),
+            file(
+                "file:///project/main.kcl",
+                r#"import bracket as makeBracket, pair from "parts.kcl"
+const small = makeBracket(5)
+const thickness = 2
+pair(10)
+"#,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_symbols() {
+        let files = workspace();
+        let names = |query| {
+            symbols(&files, query)
+                .into_iter()
+                .map(|s| (s.name, s.kind))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("brk"), vec![("bracket".to_string(), SymbolKind::FUNCTION)]);
+        assert_eq!(names("THICK"), vec![("thickness".to_string(), SymbolKind::CONSTANT)]);
+        assert_eq!(names("").len(), 4);
+    }
+
+    #[test]
+    fn test_call_hierarchy() {
+        let files = workspace();
+        let parts = Url::parse("file:///project/parts.kcl").unwrap();
+        let main = Url::parse("file:///project/main.kcl").unwrap();
+
+        // From the call through the alias in main.kcl to the declaration in parts.kcl.
+        let pos = files[1].code.find("makeBracket(5)").unwrap();
+        let item = prepare_call_hierarchy(&files, &main, pos).unwrap();
+        assert_eq!(item.name, "bracket");
+        assert_eq!(item.uri, parts);
+
+        let incoming = incoming_calls(&files, &item);
+        let callers = incoming
+            .iter()
+            .map(|c| (c.from.name.as_str(), c.from_ranges.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(callers, vec![("pair", 2), ("small", 1)]);
+
+        // From the declaration.
+        let pos = files[0].code.find("pair").unwrap();
+        let item = prepare_call_hierarchy(&files, &parts, pos).unwrap();
+        assert_eq!(item.name, "pair");
+        let outgoing = outgoing_calls(&files, &item);
+        assert_eq!(outgoing.len(), 1);
+        assert_eq!(outgoing[0].to.name, "bracket");
+        assert_eq!(outgoing[0].from_ranges.len(), 2);
+
+        let incoming = incoming_calls(&files, &item);
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(incoming[0].from.name, "main.kcl");
+        assert_eq!(incoming[0].from.kind, SymbolKind::FILE);
+    }
+}
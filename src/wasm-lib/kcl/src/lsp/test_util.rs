@@ -61,14 +61,22 @@ pub async fn kcl_lsp_server(execute: bool) -> Result<crate::lsp::kcl::Backend> {
         diagnostics_map: Default::default(),
         symbols_map: Default::default(),
         semantic_tokens_map: Default::default(),
+        sent_semantic_tokens: Default::default(),
         zoo_client,
         can_send_telemetry: true,
+        telemetry: Default::default(),
+        text_editor: Default::default(),
         executor_ctx: Arc::new(tokio::sync::RwLock::new(executor_ctx)),
         can_execute: Arc::new(tokio::sync::RwLock::new(can_execute)),
         is_initialized: Default::default(),
     })
     .custom_method("kcl/updateUnits", crate::lsp::kcl::Backend::update_units)
     .custom_method("kcl/updateCanExecute", crate::lsp::kcl::Backend::update_can_execute)
+    .custom_method("kcl/updateTelemetry", crate::lsp::kcl::Backend::update_telemetry)
+    .custom_method(
+        "kcl/updateTextEditorSettings",
+        crate::lsp::kcl::Backend::update_text_editor_settings,
+    )
     .finish();
 
     let server = service.inner();
@@ -84,16 +92,24 @@ pub async fn kcl_lsp_server(execute: bool) -> Result<crate::lsp::kcl::Backend> {
 
 // Create a fake copilot lsp server for testing.
 pub async fn copilot_lsp_server() -> Result<crate::lsp::copilot::Backend> {
-    // We don't actually need to authenticate to the backend for this test.
-    let zoo_client = kittycad::Client::new_from_env();
+    // Don't hit a real model, so the completions are deterministic.
+    copilot_lsp_server_with_provider(Arc::new(crate::lsp::copilot::provider::StaticCompletionProvider::new(
+        vec!["  |> line([0, 10], %)".to_string()],
+    )))
+    .await
+}
 
+// Create a fake copilot lsp server for testing, with completions from the given provider.
+pub async fn copilot_lsp_server_with_provider(
+    provider: Arc<dyn crate::lsp::copilot::provider::CompletionProvider>,
+) -> Result<crate::lsp::copilot::Backend> {
     // Create the backend.
     let (service, _) = tower_lsp::LspService::new(|client| crate::lsp::copilot::Backend {
         client,
         fs: Arc::new(crate::fs::FileManager::new()),
         workspace_folders: Default::default(),
         code_map: Default::default(),
-        zoo_client,
+        provider,
         editor_info: Arc::new(RwLock::new(crate::lsp::copilot::types::CopilotEditorInfo::default())),
         cache: Arc::new(crate::lsp::copilot::cache::CopilotCache::new()),
         telemetry: Default::default(),
@@ -44,7 +44,7 @@ async fn test_updating_kcl_lsp_files() {
         }
     );
 
-    assert_eq!(server.code_map.len(), 10);
+    assert_eq!(server.code_map.len(), 15);
 
     // Run open file.
     server
@@ -59,7 +59,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 11);
+    assert_eq!(server.code_map.len(), 16);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -75,7 +75,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 11);
+    assert_eq!(server.code_map.len(), 16);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -94,7 +94,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -120,7 +120,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -141,7 +141,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -161,7 +161,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 13);
+    assert_eq!(server.code_map.len(), 18);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -182,7 +182,7 @@ async fn test_updating_kcl_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -216,7 +216,7 @@ async fn test_updating_kcl_lsp_files() {
     );
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -252,7 +252,7 @@ async fn test_updating_kcl_lsp_files() {
             name: "my-project2".to_string()
         }
     );
-    assert_eq!(server.code_map.len(), 10);
+    assert_eq!(server.code_map.len(), 15);
     // Just make sure that one of the current files read from disk is accurate.
     assert_eq!(
         server
@@ -297,7 +297,7 @@ async fn test_updating_copilot_lsp_files() {
         }
     );
 
-    assert_eq!(server.code_map.len(), 10);
+    assert_eq!(server.code_map.len(), 15);
 
     // Run open file.
     server
@@ -312,7 +312,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 11);
+    assert_eq!(server.code_map.len(), 16);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -328,7 +328,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 11);
+    assert_eq!(server.code_map.len(), 16);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -347,7 +347,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -373,7 +373,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -394,7 +394,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -414,7 +414,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 13);
+    assert_eq!(server.code_map.len(), 18);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -435,7 +435,7 @@ async fn test_updating_copilot_lsp_files() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -469,7 +469,7 @@ async fn test_updating_copilot_lsp_files() {
     );
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -500,7 +500,7 @@ async fn test_updating_copilot_lsp_files() {
     );
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 12);
+    assert_eq!(server.code_map.len(), 17);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -536,7 +536,7 @@ async fn test_updating_copilot_lsp_files() {
             name: "my-project2".to_string()
         }
     );
-    assert_eq!(server.code_map.len(), 10);
+    assert_eq!(server.code_map.len(), 15);
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -572,7 +572,7 @@ async fn test_kcl_lsp_create_zip() {
         }
     );
 
-    assert_eq!(server.code_map.len(), 10);
+    assert_eq!(server.code_map.len(), 15);
 
     // Run open file.
     server
@@ -587,7 +587,7 @@ async fn test_kcl_lsp_create_zip() {
         .await;
 
     // Check the code map.
-    assert_eq!(server.code_map.len(), 11);
+    assert_eq!(server.code_map.len(), 16);
     assert_eq!(
         server.code_map.get("file:///test.kcl").unwrap().clone(),
         "test".as_bytes()
@@ -755,6 +755,199 @@ async fn test_kcl_lsp_completions_tags() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kcl_lsp_completions_pipe_solid() {
+    let server = kcl_lsp_server(false).await.unwrap();
+
+    // Send open file.
+    server
+        .did_open(tower_lsp::lsp_types::DidOpenTextDocumentParams {
+            text_document: tower_lsp::lsp_types::TextDocumentItem {
+                uri: "file:///test.kcl".try_into().unwrap(),
+                language_id: "kcl".to_string(),
+                version: 1,
+                text: r#"const part001 = startSketchOn('XY')
+  |> startProfileAt([11.19, 28.35], %)
+  |> line([28.67, -13.25], %, $here)
+  |> close(%)
+  |> extrude(5, %)
+  |> "#
+                    .to_string(),
+            },
+        })
+        .await;
+
+    // Send completion request.
+    let completions = server
+        .completion(tower_lsp::lsp_types::CompletionParams {
+            text_document_position: tower_lsp::lsp_types::TextDocumentPositionParams {
+                text_document: tower_lsp::lsp_types::TextDocumentIdentifier {
+                    uri: "file:///test.kcl".try_into().unwrap(),
+                },
+                position: tower_lsp::lsp_types::Position { line: 5, character: 5 },
+            },
+            context: None,
+            partial_result_params: Default::default(),
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Only functions that take a solid.
+    if let tower_lsp::lsp_types::CompletionResponse::Array(completions) = completions {
+        let labels = completions.iter().map(|c| c.label.as_str()).collect::<Vec<_>>();
+        assert!(labels.contains(&"fillet"));
+        assert!(labels.contains(&"shell"));
+        assert!(!labels.contains(&"line"));
+        assert!(!labels.contains(&"part001"));
+        assert!(!labels.contains(&"here"));
+    } else {
+        panic!("Expected array of completions");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kcl_lsp_inlay_hints() {
+    let server = kcl_lsp_server(false).await.unwrap();
+
+    // Send open file.
+    server
+        .did_open(tower_lsp::lsp_types::DidOpenTextDocumentParams {
+            text_document: tower_lsp::lsp_types::TextDocumentItem {
+                uri: "file:///test.kcl".try_into().unwrap(),
+                language_id: "kcl".to_string(),
+                version: 1,
+                text: r#"const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([0, legLen(5, 3)], %)"#
+                    .to_string(),
+            },
+        })
+        .await;
+
+    let params = tower_lsp::lsp_types::InlayHintParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier {
+            uri: "file:///test.kcl".try_into().unwrap(),
+        },
+        range: tower_lsp::lsp_types::Range {
+            start: tower_lsp::lsp_types::Position { line: 1, character: 0 },
+            end: tower_lsp::lsp_types::Position { line: 2, character: 40 },
+        },
+        work_done_progress_params: Default::default(),
+    };
+
+    // Only the hints in the range, and no values since nothing executed.
+    let hints = server.inlay_hint(params.clone()).await.unwrap().unwrap();
+    let labels = hints
+        .iter()
+        .map(|hint| match &hint.label {
+            tower_lsp::lsp_types::InlayHintLabel::String(label) => label.as_str(),
+            tower_lsp::lsp_types::InlayHintLabel::LabelParts(_) => panic!("expected a string label"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["data:", "data:", "hypotenuse:", "leg:"]);
+
+    // Turn the parameter hints off.
+    server
+        .update_text_editor_settings(crate::lsp::kcl::custom_notifications::UpdateTextEditorSettingsParams {
+            text_editor: crate::settings::types::TextEditorSettings {
+                parameter_hints: false.into(),
+                ..Default::default()
+            },
+        })
+        .await
+        .unwrap();
+    let hints = server.inlay_hint(params).await.unwrap().unwrap();
+    assert!(hints.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kcl_lsp_workspace_symbols_and_call_hierarchy() {
+    let server = kcl_lsp_server(false).await.unwrap();
+
+    // Send open files.
+    server
+        .did_open(tower_lsp::lsp_types::DidOpenTextDocumentParams {
+            text_document: tower_lsp::lsp_types::TextDocumentItem {
+                uri: "file:///project/parts.kcl".try_into().unwrap(),
+                language_id: "kcl".to_string(),
+                version: 1,
+                text: r#"export fn bracket = (size) => {
+  return startSketchOn('XY')
+    |> startProfileAt([0, 0], %)
+    |> line([size, 0], %)
+    |> close(%)
+}"#
+                .to_string(),
+            },
+        })
+        .await;
+    server
+        .did_open(tower_lsp::lsp_types::DidOpenTextDocumentParams {
+            text_document: tower_lsp::lsp_types::TextDocumentItem {
+                uri: "file:///project/main.kcl".try_into().unwrap(),
+                language_id: "kcl".to_string(),
+                version: 1,
+                text: r#"import bracket from "parts.kcl"
+const part001 = bracket(5)"#
+                    .to_string(),
+            },
+        })
+        .await;
+
+    // Search across both files.
+    let symbols = server
+        .symbol(tower_lsp::lsp_types::WorkspaceSymbolParams {
+            query: "part".to_string(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].name, "part001");
+    assert_eq!(symbols[0].location.uri.as_str(), "file:///project/main.kcl");
+
+    // Find who calls `bracket`.
+    let items = server
+        .prepare_call_hierarchy(tower_lsp::lsp_types::CallHierarchyPrepareParams {
+            text_document_position_params: tower_lsp::lsp_types::TextDocumentPositionParams {
+                text_document: tower_lsp::lsp_types::TextDocumentIdentifier {
+                    uri: "file:///project/parts.kcl".try_into().unwrap(),
+                },
+                position: tower_lsp::lsp_types::Position { line: 0, character: 12 },
+            },
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "bracket");
+
+    let incoming = server
+        .incoming_calls(tower_lsp::lsp_types::CallHierarchyIncomingCallsParams {
+            item: items[0].clone(),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(incoming.len(), 1);
+    assert_eq!(incoming[0].from.name, "part001");
+    assert_eq!(incoming[0].from.uri.as_str(), "file:///project/main.kcl");
+    assert_eq!(
+        incoming[0].from_ranges,
+        vec![tower_lsp::lsp_types::Range {
+            start: tower_lsp::lsp_types::Position { line: 1, character: 16 },
+            end: tower_lsp::lsp_types::Position { line: 1, character: 23 },
+        }]
+    );
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_kcl_lsp_completions_const_raw() {
     let server = kcl_lsp_server(false).await.unwrap();
@@ -992,6 +1185,87 @@ async fn test_kcl_lsp_semantic_tokens() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kcl_lsp_incremental_change_semantic_tokens_delta() {
+    let server = kcl_lsp_server(false).await.unwrap();
+
+    // Send open file.
+    server
+        .did_open(tower_lsp::lsp_types::DidOpenTextDocumentParams {
+            text_document: tower_lsp::lsp_types::TextDocumentItem {
+                uri: "file:///test.kcl".try_into().unwrap(),
+                language_id: "kcl".to_string(),
+                version: 1,
+                text: "startSketchOn('XY')".to_string(),
+            },
+        })
+        .await;
+
+    let semantic_tokens = server
+        .semantic_tokens_full(tower_lsp::lsp_types::SemanticTokensParams {
+            text_document: tower_lsp::lsp_types::TextDocumentIdentifier {
+                uri: "file:///test.kcl".try_into().unwrap(),
+            },
+            partial_result_params: Default::default(),
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    let tower_lsp::lsp_types::SemanticTokensResult::Tokens(semantic_tokens) = semantic_tokens else {
+        panic!("Expected semantic tokens");
+    };
+
+    // Send only the new line.
+    server
+        .did_change(tower_lsp::lsp_types::DidChangeTextDocumentParams {
+            text_document: tower_lsp::lsp_types::VersionedTextDocumentIdentifier {
+                uri: "file:///test.kcl".try_into().unwrap(),
+                version: 2,
+            },
+            content_changes: vec![tower_lsp::lsp_types::TextDocumentContentChangeEvent {
+                range: Some(tower_lsp::lsp_types::Range {
+                    start: tower_lsp::lsp_types::Position { line: 0, character: 19 },
+                    end: tower_lsp::lsp_types::Position { line: 0, character: 19 },
+                }),
+                range_length: None,
+                text: "\nconst thing = 1".to_string(),
+            }],
+        })
+        .await;
+
+    let code = "startSketchOn('XY')\nconst thing = 1";
+    assert_eq!(
+        server.code_map.get("file:///test.kcl").unwrap().clone(),
+        code.as_bytes()
+    );
+    assert_eq!(
+        server.token_map.get("file:///test.kcl").unwrap().clone(),
+        crate::token::lexer(code).unwrap()
+    );
+
+    // Only the tokens on the new line come back.
+    let delta = server
+        .semantic_tokens_full_delta(tower_lsp::lsp_types::SemanticTokensDeltaParams {
+            text_document: tower_lsp::lsp_types::TextDocumentIdentifier {
+                uri: "file:///test.kcl".try_into().unwrap(),
+            },
+            previous_result_id: semantic_tokens.result_id.unwrap(),
+            partial_result_params: Default::default(),
+            work_done_progress_params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    let tower_lsp::lsp_types::SemanticTokensFullDeltaResult::TokensDelta(delta) = delta else {
+        panic!("Expected a semantic tokens delta");
+    };
+    assert_eq!(delta.edits.len(), 1);
+    assert_eq!(delta.edits[0].start, 10);
+    assert_eq!(delta.edits[0].delete_count, 0);
+    assert_eq!(delta.edits[0].data.as_ref().unwrap().len(), 4);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_kcl_lsp_semantic_tokens_large_file() {
     let server = kcl_lsp_server(false).await.unwrap();
@@ -1942,6 +2216,63 @@ async fn test_copilot_lsp_completions() {
         .await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_copilot_lsp_completions_context() {
+    let provider = std::sync::Arc::new(crate::lsp::copilot::provider::StaticCompletionProvider::new(vec![
+        "  |> line([width, 0], %)".to_string(),
+    ]));
+    let server = crate::lsp::test_util::copilot_lsp_server_with_provider(provider.clone())
+        .await
+        .unwrap();
+
+    // Open the imported file, so it comes from the editor rather than disk.
+    server
+        .did_open(tower_lsp::lsp_types::DidOpenTextDocumentParams {
+            text_document: tower_lsp::lsp_types::TextDocumentItem {
+                uri: "file:///project/dims.kcl".try_into().unwrap(),
+                language_id: "kcl".to_string(),
+                version: 1,
+                text: "export const width = 10".to_string(),
+            },
+        })
+        .await;
+
+    let params = crate::lsp::copilot::types::CopilotLspCompletionParams {
+        doc: crate::lsp::copilot::types::CopilotDocParams {
+            indent_size: 2,
+            insert_spaces: true,
+            language_id: "kcl".to_string(),
+            path: "file:///project/main.kcl".to_string(),
+            position: crate::lsp::copilot::types::CopilotPosition { line: 3, character: 2 },
+            relative_path: "main.kcl".to_string(),
+            source: r#"import width from "dims.kcl"
+const bracket = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  
+  |> close(%)
+"#
+            .to_string(),
+            tab_size: 2,
+            uri: "file:///project/main.kcl".into(),
+        },
+    };
+    let completions = server.get_completions_cycling(params).await.unwrap();
+    assert_eq!(completions.completions.len(), 1);
+
+    let requests = provider.requests();
+    assert_eq!(requests.len(), 1);
+    let prompt = &requests[0].prompt;
+    assert!(prompt.contains("// startProfileAt("), "{}", prompt);
+    assert!(prompt.contains("// close("), "{}", prompt);
+    assert!(
+        prompt.contains("// Imported from \"dims.kcl\":\n// export const width = 10\n"),
+        "{}",
+        prompt
+    );
+    assert!(prompt.ends_with("  |> startProfileAt([0, 0], %)\n  "), "{}", prompt);
+    assert_eq!(requests[0].suffix, "\n  |> close(%)\n");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_copilot_on_save() {
     let server = copilot_lsp_server().await.unwrap();
@@ -1,7 +1,7 @@
 //! Utility functions for working with ropes and positions.
 
 use ropey::Rope;
-use tower_lsp::lsp_types::{Diagnostic, Position};
+use tower_lsp::lsp_types::{Diagnostic, Position, SemanticToken, SemanticTokensEdit, TextDocumentContentChangeEvent};
 
 pub fn position_to_offset(position: Position, rope: &Rope) -> Option<usize> {
     Some(rope.try_line_to_char(position.line as usize).ok()? + position.character as usize)
@@ -32,6 +32,63 @@ pub fn get_line_before(pos: Position, rope: &Rope) -> Option<String> {
     Some(rope.slice(line_start..offset).to_string())
 }
 
+/// Apply the changes from a `textDocument/didChange` notification, in order.
+/// A change without a range replaces the whole text.
+pub fn apply_content_changes(text: &str, changes: Vec<TextDocumentContentChangeEvent>) -> String {
+    let mut rope = Rope::from_str(text);
+    for change in changes {
+        match change.range {
+            Some(range) => {
+                let start = lsp_position_to_char(range.start, &rope);
+                let end = lsp_position_to_char(range.end, &rope).max(start);
+                rope.remove(start..end);
+                rope.insert(start, &change.text);
+            }
+            None => rope = Rope::from_str(&change.text),
+        }
+    }
+    rope.to_string()
+}
+
+/// Convert a position, where the character counts utf-16 code units, to a char index.
+/// Positions past the end of a line or of the text are clamped to its end.
+fn lsp_position_to_char(position: Position, rope: &Rope) -> usize {
+    let line_index = position.line as usize;
+    if line_index >= rope.len_lines() {
+        return rope.len_chars();
+    }
+
+    let line = rope.line(line_index);
+    let mut line_len = line.len_chars();
+    while line_len > 0 && matches!(line.char(line_len - 1), '\n' | '\r') {
+        line_len -= 1;
+    }
+    let character = (position.character as usize).min(line.slice(..line_len).len_utf16_cu());
+
+    rope.line_to_char(line_index) + line.utf16_cu_to_char(character)
+}
+
+/// The edits that turn the semantic tokens the client has into the current ones.
+pub fn semantic_tokens_edits(previous: &[SemanticToken], current: &[SemanticToken]) -> Vec<SemanticTokensEdit> {
+    let prefix = previous.iter().zip(current).take_while(|(a, b)| a == b).count();
+    if prefix == previous.len() && prefix == current.len() {
+        return vec![];
+    }
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // Edits index into the flattened data, which has 5 numbers for every token.
+    vec![SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: ((previous.len() - prefix - suffix) * 5) as u32,
+        data: Some(current[prefix..current.len() - suffix].to_vec()),
+    }]
+}
+
 /// Convert an object into a [lsp_types::Diagnostic] given the
 /// [TextDocumentItem]'s `.text` field.
 pub trait IntoDiagnostic {
@@ -41,3 +98,68 @@ pub trait IntoDiagnostic {
     /// Get the severity of the diagnostic.
     fn severity(&self) -> tower_lsp::lsp_types::DiagnosticSeverity;
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tower_lsp::lsp_types::Range;
+
+    use super::*;
+
+    fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range {
+                start: Position::new(start.0, start.1),
+                end: Position::new(end.0, end.1),
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_content_changes() {
+        let text = "const x = 1\nconst y = '😀' + x\n";
+        let changes = vec![
+            change((0, 6), (0, 7), "width"),
+            // The character counts utf-16 code units, and the emoji is two of them.
+            change((1, 14), (1, 17), ""),
+            // Past the end of the line.
+            change((0, 99), (0, 99), "0"),
+        ];
+        assert_eq!(
+            apply_content_changes(text, changes),
+            "const width = 10\nconst y = '😀'x\n"
+        );
+
+        let full = TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "const z = 2".to_string(),
+        };
+        assert_eq!(apply_content_changes(text, vec![full]), "const z = 2");
+    }
+
+    #[test]
+    fn test_semantic_tokens_edits() {
+        let token = |delta_start| SemanticToken {
+            delta_line: 0,
+            delta_start,
+            length: 1,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        };
+        let previous = vec![token(0), token(1), token(2), token(3)];
+        assert!(semantic_tokens_edits(&previous, &previous).is_empty());
+
+        let current = vec![token(0), token(5), token(6), token(3)];
+        assert_eq!(
+            semantic_tokens_edits(&previous, &current),
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 10,
+                data: Some(vec![token(5), token(6)]),
+            }]
+        );
+    }
+}
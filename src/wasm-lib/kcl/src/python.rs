@@ -0,0 +1,342 @@
+//! Python bindings, built into the `kcl` module with `maturin build` from the `kcl-python` crate.
+//!
+//! ```python
+//! import kcl
+//!
+//! program = kcl.parse("const x = 1 + 2")
+//! print(kcl.recast(program, kcl.FormatOptions(tab_size=4)))
+//! print(kcl.execute("const x = 1 + 2", mock=True)["x"])
+//! ```
+
+use kcmc::{
+    each_cmd as mcmd,
+    format::{fbx, gltf, obj, ply, step, stl, OutputFormat},
+    websocket::OkWebSocketResponseData,
+    ModelingCmd,
+};
+use kittycad_modeling_cmds as kcmc;
+use pyo3::{
+    exceptions::PyException,
+    prelude::*,
+    types::{PyBytes, PyDict, PyList},
+};
+
+use crate::{
+    ast::types::{FormatOptions, Program},
+    executor::{ExecutorContext, ExecutorSettings, IdGenerator, SourceRange},
+    lint::{Discovered, Finding},
+    settings::types::UnitLength,
+};
+
+/// The formats programs can be exported to.
+#[pyclass(eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileExportFormat {
+    Fbx,
+    Glb,
+    Gltf,
+    Obj,
+    Ply,
+    Step,
+    Stl,
+}
+
+impl FileExportFormat {
+    fn to_output_format(self, units: UnitLength) -> OutputFormat {
+        // The engine's coordinate system.
+        let coords = kcmc::coord::System {
+            forward: kcmc::coord::AxisDirectionPair {
+                axis: kcmc::coord::Axis::Y,
+                direction: kcmc::coord::Direction::Negative,
+            },
+            up: kcmc::coord::AxisDirectionPair {
+                axis: kcmc::coord::Axis::Z,
+                direction: kcmc::coord::Direction::Positive,
+            },
+        };
+
+        match self {
+            FileExportFormat::Fbx => OutputFormat::Fbx(fbx::export::Options {
+                storage: fbx::export::Storage::Binary,
+                created: None,
+            }),
+            FileExportFormat::Glb => OutputFormat::Gltf(gltf::export::Options {
+                storage: gltf::export::Storage::Binary,
+                presentation: gltf::export::Presentation::Compact,
+            }),
+            FileExportFormat::Gltf => OutputFormat::Gltf(gltf::export::Options {
+                storage: gltf::export::Storage::Embedded,
+                presentation: gltf::export::Presentation::Pretty,
+            }),
+            FileExportFormat::Obj => OutputFormat::Obj(obj::export::Options {
+                coords,
+                units: units.into(),
+            }),
+            FileExportFormat::Ply => OutputFormat::Ply(ply::export::Options {
+                storage: ply::export::Storage::Ascii,
+                coords,
+                selection: kcmc::format::Selection::DefaultScene,
+                units: units.into(),
+            }),
+            FileExportFormat::Step => OutputFormat::Step(step::export::Options { coords, created: None }),
+            FileExportFormat::Stl => OutputFormat::Stl(stl::export::Options {
+                storage: stl::export::Storage::Ascii,
+                coords,
+                selection: kcmc::format::Selection::DefaultScene,
+                units: units.into(),
+            }),
+        }
+    }
+}
+
+/// A file the engine exported.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ExportFile {
+    name: String,
+    contents: Vec<u8>,
+}
+
+#[pymethods]
+impl ExportFile {
+    #[getter]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[getter]
+    pub fn contents<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.contents)
+    }
+}
+
+#[pymethods]
+impl FormatOptions {
+    #[new]
+    #[pyo3(signature = (tab_size = 2, use_tabs = false, insert_final_newline = true))]
+    pub fn py_new(tab_size: usize, use_tabs: bool, insert_final_newline: bool) -> Self {
+        Self {
+            tab_size,
+            use_tabs,
+            insert_final_newline,
+        }
+    }
+}
+
+#[pymethods]
+impl Program {
+    /// The AST as JSON, in the same shape the modeling app uses.
+    pub fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(to_py_err)
+    }
+}
+
+fn to_py_err(error: impl std::fmt::Display) -> PyErr {
+    PyException::new_err(error.to_string())
+}
+
+/// Turn a JSON value into the equivalent Python object.
+fn json_to_py(py: Python<'_>, value: &serde_json::Value) -> PyResult<PyObject> {
+    Ok(match value {
+        serde_json::Value::Null => py.None(),
+        serde_json::Value::Bool(b) => b.into_py(py),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into_py(py),
+            None => n.as_f64().unwrap_or(f64::NAN).into_py(py),
+        },
+        serde_json::Value::String(s) => s.into_py(py),
+        serde_json::Value::Array(values) => {
+            let values = values
+                .iter()
+                .map(|value| json_to_py(py, value))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new_bound(py, values).into_py(py)
+        }
+        serde_json::Value::Object(map) => {
+            let dict = PyDict::new_bound(py);
+            for (key, value) in map {
+                dict.set_item(key, json_to_py(py, value)?)?;
+            }
+            dict.into_py(py)
+        }
+    })
+}
+
+/// Run the future to completion without holding the GIL, so other Python threads can run
+/// while the engine works.
+fn block_on<T, F>(py: Python<'_>, f: impl FnOnce() -> F + Send) -> PyResult<T>
+where
+    T: Send,
+    F: std::future::Future<Output = anyhow::Result<T>>,
+{
+    py.allow_threads(|| {
+        let runtime = tokio::runtime::Runtime::new()?;
+        runtime.block_on(f())
+    })
+    .map_err(to_py_err)
+}
+
+async fn new_context(units: UnitLength, mock: bool) -> anyhow::Result<ExecutorContext> {
+    let settings = ExecutorSettings {
+        units,
+        ..Default::default()
+    };
+    if mock {
+        ExecutorContext::new_mock(settings).await
+    } else {
+        ExecutorContext::new_with_default_client(settings).await
+    }
+}
+
+/// Parse KCL code into a program.
+#[pyfunction]
+fn parse(code: &str) -> PyResult<Program> {
+    Ok(crate::parser::parse(code)?)
+}
+
+/// Format a program back into KCL code.
+#[pyfunction]
+#[pyo3(signature = (program, options = None))]
+fn recast(program: &Program, options: Option<FormatOptions>) -> String {
+    program.recast(&options.unwrap_or_default(), 0)
+}
+
+/// Lint KCL code.
+#[pyfunction]
+fn lint(code: &str) -> PyResult<Vec<Discovered>> {
+    let program = crate::parser::parse(code)?;
+    program.lint_all().map_err(to_py_err)
+}
+
+/// Execute KCL code and return the values of its top-level variables.
+/// With `mock=True` nothing is sent to the engine, which is enough to compute values;
+/// otherwise `KITTYCAD_API_TOKEN` must be set.
+#[pyfunction]
+#[pyo3(signature = (code, units = None, mock = false))]
+fn execute(py: Python<'_>, code: &str, units: Option<UnitLength>, mock: bool) -> PyResult<PyObject> {
+    let program = crate::parser::parse(code)?;
+    let values = block_on(py, move || async move {
+        let ctx = new_context(units.unwrap_or_default(), mock).await?;
+        let exec_state = ctx.run(&program, None, IdGenerator::default(), None).await?;
+        let root = &exec_state.memory.environments[0];
+        root.iter()
+            .map(|(name, value)| Ok((name.clone(), value.get_json_value()?)))
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+
+    let dict = PyDict::new_bound(py);
+    for (name, value) in values {
+        dict.set_item(name, json_to_py(py, &value)?)?;
+    }
+    Ok(dict.into_py(py))
+}
+
+/// Execute KCL code on the engine and export the result. glTF files list the materials solids
+/// were given in their `extras`.
+#[pyfunction]
+#[pyo3(signature = (code, export_format, units = None))]
+fn execute_and_export(
+    py: Python<'_>,
+    code: &str,
+    export_format: FileExportFormat,
+    units: Option<UnitLength>,
+) -> PyResult<Vec<ExportFile>> {
+    let program = crate::parser::parse(code)?;
+    let units = units.unwrap_or_default();
+    block_on(py, move || async move {
+        let ctx = new_context(units, false).await?;
+        let exec_state = ctx.run(&program, None, IdGenerator::default(), None).await?;
+        let resp = ctx
+            .engine
+            .send_modeling_cmd(
+                uuid::Uuid::new_v4(),
+                SourceRange::default(),
+                ModelingCmd::from(mcmd::Export {
+                    entity_ids: vec![],
+                    format: export_format.to_output_format(units),
+                }),
+            )
+            .await?;
+
+        let OkWebSocketResponseData::Export { files } = resp else {
+            anyhow::bail!("Unexpected response from engine: {:?}", resp);
+        };
+        files
+            .into_iter()
+            .map(|file| {
+                Ok(ExportFile {
+                    contents: crate::export::add_materials(&file.name, file.contents, exec_state.parts.materials())?,
+                    name: file.name,
+                })
+            })
+            .collect()
+    })
+}
+
+/// Execute KCL code on the engine and return a PNG snapshot of the result.
+#[pyfunction]
+#[pyo3(signature = (code, units = None))]
+fn execute_and_snapshot<'py>(py: Python<'py>, code: &str, units: Option<UnitLength>) -> PyResult<Bound<'py, PyBytes>> {
+    let program = crate::parser::parse(code)?;
+    let snapshot = block_on(py, move || async move {
+        let ctx = new_context(units.unwrap_or_default(), false).await?;
+        ctx.execute_and_prepare_snapshot(&program, IdGenerator::default(), None)
+            .await
+    })?;
+    Ok(PyBytes::new_bound(py, &snapshot.contents.0))
+}
+
+/// Add the classes and functions of the `kcl` Python module.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Program>()?;
+    m.add_class::<FormatOptions>()?;
+    m.add_class::<UnitLength>()?;
+    m.add_class::<Discovered>()?;
+    m.add_class::<Finding>()?;
+    m.add_class::<SourceRange>()?;
+    m.add_class::<FileExportFormat>()?;
+    m.add_class::<ExportFile>()?;
+
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(recast, m)?)?;
+    m.add_function(wrap_pyfunction!(lint, m)?)?;
+    m.add_function(wrap_pyfunction!(execute, m)?)?;
+    m.add_function(wrap_pyfunction!(execute_and_export, m)?)?;
+    m.add_function(wrap_pyfunction!(execute_and_snapshot, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let program = parse("const x = 1 + 2").unwrap();
+        assert_eq!(program.body.len(), 1);
+
+        assert!(parse("const x = ").is_err());
+    }
+
+    #[test]
+    fn test_recast() {
+        let program = parse("fn add = (a, b) => {\nreturn a+b\n}").unwrap();
+        assert_eq!(recast(&program, None), "fn add = (a, b) => {\n  return a + b\n}\n");
+        assert_eq!(
+            recast(&program, Some(FormatOptions::py_new(4, false, false))),
+            "fn add = (a, b) => {\n    return a + b\n}"
+        );
+    }
+
+    #[test]
+    fn test_lint() {
+        let findings = lint("const Thickness = 0.5").unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].finding.code, "Z0001");
+
+        assert!(lint("const thickness = 0.5").unwrap().is_empty());
+        assert!(lint("const thickness = ").is_err());
+    }
+}
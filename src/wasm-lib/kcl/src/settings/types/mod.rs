@@ -87,6 +87,10 @@ pub struct Settings {
     #[serde(default, alias = "commandBar", skip_serializing_if = "is_default")]
     #[validate(nested)]
     pub command_bar: CommandBarSettings,
+    /// Settings that control what usage data is collected and where it goes.
+    #[serde(default, skip_serializing_if = "is_default")]
+    #[validate(nested)]
+    pub telemetry: TelemetrySettings,
 }
 
 /// Application wide settings.
@@ -424,6 +428,12 @@ pub struct TextEditorSettings {
     /// Whether to make the cursor blink in the editor.
     #[serde(default, alias = "blinkingCursor", skip_serializing_if = "is_default")]
     pub blinking_cursor: DefaultTrue,
+    /// Whether to show the names of parameters next to the arguments of function calls.
+    #[serde(default, alias = "parameterHints", skip_serializing_if = "is_default")]
+    pub parameter_hints: DefaultTrue,
+    /// Whether to show the values of calls that compute numbers, once the code has executed.
+    #[serde(default, alias = "valueHints", skip_serializing_if = "is_default")]
+    pub value_hints: DefaultTrue,
 }
 
 /// Settings that affect the behavior of project management.
@@ -472,6 +482,36 @@ pub struct CommandBarSettings {
     pub include_settings: DefaultTrue,
 }
 
+/// Settings that control what usage data is collected and where it goes.
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema, ts_rs::TS, PartialEq, Eq, Validate)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub struct TelemetrySettings {
+    /// How much to collect. Nothing is collected unless the user opts in.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub level: TelemetryLevel,
+    /// Write telemetry to this file instead of sending it to Zoo.
+    #[serde(default, alias = "localPath", skip_serializing_if = "Option::is_none")]
+    pub local_path: Option<std::path::PathBuf>,
+}
+
+/// How much telemetry to collect.
+#[derive(
+    Debug, Default, Copy, Eq, PartialEq, Clone, Deserialize, Serialize, JsonSchema, ts_rs::TS, Display, FromStr,
+)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+#[display(style = "snake_case")]
+pub enum TelemetryLevel {
+    /// Collect nothing.
+    #[default]
+    Off,
+    /// Collect the size of each file and which functions it calls, but no code.
+    MetadataOnly,
+    /// Also collect the code, with string literals and comments redacted.
+    Full,
+}
+
 /// The types of onboarding status.
 #[derive(Debug, Default, Eq, PartialEq, Clone, Deserialize, Serialize, JsonSchema, ts_rs::TS, Display, FromStr)]
 #[ts(export)]
@@ -539,7 +579,8 @@ mod tests {
 
     use super::{
         AppColor, AppSettings, AppTheme, AppearanceSettings, CameraProjectionType, CommandBarSettings, Configuration,
-        ModelingSettings, OnboardingStatus, ProjectSettings, Settings, TextEditorSettings, UnitLength,
+        ModelingSettings, OnboardingStatus, ProjectSettings, Settings, TelemetryLevel, TelemetrySettings,
+        TextEditorSettings, UnitLength,
     };
 
     #[test]
@@ -595,12 +636,15 @@ textWrapping = true
                     },
                     text_editor: TextEditorSettings {
                         text_wrapping: true.into(),
-                        blinking_cursor: true.into()
+                        blinking_cursor: true.into(),
+                        parameter_hints: true.into(),
+                        value_hints: true.into()
                     },
                     project: Default::default(),
                     command_bar: CommandBarSettings {
                         include_settings: true.into()
                     },
+                    telemetry: Default::default(),
                 }
             }
         );
@@ -656,12 +700,15 @@ includeSettings = false
                     },
                     text_editor: TextEditorSettings {
                         text_wrapping: false.into(),
-                        blinking_cursor: false.into()
+                        blinking_cursor: false.into(),
+                        parameter_hints: true.into(),
+                        value_hints: true.into()
                     },
                     project: Default::default(),
                     command_bar: CommandBarSettings {
                         include_settings: false.into()
                     },
+                    telemetry: Default::default(),
                 }
             }
         );
@@ -722,7 +769,9 @@ defaultProjectName = "projects-$nnn"
                     },
                     text_editor: TextEditorSettings {
                         text_wrapping: false.into(),
-                        blinking_cursor: false.into()
+                        blinking_cursor: false.into(),
+                        parameter_hints: true.into(),
+                        value_hints: true.into()
                     },
                     project: ProjectSettings {
                         directory: "/Users/macinatormax/Documents/kittycad-modeling-projects".into(),
@@ -731,6 +780,7 @@ defaultProjectName = "projects-$nnn"
                     command_bar: CommandBarSettings {
                         include_settings: false.into()
                     },
+                    telemetry: Default::default(),
                 }
             }
         );
@@ -800,7 +850,9 @@ projectDirectory = "/Users/macinatormax/Documents/kittycad-modeling-projects""#;
                     },
                     text_editor: TextEditorSettings {
                         text_wrapping: true.into(),
-                        blinking_cursor: true.into()
+                        blinking_cursor: true.into(),
+                        parameter_hints: true.into(),
+                        value_hints: true.into()
                     },
                     project: ProjectSettings {
                         directory: "/Users/macinatormax/Documents/kittycad-modeling-projects".into(),
@@ -809,6 +861,7 @@ projectDirectory = "/Users/macinatormax/Documents/kittycad-modeling-projects""#;
                     command_bar: CommandBarSettings {
                         include_settings: true.into()
                     },
+                    telemetry: Default::default(),
                 }
             }
         );
@@ -841,6 +894,26 @@ directory = "/Users/macinatormax/Documents/kittycad-modeling-projects"
         assert_eq!(parsed, Configuration::default());
     }
 
+    #[test]
+    fn test_settings_telemetry() {
+        let settings_file = r#"[settings.telemetry]
+level = "metadata_only"
+local_path = "/tmp/kcl-telemetry.jsonl"
+"#;
+
+        let parsed = toml::from_str::<Configuration>(settings_file).unwrap();
+        assert_eq!(
+            parsed.settings.telemetry,
+            TelemetrySettings {
+                level: TelemetryLevel::MetadataOnly,
+                local_path: Some("/tmp/kcl-telemetry.jsonl".into()),
+            }
+        );
+
+        // Telemetry is off by default.
+        assert_eq!(Configuration::default().settings.telemetry.level, TelemetryLevel::Off);
+    }
+
     #[test]
     fn test_color_validation() {
         let color = AppColor(360.0);
@@ -136,7 +136,9 @@ includeSettings = false
                     },
                     text_editor: TextEditorSettings {
                         text_wrapping: false.into(),
-                        blinking_cursor: false.into()
+                        blinking_cursor: false.into(),
+                        parameter_hints: true.into(),
+                        value_hints: true.into()
                     },
                     command_bar: CommandBarSettings {
                         include_settings: false.into()
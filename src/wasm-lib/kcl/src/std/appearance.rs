@@ -0,0 +1,236 @@
+//! Standard library appearances and materials.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use kcmc::{each_cmd as mcmd, shared::Color, ModelingCmd};
+use kittycad_modeling_cmds as kcmc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{KclError, KclErrorDetails},
+    executor::{ExecState, KclValue, Solid},
+    std::{transform::SolidSetOrImportedGeometry, Args},
+};
+
+/// How a solid looks.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct AppearanceData {
+    /// The color, as a hex string like `"#ff8800"`, with an optional alpha like `"#ff880080"`.
+    pub color: String,
+    /// How metallic the surface is, from 0 to 100. Defaults to 0.
+    #[serde(default)]
+    pub metalness: Option<f64>,
+    /// How rough the surface is, from 0 to 100. Defaults to 0.
+    #[serde(default)]
+    pub roughness: Option<f64>,
+}
+
+/// What a solid is made of.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialData {
+    /// The name of the material.
+    pub name: String,
+    /// The density of the material, in kilograms per cubic meter.
+    pub density: f64,
+}
+
+/// Set how solids or imported geometry look.
+pub async fn appearance(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, geometry): (AppearanceData, SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_appearance(data, geometry, exec_state, &args).await?;
+    Ok(geometry.into())
+}
+
+/// Set the color, metalness and roughness of solids or imported geometry.
+///
+/// The engine renders the geometry with them, so they show in snapshots
+/// and are written to exports which carry materials, like glTF.
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(2, %)
+///   |> appearance({
+///        color: "#b0b8c0",
+///        metalness: 90,
+///        roughness: 30
+///      }, %)
+/// ```
+///
+/// ```no_run,no_image
+/// // Half see-through.
+/// const cover = startSketchOn('XY')
+///   |> circle({ center: [0, 0], radius: 10 }, %)
+///   |> extrude(1, %)
+///   |> appearance({ color: "#3080ff80" }, %)
+/// ```
+#[stdlib {
+    name = "appearance",
+}]
+async fn inner_appearance(
+    data: AppearanceData,
+    geometry: SolidSetOrImportedGeometry,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    let color = parse_color(&data.color).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: format!(
+                "Expected a color like \"#ff8800\" or \"#ff880080\", found `{}`",
+                data.color
+            ),
+            source_ranges: vec![args.source_range],
+        })
+    })?;
+    let mut fractions = vec![];
+    for (name, value) in [("metalness", data.metalness), ("roughness", data.roughness)] {
+        let value = value.unwrap_or_default();
+        if !(0.0..=100.0).contains(&value) {
+            return Err(KclError::Semantic(KclErrorDetails {
+                message: format!("Expected {} to be from 0 to 100, found {}", name, value),
+                source_ranges: vec![args.source_range],
+            }));
+        }
+        fractions.push(value as f32 / 100.0);
+    }
+
+    let ids = match &geometry {
+        SolidSetOrImportedGeometry::SolidSet(solid_set) => Vec::<Box<Solid>>::from(solid_set.clone())
+            .iter()
+            .map(|solid| solid.id)
+            .collect(),
+        SolidSetOrImportedGeometry::ImportedGeometry(geometry) => vec![geometry.id],
+    };
+    for object_id in ids {
+        args.batch_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::ObjectSetMaterialParamsPbr {
+                object_id,
+                color,
+                metalness: fractions[0],
+                roughness: fractions[1],
+                ambient_occlusion: 0.0,
+            }),
+        )
+        .await?;
+    }
+
+    Ok(match geometry {
+        SolidSetOrImportedGeometry::SolidSet(solid_set) => {
+            let mut solids: Vec<Box<Solid>> = solid_set.into();
+            for solid in solids.iter_mut() {
+                solid.appearance = Some(data.clone());
+            }
+            SolidSetOrImportedGeometry::SolidSet(solids.into())
+        }
+        SolidSetOrImportedGeometry::ImportedGeometry(mut geometry) => {
+            geometry.appearance = Some(data);
+            SolidSetOrImportedGeometry::ImportedGeometry(geometry)
+        }
+    })
+}
+
+/// Say what solids or imported geometry are made of.
+pub async fn material(_exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, geometry): (MaterialData, SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_material(data, geometry, &args).await?;
+    Ok(geometry.into())
+}
+
+/// Say what solids or imported geometry are made of.
+///
+/// The material's density is used by `mass`, and its name is the material
+/// listed in the bill of materials for parts which don't give one.
+///
+/// The engine doesn't know about materials, so exports from the modeling
+/// app don't have them. glTF files exported by `execute_and_export` in the
+/// Python bindings list them in their `extras`, as `kclMaterials`, with the
+/// id of each solid made of them.
+///
+/// ```no_run,no_image
+/// const bracket = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 3], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///   |> material({ name: "Aluminum 6061-T6", density: 2700 }, %)
+/// ```
+#[stdlib {
+    name = "material",
+}]
+async fn inner_material(
+    data: MaterialData,
+    geometry: SolidSetOrImportedGeometry,
+    args: &Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    if data.name.trim().is_empty() {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "A material needs a name".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+    if !data.density.is_finite() || data.density <= 0.0 {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: format!("Expected the density to be more than 0, found {}", data.density),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+
+    Ok(match geometry {
+        SolidSetOrImportedGeometry::SolidSet(solid_set) => {
+            let mut solids: Vec<Box<Solid>> = solid_set.into();
+            for solid in solids.iter_mut() {
+                solid.material = Some(data.clone());
+            }
+            SolidSetOrImportedGeometry::SolidSet(solids.into())
+        }
+        SolidSetOrImportedGeometry::ImportedGeometry(mut geometry) => {
+            geometry.material = Some(data);
+            SolidSetOrImportedGeometry::ImportedGeometry(geometry)
+        }
+    })
+}
+
+/// A color from a hex string: `#rrggbb` or `#rrggbbaa`.
+fn parse_color(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#')?;
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+        return None;
+    }
+    let mut channels = [1.0; 4];
+    for (i, channel) in channels.iter_mut().enumerate().take(hex.len() / 2) {
+        *channel = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()? as f32 / 255.0;
+    }
+    let [r, g, b, a] = channels;
+    Some(Color { r, g, b, a })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        let color = parse_color("#ff0080").unwrap();
+        assert_eq!((color.r, color.g, color.b, color.a), (1.0, 0.0, 128.0 / 255.0, 1.0));
+        assert_eq!(parse_color("#00000000").unwrap().a, 0.0);
+        for bad in ["ff0080", "#ff008", "#gg0080", "#ff00800"] {
+            assert!(parse_color(bad).is_none(), "{}", bad);
+        }
+    }
+}
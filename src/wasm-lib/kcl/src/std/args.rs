@@ -237,7 +237,17 @@ impl Args {
         Ok(numbers)
     }
 
-    pub(crate) fn get_pattern_transform_args(&self) -> Result<(u32, FnAsArg<'_>, SolidSet), KclError> {
+    pub(crate) fn get_pattern_transform_args(
+        &self,
+    ) -> Result<
+        (
+            u32,
+            FnAsArg<'_>,
+            SolidSet,
+            Option<super::patterns::PatternTransformData>,
+        ),
+        KclError,
+    > {
         FromArgs::from_args(self, 0)
     }
 
@@ -267,6 +277,10 @@ impl Args {
         FromArgs::from_args(self, 0)
     }
 
+    pub(crate) fn get_text_args(&self) -> Result<(String, crate::std::text::TextData, SketchSurface), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
     pub(crate) fn get_sketches(&self) -> Result<(SketchSet, Sketch), KclError> {
         FromArgs::from_args(self, 0)
     }
@@ -334,6 +348,24 @@ impl Args {
         FromArgs::from_args(self, 0)
     }
 
+    pub(crate) fn get_pattern_along_path_args(
+        &self,
+    ) -> Result<(crate::std::patterns::PatternAlongPathData, Sketch, SolidSet), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_pattern_grid_on_face_args(
+        &self,
+    ) -> Result<(crate::std::patterns::PatternGridData, Box<Solid>, FaceTag, SolidSet), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_mirror_3d_args(
+        &self,
+    ) -> Result<(SolidSet, crate::std::sketch::SketchData, Option<FaceTag>), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
     pub(crate) fn get_data_and_solid_set<'a, T>(&'a self) -> Result<(T, SolidSet), KclError>
     where
         T: serde::de::DeserializeOwned + FromKclValue<'a> + Sized,
@@ -341,6 +373,46 @@ impl Args {
         FromArgs::from_args(self, 0)
     }
 
+    pub(crate) fn get_data_and_solid_set_or_imported_geometry<'a, T>(
+        &'a self,
+    ) -> Result<(T, crate::std::transform::SolidSetOrImportedGeometry), KclError>
+    where
+        T: serde::de::DeserializeOwned + FromKclValue<'a> + Sized,
+    {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_solid_and_face_tag(&self) -> Result<(Box<Solid>, FaceTag), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_solid_and_two_face_tags(&self) -> Result<(Box<Solid>, FaceTag, FaceTag), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_part_and_optional_data(
+        &self,
+    ) -> Result<(FnAsArg<'_>, Option<crate::std::assembly::InstanceData>), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_solid_and_optional_data<'a, T>(&'a self) -> Result<(Box<Solid>, Option<T>), KclError>
+    where
+        T: serde::de::DeserializeOwned + FromKclValue<'a> + Sized,
+    {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_solid_and_edge(&self) -> Result<(Box<Solid>, crate::std::fillet::EdgeReference), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
+    pub(crate) fn get_two_measure_entities(
+        &self,
+    ) -> Result<(crate::std::measure::MeasureEntity, crate::std::measure::MeasureEntity), KclError> {
+        FromArgs::from_args(self, 0)
+    }
+
     pub(crate) fn get_data_and_solid<'a, T>(&'a self) -> Result<(T, Box<Solid>), KclError>
     where
         T: serde::de::DeserializeOwned + FromKclValue<'a> + Sized,
@@ -357,6 +429,13 @@ impl Args {
         FromArgs::from_args(self, 0)
     }
 
+    pub(crate) fn get_data_and_solid_and_face<'a, T>(&'a self) -> Result<(T, Box<Solid>, FaceTag), KclError>
+    where
+        T: serde::de::DeserializeOwned + FromKclValue<'a> + Sized,
+    {
+        FromArgs::from_args(self, 0)
+    }
+
     pub(crate) fn get_tag_to_number_sketch(&self) -> Result<(TagIdentifier, f64, Sketch), KclError> {
         FromArgs::from_args(self, 0)
     }
@@ -660,6 +739,9 @@ impl_from_arg_via_json!(super::patterns::LinearPattern3dData);
 impl_from_arg_via_json!(super::patterns::CircularPattern3dData);
 impl_from_arg_via_json!(super::patterns::LinearPattern2dData);
 impl_from_arg_via_json!(super::patterns::CircularPattern2dData);
+impl_from_arg_via_json!(super::patterns::PatternAlongPathData);
+impl_from_arg_via_json!(super::patterns::PatternGridData);
+impl_from_arg_via_json!(super::patterns::PatternTransformData);
 impl_from_arg_via_json!(super::helix::HelixData);
 impl_from_arg_via_json!(super::shell::ShellData);
 impl_from_arg_via_json!(super::fillet::FilletData);
@@ -670,8 +752,22 @@ impl_from_arg_via_json!(crate::std::polar::PolarCoordsData);
 impl_from_arg_via_json!(crate::std::loft::LoftData);
 impl_from_arg_via_json!(crate::std::planes::StandardPlane);
 impl_from_arg_via_json!(crate::std::mirror::Mirror2dData);
+impl_from_arg_via_json!(crate::std::text::TextData);
+impl_from_arg_via_json!(crate::std::holes::HoleData);
+impl_from_arg_via_json!(crate::std::transform::RotateData);
+impl_from_arg_via_json!(crate::std::datums::DatumPointData);
+impl_from_arg_via_json!(crate::std::datums::DatumAxisData);
+impl_from_arg_via_json!(crate::std::datums::PlaneAtAngleData);
+impl_from_arg_via_json!(crate::std::transform::ScaleData);
+impl_from_arg_via_json!(crate::std::query::EdgeQuery);
+impl_from_arg_via_json!(crate::std::query::FaceQuery);
+impl_from_arg_via_json!(crate::std::assembly::InstanceData);
+impl_from_arg_via_json!(crate::bom::PartMetadata);
+impl_from_arg_via_json!(crate::std::appearance::AppearanceData);
+impl_from_arg_via_json!(crate::std::appearance::MaterialData);
 impl_from_arg_via_json!(Sketch);
 impl_from_arg_via_json!(FaceTag);
+impl_from_arg_via_json!(super::fillet::EdgeReference);
 impl_from_arg_via_json!(String);
 impl_from_arg_via_json!(crate::ast::types::KclNone);
 impl_from_arg_via_json!(u32);
@@ -681,6 +777,7 @@ impl_from_arg_via_json!(bool);
 
 impl_from_arg_for_array!(2);
 impl_from_arg_for_array!(3);
+impl_from_arg_for_array!(4);
 
 impl<'a> FromKclValue<'a> for SketchSet {
     fn from_mem_item(arg: &'a KclValue) -> Option<Self> {
@@ -715,6 +812,25 @@ impl<'a> FromKclValue<'a> for SolidSet {
         arg.get_solid_set().ok()
     }
 }
+
+impl<'a> FromKclValue<'a> for crate::std::transform::SolidSetOrImportedGeometry {
+    fn from_mem_item(arg: &'a KclValue) -> Option<Self> {
+        match arg {
+            KclValue::ImportedGeometry(geometry) => Some(Self::ImportedGeometry(geometry.clone())),
+            _ => arg.get_solid_set().ok().map(Self::SolidSet),
+        }
+    }
+}
+
+impl<'a> FromKclValue<'a> for crate::std::measure::MeasureEntity {
+    fn from_mem_item(arg: &'a KclValue) -> Option<Self> {
+        match arg {
+            KclValue::Solid(solid) => Some(Self::Solid(solid.to_owned())),
+            _ => arg.get_tag_identifier().ok().map(|tag| Self::Tag(Box::new(tag))),
+        }
+    }
+}
+
 impl<'a> FromKclValue<'a> for SketchOrSurface {
     fn from_mem_item(arg: &'a KclValue) -> Option<Self> {
         match arg {
@@ -0,0 +1,204 @@
+//! Standard library assemblies.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    assembly::Mate,
+    errors::{KclError, KclErrorDetails},
+    executor::{ExecState, KclValue, Solid, Transform3d},
+    function_param::FunctionParam,
+    std::{
+        args::FromKclValue,
+        transform::{apply_transform, transform_solid, SolidSetOrImportedGeometry},
+        Args, FnAsArg,
+    },
+};
+
+/// Data for an instance of a part.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceData {
+    /// The name of the instance, unique among its siblings. Defaults to the part's name and a number.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A 4x4 transform, as rows, to move the part by from where it builds it.
+    #[serde(default)]
+    pub transform: Option<[[f64; 4]; 4]>,
+    /// How to put the instance in place relative to other geometry, after it's been moved by
+    /// `transform`.
+    #[serde(default)]
+    pub mate: Option<Mate>,
+}
+
+/// Make an instance of a part.
+pub async fn instance(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (part, data): (FnAsArg<'_>, Option<InstanceData>) = args.get_part_and_optional_data()?;
+    let part = FunctionParam {
+        inner: part.func,
+        fn_expr: part.expr,
+        meta: vec![args.source_range.into()],
+        ctx: args.ctx.clone(),
+        memory: *part.memory,
+    };
+
+    let geometry = inner_instance(part, data, exec_state, &args).await?;
+    Ok(geometry.into())
+}
+
+/// Build a part imported from another file, and put it in place.
+///
+/// Any function imported from another file which takes no arguments and
+/// returns solids or imported geometry is a part. Each instance builds it
+/// again, moves it by `transform` and then by `mate`, and is recorded in
+/// the program's assembly. Parts which make instances of other parts are
+/// subassemblies, with those instances as their children.
+///
+/// A `coincident` mate puts a planar face of the instance against a planar
+/// face of another solid. Faces of the part are given by `'start'`, `'end'`
+/// or the name of their tag in the part, as tags made in the part's
+/// function can't be used outside it. A `concentric` mate lines an axis of
+/// the part up with another axis.
+///
+/// ```no_run,no_image
+/// import plate, pin from "parts.kcl"
+///
+/// const base = instance(plate, { name: "base" })
+///
+/// // Stand a pin on top of the plate.
+/// const pin1 = instance(pin, {
+///   name: "pin 1",
+///   mate: {
+///     type: "coincident",
+///     face: 'start',
+///     to: base,
+///     toFace: 'end'
+///   }
+/// })
+///
+/// // And another beside it, moved along first.
+/// const pin2 = instance(pin, {
+///   name: "pin 2",
+///   transform: [[1, 0, 0, 20], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]],
+///   mate: {
+///     type: "coincident",
+///     face: 'start',
+///     to: base,
+///     toFace: 'end'
+///   }
+/// })
+/// ```
+///
+/// ```no_run,no_image
+/// import shaft from "shaft.kcl"
+///
+/// // Line the shaft, built along the X axis, up with a bore along Z.
+/// const driveShaft = instance(shaft, {
+///   mate: {
+///     type: "concentric",
+///     axis: { origin: [0, 0, 0], direction: [1, 0, 0] },
+///     to: { origin: [40, 25, 0], direction: [0, 0, 1] }
+///   }
+/// })
+/// ```
+#[stdlib {
+    name = "instance",
+}]
+async fn inner_instance<'a>(
+    part: FunctionParam<'a>,
+    data: Option<InstanceData>,
+    exec_state: &mut ExecState,
+    args: &'a Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    let data = data.unwrap_or_default();
+    let definition = exec_state.assembly.part(&part.fn_expr).cloned().ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "Expected a part: a function imported from another file".to_string(),
+            source_ranges: vec![args.source_range],
+        })
+    })?;
+    let name = data.name.unwrap_or_else(|| {
+        let made = exec_state
+            .assembly
+            .instances
+            .iter()
+            .filter(|instance| instance.part == definition.name)
+            .count();
+        format!("{} {}", definition.name, made + 1)
+    });
+    if exec_state
+        .assembly
+        .instances
+        .iter()
+        .any(|instance| instance.name == name)
+    {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: format!("There's already an instance named `{}`", name),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+
+    // Instances made while the part is built are its children.
+    let siblings = std::mem::take(&mut exec_state.assembly.instances);
+    let built = part.call(exec_state, vec![]).await;
+    let mut children = std::mem::replace(&mut exec_state.assembly.instances, siblings);
+    let geometry = built?
+        .as_ref()
+        .and_then(SolidSetOrImportedGeometry::from_mem_item)
+        .ok_or_else(|| {
+            KclError::Semantic(KclErrorDetails {
+                message: format!(
+                    "Expected the part `{}` to return solids or imported geometry",
+                    definition.name
+                ),
+                source_ranges: vec![args.source_range],
+            })
+        })?;
+
+    let placed = data.transform.map(Transform3d).unwrap_or_default();
+    let transform = match &data.mate {
+        Some(mate) => {
+            // Faces are mated where they are once the part has been moved by `transform`.
+            let solid = match &geometry {
+                SolidSetOrImportedGeometry::SolidSet(solid_set) => Vec::<Box<Solid>>::from(solid_set.clone())
+                    .into_iter()
+                    .next()
+                    .map(|mut solid| {
+                        transform_solid(&mut solid, &placed);
+                        solid
+                    }),
+                SolidSetOrImportedGeometry::ImportedGeometry(_) => None,
+            };
+            placed.then(&mate.solve(solid.as_deref(), &placed, args.source_range)?)
+        }
+        None => placed,
+    };
+    let geometry = if transform.is_identity() {
+        geometry
+    } else {
+        apply_transform(transform, geometry, exec_state, args).await?
+    };
+    for child in &mut children {
+        child.moved(&transform);
+    }
+
+    let ids = match &geometry {
+        SolidSetOrImportedGeometry::SolidSet(solid_set) => Vec::<Box<Solid>>::from(solid_set.clone())
+            .iter()
+            .map(|solid| solid.id)
+            .collect(),
+        SolidSetOrImportedGeometry::ImportedGeometry(geometry) => vec![geometry.id],
+    };
+    exec_state.assembly.instances.push(crate::assembly::Instance {
+        name,
+        part: definition.name,
+        ids,
+        transform,
+        children,
+        meta: vec![args.source_range.into()],
+    });
+    Ok(geometry)
+}
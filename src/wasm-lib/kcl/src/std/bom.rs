@@ -0,0 +1,84 @@
+//! Standard library part metadata, for bills of materials.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+
+use crate::{
+    bom::PartMetadata,
+    errors::{KclError, KclErrorDetails},
+    executor::{ExecState, KclValue, Solid},
+    std::{transform::SolidSetOrImportedGeometry, Args},
+};
+
+/// Say what part some geometry is.
+pub async fn part_info(_exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, geometry): (PartMetadata, SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_part_info(data, geometry, &args).await?;
+    Ok(geometry.into())
+}
+
+/// Say what part solids or imported geometry are, so they're listed in the
+/// bill of materials.
+///
+/// Each solid is counted as `quantity` of the part, which defaults to 1.
+/// Copies made by patterns, and solids made by functions imported from
+/// other files, are counted too.
+///
+/// ```no_run,no_image
+/// const bracket = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 3], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///   |> partInfo({
+///        name: "Mounting bracket",
+///        partNumber: "BR-100",
+///        material: "Aluminum 6061-T6"
+///      }, %)
+///
+/// // Four of these in the bill of materials.
+/// const feet = startSketchOn('XY')
+///   |> circle({ center: [2, 2], radius: 1 }, %)
+///   |> extrude(1, %)
+///   |> partInfo({ name: "Rubber foot", material: "EPDM" }, %)
+///   |> patternLinear3d({ axis: [1, 0, 0], instances: 4, distance: 5 }, %)
+/// ```
+///
+/// ```no_run,no_image
+/// // A bag of screws, modeled once.
+/// const screws = import("tests/inputs/cube.step")
+///   |> partInfo({ name: "M3x8 screw", partNumber: "ISO 7380", quantity: 12 }, %)
+/// ```
+#[stdlib {
+    name = "partInfo",
+}]
+async fn inner_part_info(
+    data: PartMetadata,
+    geometry: SolidSetOrImportedGeometry,
+    args: &Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    if data.name.trim().is_empty() {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "A part needs a name".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+
+    Ok(match geometry {
+        SolidSetOrImportedGeometry::SolidSet(solid_set) => {
+            let mut solids: Vec<Box<Solid>> = solid_set.into();
+            for solid in solids.iter_mut() {
+                solid.part = Some(data.clone());
+            }
+            SolidSetOrImportedGeometry::SolidSet(solids.into())
+        }
+        SolidSetOrImportedGeometry::ImportedGeometry(mut geometry) => {
+            geometry.part = Some(data);
+            SolidSetOrImportedGeometry::ImportedGeometry(geometry)
+        }
+    })
+}
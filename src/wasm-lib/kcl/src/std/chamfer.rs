@@ -41,6 +41,10 @@ pub async fn chamfer(exec_state: &mut ExecState, args: Args) -> Result<KclValue,
 /// a fillet will blend the transition along an edge, rather than cut
 /// a sharp, straight transitional edge.
 ///
+/// The engine cuts the same length back along both faces of the edge, so
+/// chamfers with a different distance on each face, or a distance and an
+/// angle, can't be made yet.
+///
 /// ```no_run
 /// // Chamfer a mounting plate.
 /// const width = 20
@@ -126,6 +130,7 @@ async fn inner_chamfer(
         }));
     }
 
+    exec_state.plain_extrusions.remove(&solid.id);
     let mut solid = solid.clone();
     for edge_tag in data.tags {
         let edge_id = match edge_tag {
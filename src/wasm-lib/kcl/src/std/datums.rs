@@ -0,0 +1,615 @@
+//! Standard library construction geometry: datum points, axes and planes.
+//!
+//! Datums are plain values, they aren't sent to the engine. Planes come out as
+//! the same kind of value as `offsetPlane`, so they work with `startSketchOn`
+//! and `mirror3d`, points are `[x, y, z]` arrays, and axes are
+//! `{ origin, direction }` objects which `revolve` takes as its axis.
+
+use derive_docs::stdlib;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{KclError, KclErrorDetails},
+    executor::{
+        ExecState, KclValue, Metadata, Path, Plane, Point3d, Sketch, SketchSurface, Solid, SourceRange, Transform3d,
+        UserVal,
+    },
+    std::{
+        sketch::{FaceTag, PlaneData, StartOrEnd},
+        utils::{CurveSegment, PathCurve},
+        Args,
+    },
+};
+
+/// A line through space, for revolving or patterning around.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Axis3d {
+    /// A point on the axis.
+    pub origin: [f64; 3],
+    /// The direction of the axis.
+    pub direction: [f64; 3],
+}
+
+/// Data for a datum axis.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum DatumAxisData {
+    /// Through two points, pointing from the first to the second.
+    Through {
+        /// The two points.
+        through: [[f64; 3]; 2],
+    },
+    /// Through a point, in a direction.
+    Direction(Axis3d),
+}
+
+/// Data for a datum point.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum DatumPointData {
+    /// A point in global coordinates.
+    Point([f64; 3]),
+    /// A point on a plane, in the plane's own coordinates.
+    OnPlane {
+        /// The plane.
+        plane: PlaneData,
+        /// Where on the plane, along its X and Y axes.
+        at: [f64; 2],
+    },
+}
+
+/// Data for a plane at an angle to another plane.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaneAtAngleData {
+    /// The plane to start from.
+    pub plane: PlaneData,
+    /// The axis to turn the plane about.
+    pub axis: Axis3d,
+    /// The angle to turn the plane by, in degrees, counterclockwise looking down the axis.
+    pub angle: f64,
+}
+
+/// A plane's origin and axes, in global coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Frame {
+    pub origin: Point3d,
+    pub x_axis: Point3d,
+    pub y_axis: Point3d,
+    pub z_axis: Point3d,
+}
+
+impl From<Frame> for PlaneData {
+    fn from(frame: Frame) -> Self {
+        PlaneData::Plane {
+            origin: Box::new(frame.origin),
+            x_axis: Box::new(frame.x_axis),
+            y_axis: Box::new(frame.y_axis),
+            z_axis: Box::new(frame.z_axis),
+        }
+    }
+}
+
+impl From<&Plane> for Frame {
+    fn from(plane: &Plane) -> Self {
+        Self {
+            origin: plane.origin,
+            x_axis: plane.x_axis,
+            y_axis: plane.y_axis,
+            z_axis: plane.z_axis,
+        }
+    }
+}
+
+impl Frame {
+    pub(crate) fn from_plane_data(plane: PlaneData, exec_state: &mut ExecState) -> Self {
+        Self::from(&Plane::from_plane_data(plane, exec_state))
+    }
+
+    /// A point given in the plane's coordinates, in global coordinates.
+    pub(crate) fn point(&self, at: [f64; 2]) -> Point3d {
+        add(self.origin, add(mul(self.x_axis, at[0]), mul(self.y_axis, at[1])))
+    }
+
+    /// A direction given in the plane's coordinates, in global coordinates.
+    pub(crate) fn vector(&self, v: [f64; 2]) -> Point3d {
+        add(mul(self.x_axis, v[0]), mul(self.y_axis, v[1]))
+    }
+}
+
+fn user_val<T: Serialize>(value: T, source_range: SourceRange) -> KclValue {
+    KclValue::UserVal(UserVal::new(vec![Metadata { source_range }], value))
+}
+
+/// Make a datum point.
+pub async fn datum_point(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let data: DatumPointData = args.get_data()?;
+
+    let point = inner_datum_point(data, exec_state).await?;
+    Ok(user_val(point, args.source_range))
+}
+
+/// Make a datum point, either from global coordinates or from a position
+/// on a plane. The point is an `[x, y, z]` array, so it can be used anywhere
+/// a 3D point is expected, such as the `center` of `patternCircular3d` or
+/// as one of the points of `planeThroughPoints`.
+///
+/// ```no_run,no_image
+/// const corner = datumPoint({ plane: offsetPlane('XY', 10), at: [5, 5] })
+///
+/// const peg = startSketchOn('XY')
+///   |> circle({ center: [20, 0], radius: 1 }, %)
+///   |> extrude(5, %)
+///   |> patternCircular3d({
+///        axis: [0, 0, 1],
+///        center: corner,
+///        instances: 6,
+///        arcDegrees: 360,
+///        rotateDuplicates: true
+///      }, %)
+/// ```
+#[stdlib {
+    name = "datumPoint",
+}]
+async fn inner_datum_point(data: DatumPointData, exec_state: &mut ExecState) -> Result<[f64; 3], KclError> {
+    let point = match data {
+        DatumPointData::Point(point) => return Ok(point),
+        DatumPointData::OnPlane { plane, at } => Frame::from_plane_data(plane, exec_state).point(at),
+    };
+    Ok([point.x, point.y, point.z])
+}
+
+/// Make a datum axis.
+pub async fn datum_axis(_exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let data: DatumAxisData = args.get_data()?;
+
+    let axis = inner_datum_axis(data, &args).await?;
+    Ok(user_val(axis, args.source_range))
+}
+
+/// Make a datum axis, either through two points or through a point in a
+/// direction.
+///
+/// The axis is an object with the `origin` and the unit length `direction`
+/// of the line, which `revolve` takes as its `axis` and `planeAtAngle`
+/// turns planes about.
+///
+/// ```no_run,no_image
+/// // Revolve a profile about an axis which isn't in the sketch's plane.
+/// const hinge = datumAxis({ through: [[0, -5, 0], [0, -5, 10]] })
+///
+/// const part = startSketchOn('XZ')
+///   |> startProfileAt([2, 0], %)
+///   |> line([2, 0], %)
+///   |> line([0, 4], %)
+///   |> line([-2, 0], %)
+///   |> close(%)
+///   |> revolve({ axis: hinge, angle: 90 }, %)
+/// ```
+///
+/// ```no_run,no_image
+/// const axis = datumAxis({ origin: [10, 0, 0], direction: [0, 0, 1] })
+///
+/// const boss = startSketchOn('XY')
+///   |> circle({ center: [15, 0], radius: 1 }, %)
+///   |> extrude(2, %)
+///   |> patternCircular3d({
+///        axis: axis.direction,
+///        center: axis.origin,
+///        instances: 4,
+///        arcDegrees: 360,
+///        rotateDuplicates: true
+///      }, %)
+/// ```
+#[stdlib {
+    name = "datumAxis",
+}]
+async fn inner_datum_axis(data: DatumAxisData, args: &Args) -> Result<Axis3d, KclError> {
+    let (origin, direction) = match data {
+        DatumAxisData::Through { through: [a, b] } => (a, [b[0] - a[0], b[1] - a[1], b[2] - a[2]]),
+        DatumAxisData::Direction(axis) => (axis.origin, axis.direction),
+    };
+    let direction = normalize(point3d(direction)).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "A datum axis needs two different points, or a direction which isn't the zero vector".to_string(),
+            source_ranges: vec![args.source_range],
+        })
+    })?;
+    Ok(Axis3d {
+        origin,
+        direction: [direction.x, direction.y, direction.z],
+    })
+}
+
+/// Make a plane through three points.
+pub async fn plane_through_points(_exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let points: [[f64; 3]; 3] = args.get_data()?;
+
+    let plane = inner_plane_through_points(points, &args).await?;
+    Ok(user_val(plane, args.source_range))
+}
+
+/// Make a plane through three points.
+///
+/// The plane's origin is the first point, its X axis points toward the
+/// second, and its normal is on the side the points go counterclockwise
+/// around.
+///
+/// ```no_run,no_image
+/// // Sketch on a sloped plane.
+/// const slope = planeThroughPoints([[0, 0, 0], [10, 0, 0], [0, 10, 5]])
+///
+/// const part = startSketchOn(slope)
+///   |> circle({ center: [5, 5], radius: 2 }, %)
+///   |> extrude(3, %)
+/// ```
+#[stdlib {
+    name = "planeThroughPoints",
+}]
+async fn inner_plane_through_points(points: [[f64; 3]; 3], args: &Args) -> Result<PlaneData, KclError> {
+    let frame = frame_through_points(points.map(point3d)).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "The three points of a plane cannot all be on one line".to_string(),
+            source_ranges: vec![args.source_range],
+        })
+    })?;
+    Ok(frame.into())
+}
+
+/// Make a plane at an angle to another plane.
+pub async fn plane_at_angle(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let data: PlaneAtAngleData = args.get_data()?;
+
+    let plane = inner_plane_at_angle(data, exec_state, &args).await?;
+    Ok(user_val(plane, args.source_range))
+}
+
+/// Make a plane by turning another plane about an axis.
+///
+/// ```no_run,no_image
+/// // A plane tilted 30 degrees from XY about the X axis.
+/// const tilted = planeAtAngle({
+///   plane: 'XY',
+///   axis: datumAxis({ origin: [0, 0, 0], direction: [1, 0, 0] }),
+///   angle: 30
+/// })
+///
+/// const part = startSketchOn(tilted)
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(2, %)
+/// ```
+#[stdlib {
+    name = "planeAtAngle",
+}]
+async fn inner_plane_at_angle(
+    data: PlaneAtAngleData,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<PlaneData, KclError> {
+    let frame = Frame::from_plane_data(data.plane, exec_state);
+    let rotation = Transform3d::rotation(point3d(data.axis.direction), data.angle, point3d(data.axis.origin))
+        .ok_or_else(|| {
+            KclError::Semantic(KclErrorDetails {
+                message: "The axis to turn the plane about cannot be the zero vector".to_string(),
+                source_ranges: vec![args.source_range],
+            })
+        })?;
+    Ok(Frame {
+        origin: rotation.transform_point(frame.origin),
+        x_axis: rotation.transform_vector(frame.x_axis),
+        y_axis: rotation.transform_vector(frame.y_axis),
+        z_axis: rotation.transform_vector(frame.z_axis),
+    }
+    .into())
+}
+
+/// Make a plane normal to a sketch's path.
+pub async fn plane_normal_to_path(_exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (at, sketch): (f64, Sketch) = args.get_data_and_sketch()?;
+
+    let plane = inner_plane_normal_to_path(at, sketch, &args).await?;
+    Ok(user_val(plane, args.source_range))
+}
+
+/// Make a plane normal to a sketch's path, at a fraction of the way along it.
+///
+/// `0` is the start of the path and `1` is its end. The plane's origin is
+/// on the path, its normal points along the path, and its Y axis is the
+/// normal of the plane the path was sketched on. This is where to sketch
+/// the profile for a sweep.
+///
+/// ```no_run,no_image
+/// const path = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([0, 20], %)
+///   |> tangentialArc({ radius: 10, offset: 90 }, %)
+///
+/// // A profile halfway along the path.
+/// const profile = startSketchOn(planeNormalToPath(0.5, path))
+///   |> circle({ center: [0, 0], radius: 2 }, %)
+/// ```
+#[stdlib {
+    name = "planeNormalToPath",
+}]
+async fn inner_plane_normal_to_path(at: f64, sketch: Sketch, args: &Args) -> Result<PlaneData, KclError> {
+    if !(0.0..=1.0).contains(&at) {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: format!("Expected a fraction of the path between 0 and 1, found `{}`", at),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+    let SketchSurface::Plane(plane) = &sketch.on else {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "Planes normal to a path sketched on a face are not supported yet".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    let curve = PathCurve::from_sketch(&sketch);
+    if curve.segments.is_empty() {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "Expected a sketch with at least one segment which isn't zero length".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+    let distance = at * curve.length();
+    let (point, tangent) = (curve.point_at(distance), curve.tangent_at(distance));
+
+    let sketch_frame = Frame::from(plane.as_ref());
+    let normal = normalize(sketch_frame.vector(tangent)).unwrap_or(sketch_frame.x_axis);
+    let y_axis = normalize(sketch_frame.z_axis).unwrap_or(sketch_frame.z_axis);
+    Ok(Frame {
+        origin: sketch_frame.point(point),
+        x_axis: cross(y_axis, normal),
+        y_axis,
+        z_axis: normal,
+    }
+    .into())
+}
+
+/// Make a plane on a face of a solid.
+pub async fn plane_on_face(_exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, face): (Box<Solid>, FaceTag) = args.get_solid_and_face_tag()?;
+
+    let plane = inner_plane_on_face(solid, face, &args).await?;
+    Ok(user_val(plane, args.source_range))
+}
+
+/// Make a plane coincident with a planar face of an extruded solid, given
+/// by the face's tag or `'start'`/`'end'`, the same way as in
+/// `startSketchOn`. The plane's normal points out of the solid.
+///
+/// Unlike sketching on the face itself, the plane doesn't depend on the
+/// face, so turning it with `planeAtAngle` or mirroring across it
+/// doesn't need the engine.
+///
+/// ```no_run,no_image
+/// const block = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %, $side)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// // Lean a plane 45 degrees out from the top face, about its front edge.
+/// const top = planeOnFace(block, 'end')
+/// const leaning = planeAtAngle({
+///   plane: top,
+///   axis: datumAxis({ origin: [0, 0, 10], direction: [1, 0, 0] }),
+///   angle: 45
+/// })
+///
+/// const mirrored = mirror3d(block, planeOnFace(block, side))
+/// ```
+#[stdlib {
+    name = "planeOnFace",
+}]
+async fn inner_plane_on_face(solid: Box<Solid>, face: FaceTag, args: &Args) -> Result<PlaneData, KclError> {
+    Ok(face_frame(&solid, &face, args.source_range)?.into())
+}
+
+/// The frame the solid's profile was sketched in, for `doing` something with the solid, like
+/// "Measuring".
+pub(crate) fn profile_frame(solid: &Solid, doing: &str, source_range: SourceRange) -> Result<Frame, KclError> {
+    let SketchSurface::Plane(plane) = &solid.sketch.on else {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: format!(
+                "{} a solid which was sketched on another face is not supported yet",
+                doing
+            ),
+            source_ranges: vec![source_range],
+        }));
+    };
+    Ok(Frame::from(plane.as_ref()))
+}
+
+/// The plane of a planar face of an extruded solid, with its normal pointing out of the solid.
+pub(crate) fn face_frame(solid: &Solid, face: &FaceTag, source_range: SourceRange) -> Result<Frame, KclError> {
+    let sketch_frame = profile_frame(solid, "Using the faces of", source_range)?;
+
+    match face {
+        FaceTag::StartOrEnd(StartOrEnd::Start) => Ok(Frame {
+            origin: sketch_frame.origin,
+            x_axis: sketch_frame.x_axis,
+            y_axis: mul(sketch_frame.y_axis, -1.0),
+            z_axis: mul(sketch_frame.z_axis, -1.0),
+        }),
+        FaceTag::StartOrEnd(StartOrEnd::End) => Ok(Frame {
+            origin: add(sketch_frame.origin, mul(sketch_frame.z_axis, solid.height)),
+            ..sketch_frame
+        }),
+        FaceTag::Tag(_) | FaceTag::Uuid(_) => {
+            let path = face.side_path(solid).ok_or_else(|| {
+                KclError::Type(KclErrorDetails {
+                    message: match face {
+                        FaceTag::Uuid(id) => format!("Expected a face of the solid with the id `{}`", id),
+                        _ => format!("Expected a face with the tag `{}`", face),
+                    },
+                    source_ranges: vec![source_range],
+                })
+            })?;
+            let base = match path {
+                Path::ToPoint { base }
+                | Path::Horizontal { base, .. }
+                | Path::AngledLineTo { base, .. }
+                | Path::Base { base } => base,
+                _ => {
+                    return Err(KclError::Type(KclErrorDetails {
+                        message: format!("The face `{}` is not planar", face),
+                        source_ranges: vec![source_range],
+                    }));
+                }
+            };
+            // The side face of a straight segment is perpendicular to the sketch plane, along
+            // the segment and up the extrusion. Going counterclockwise around the profile the
+            // solid is on the left, so for a clockwise profile walk the segment backwards to
+            // keep the normal pointing out. A mirrored solid's sketch plane is left-handed,
+            // which turns the profile the other way round.
+            let clockwise = solid
+                .sketch
+                .paths
+                .iter()
+                .map(|path| CurveSegment::from(path).signed_area())
+                .sum::<f64>()
+                < 0.0;
+            let left_handed = dot(cross(sketch_frame.x_axis, sketch_frame.y_axis), sketch_frame.z_axis) < 0.0;
+            let (from, to) = if clockwise != left_handed {
+                (base.to, base.from)
+            } else {
+                (base.from, base.to)
+            };
+            let along = sketch_frame.vector([to[0] - from[0], to[1] - from[1]]);
+            let x_axis = normalize(along).ok_or_else(|| {
+                KclError::Type(KclErrorDetails {
+                    message: format!("The segment of the face `{}` has no length", face),
+                    source_ranges: vec![source_range],
+                })
+            })?;
+            let y_axis = normalize(sketch_frame.z_axis).unwrap_or(sketch_frame.z_axis);
+            Ok(Frame {
+                origin: sketch_frame.point(from),
+                x_axis,
+                y_axis,
+                z_axis: cross(x_axis, y_axis),
+            })
+        }
+    }
+}
+
+/// The plane through three points, or `None` if they're on one line.
+fn frame_through_points([a, b, c]: [Point3d; 3]) -> Option<Frame> {
+    let x_axis = normalize(sub(b, a))?;
+    let z_axis = normalize(cross(sub(b, a), sub(c, a)))?;
+    Some(Frame {
+        origin: a,
+        x_axis,
+        y_axis: cross(z_axis, x_axis),
+        z_axis,
+    })
+}
+
+pub(crate) fn point3d(p: [f64; 3]) -> Point3d {
+    Point3d::new(p[0], p[1], p[2])
+}
+
+pub(crate) fn add(a: Point3d, b: Point3d) -> Point3d {
+    Point3d::new(a.x + b.x, a.y + b.y, a.z + b.z)
+}
+
+pub(crate) fn sub(a: Point3d, b: Point3d) -> Point3d {
+    Point3d::new(a.x - b.x, a.y - b.y, a.z - b.z)
+}
+
+pub(crate) fn mul(a: Point3d, s: f64) -> Point3d {
+    Point3d::new(a.x * s, a.y * s, a.z * s)
+}
+
+pub(crate) fn cross(a: Point3d, b: Point3d) -> Point3d {
+    Point3d::new(a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x)
+}
+
+pub(crate) fn dot(a: Point3d, b: Point3d) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+pub(crate) fn norm(a: Point3d) -> f64 {
+    dot(a, a).sqrt()
+}
+
+pub(crate) fn normalize(a: Point3d) -> Option<Point3d> {
+    let len = norm(a);
+    if len <= f64::EPSILON {
+        return None;
+    }
+    Some(mul(a, 1.0 / len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_through_points() {
+        let frame = frame_through_points([
+            Point3d::new(1.0, 1.0, 0.0),
+            Point3d::new(3.0, 1.0, 0.0),
+            Point3d::new(1.0, 5.0, 0.0),
+        ])
+        .unwrap();
+        assert_eq!(frame.origin, Point3d::new(1.0, 1.0, 0.0));
+        assert_eq!(frame.x_axis, Point3d::new(1.0, 0.0, 0.0));
+        assert_eq!(frame.y_axis, Point3d::new(0.0, 1.0, 0.0));
+        assert_eq!(frame.z_axis, Point3d::new(0.0, 0.0, 1.0));
+
+        assert!(
+            frame_through_points([Point3d::ZERO, Point3d::new(1.0, 1.0, 1.0), Point3d::new(2.0, 2.0, 2.0)]).is_none()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_side_faces_point_out_of_clockwise_profiles() {
+        // The same square, drawn counterclockwise and then clockwise.
+        for (profile, side) in [
+            (
+                "line([10, 0], %, $side)\n  |> line([0, 10], %)\n  |> line([-10, 0], %)",
+                "[0, -1, 0]",
+            ),
+            (
+                "line([0, 10], %)\n  |> line([10, 0], %)\n  |> line([0, -10], %, $side)",
+                "[1, 0, 0]",
+            ),
+        ] {
+            let code = format!(
+                r#"const block = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> {}
+  |> close(%)
+  |> extrude(10, %)
+const normal = faceNormal(block, side)
+const expected = {}
+assertEqual(normal[0], expected[0], 0.000001, "x")
+assertEqual(normal[1], expected[1], 0.000001, "y")
+assertEqual(normal[2], expected[2], 0.000001, "z")
+"#,
+                profile, side
+            );
+            let ctx = crate::executor::ExecutorContext::new_mock(Default::default())
+                .await
+                .unwrap();
+            let program = crate::parser::parse(&code).unwrap();
+            ctx.run(&program, None, crate::executor::IdGenerator::default(), None)
+                .await
+                .unwrap();
+        }
+    }
+}
@@ -75,7 +75,7 @@ pub async fn extrude(exec_state: &mut ExecState, args: Args) -> Result<KclValue,
 #[stdlib {
     name = "extrude"
 }]
-async fn inner_extrude(
+pub(crate) async fn inner_extrude(
     length: f64,
     sketch_set: SketchSet,
     exec_state: &mut ExecState,
@@ -121,7 +121,11 @@ async fn inner_extrude(
             ModelingCmd::SketchModeDisable(mcmd::SketchModeDisable {}),
         )
         .await?;
-        solids.push(do_post_extrude(sketch.clone(), length, exec_state, args.clone()).await?);
+        let solid = do_post_extrude(sketch.clone(), length, exec_state, args.clone()).await?;
+        if let SketchSurface::Plane(_) = sketch.on {
+            exec_state.plain_extrusions.insert(solid.id);
+        }
+        solids.push(solid);
     }
 
     Ok(solids.into())
@@ -148,6 +152,11 @@ pub(crate) async fn do_post_extrude(
         }));
     }
 
+    // Sweeping a sketch on a face adds to or cuts from that face's solid.
+    if let SketchSurface::Face(face) = &sketch.on {
+        exec_state.plain_extrusions.remove(&face.solid.id);
+    }
+
     let edge_id = sketch.paths.iter().find_map(|segment| match segment {
         Path::ToPoint { base } | Path::Circle { base, .. } => Some(base.geo_meta.id),
         _ => None,
@@ -288,6 +297,11 @@ pub(crate) async fn do_post_extrude(
         start_cap_id,
         end_cap_id,
         edge_cuts: vec![],
+        holes: vec![],
+        transform: Default::default(),
+        part: None,
+        appearance: None,
+        material: None,
     }))
 }
 
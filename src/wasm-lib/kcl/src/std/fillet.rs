@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
+    artifact_graph::EdgeSubType,
     ast::types::TagDeclarator,
     errors::{KclError, KclErrorDetails},
     executor::{EdgeCut, ExecState, ExtrudeSurface, FilletSurface, GeoMeta, KclValue, Solid, TagIdentifier, UserVal},
@@ -67,6 +68,10 @@ pub async fn fillet(exec_state: &mut ExecState, args: Args) -> Result<KclValue,
 /// a chamfer will cut a sharp transition along an edge while fillet
 /// will smoothly blend the transition.
 ///
+/// The engine rounds an edge with one radius along its whole length, so
+/// fillets whose radius varies along the edge, and setbacks where filleted
+/// edges meet at a corner, can't be made yet.
+///
 /// ```no_run
 /// const width = 20
 /// const length = 10
@@ -138,6 +143,7 @@ async fn inner_fillet(
         }));
     }
 
+    exec_state.plain_extrusions.remove(&solid.id);
     let mut solid = solid.clone();
     for edge_tag in data.tags {
         let edge_id = edge_tag.get_engine_id(exec_state, &args)?;
@@ -235,13 +241,14 @@ async fn inner_get_opposite_edge(tag: TagIdentifier, exec_state: &mut ExecState,
 
     let id = exec_state.id_generator.next_uuid();
     let tagged_path = args.get_tag_engine_info(exec_state, &tag)?;
+    let (seg_id, solid_id) = (tagged_path.id, tagged_path.sketch);
 
     let resp = args
         .send_modeling_cmd(
             id,
             ModelingCmd::from(mcmd::Solid3dGetOppositeEdge {
-                edge_id: tagged_path.id,
-                object_id: tagged_path.sketch,
+                edge_id: seg_id,
+                object_id: solid_id,
                 face_id,
             }),
         )
@@ -256,6 +263,9 @@ async fn inner_get_opposite_edge(tag: TagIdentifier, exec_state: &mut ExecState,
         }));
     };
 
+    exec_state
+        .artifact_graph
+        .record_edge(opposite_edge.edge, seg_id, solid_id, EdgeSubType::Opposite);
     Ok(opposite_edge.edge)
 }
 
@@ -317,13 +327,14 @@ async fn inner_get_next_adjacent_edge(
 
     let id = exec_state.id_generator.next_uuid();
     let tagged_path = args.get_tag_engine_info(exec_state, &tag)?;
+    let (seg_id, solid_id) = (tagged_path.id, tagged_path.sketch);
 
     let resp = args
         .send_modeling_cmd(
             id,
             ModelingCmd::from(mcmd::Solid3dGetNextAdjacentEdge {
-                edge_id: tagged_path.id,
-                object_id: tagged_path.sketch,
+                edge_id: seg_id,
+                object_id: solid_id,
                 face_id,
             }),
         )
@@ -341,12 +352,16 @@ async fn inner_get_next_adjacent_edge(
         }));
     };
 
-    adjacent_edge.edge.ok_or_else(|| {
+    let edge = adjacent_edge.edge.ok_or_else(|| {
         KclError::Type(KclErrorDetails {
             message: format!("No edge found next adjacent to tag: `{}`", tag.value),
             source_ranges: vec![args.source_range],
         })
-    })
+    })?;
+    exec_state
+        .artifact_graph
+        .record_edge(edge, seg_id, solid_id, EdgeSubType::Adjacent);
+    Ok(edge)
 }
 
 /// Get the previous adjacent edge to the edge given.
@@ -407,13 +422,14 @@ async fn inner_get_previous_adjacent_edge(
 
     let id = exec_state.id_generator.next_uuid();
     let tagged_path = args.get_tag_engine_info(exec_state, &tag)?;
+    let (seg_id, solid_id) = (tagged_path.id, tagged_path.sketch);
 
     let resp = args
         .send_modeling_cmd(
             id,
             ModelingCmd::from(mcmd::Solid3dGetPrevAdjacentEdge {
-                edge_id: tagged_path.id,
-                object_id: tagged_path.sketch,
+                edge_id: seg_id,
+                object_id: solid_id,
                 face_id,
             }),
         )
@@ -431,12 +447,16 @@ async fn inner_get_previous_adjacent_edge(
         }));
     };
 
-    adjacent_edge.edge.ok_or_else(|| {
+    let edge = adjacent_edge.edge.ok_or_else(|| {
         KclError::Type(KclErrorDetails {
             message: format!("No edge found previous adjacent to tag: `{}`", tag.value),
             source_ranges: vec![args.source_range],
         })
-    })
+    })?;
+    exec_state
+        .artifact_graph
+        .record_edge(edge, seg_id, solid_id, EdgeSubType::Adjacent);
+    Ok(edge)
 }
 
 pub(crate) fn default_tolerance(units: &UnitLength) -> f64 {
@@ -0,0 +1,739 @@
+//! Standard library hole features.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use kcmc::{each_cmd as mcmd, length_unit::LengthUnit, shared::CutType, ModelingCmd};
+use kittycad_modeling_cmds as kcmc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{KclError, KclErrorDetails},
+    executor::{EdgeCut, ExecState, KclValue, Path, SketchSet, SketchSurface, Solid, SourceRange},
+    settings::types::UnitLength,
+    std::{
+        chamfer::DEFAULT_TOLERANCE,
+        shapes::{CircleData, SketchOrSurface},
+        sketch::{FaceTag, SketchData},
+        Args,
+    },
+};
+
+/// Data for a hole feature.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct HoleData {
+    /// The kind of hole to cut. Defaults to a simple hole.
+    #[serde(default, rename = "type")]
+    pub hole_type: HoleType,
+    /// A standard fastener size, e.g. `M6` or `1/4-20`. Used to look up
+    /// any dimensions which are not given explicitly.
+    #[serde(default)]
+    pub size: Option<String>,
+    /// The diameter of the hole. Defaults to the clearance diameter of
+    /// `size`, or the tap drill diameter for tapped holes.
+    #[serde(default)]
+    pub diameter: Option<f64>,
+    /// How deep to cut the hole, measured from the face.
+    pub depth: f64,
+    /// Where to put the holes, in the coordinate system of the face.
+    pub positions: Vec<[f64; 2]>,
+    /// The diameter of the counterbore.
+    #[serde(default)]
+    pub counterbore_diameter: Option<f64>,
+    /// The depth of the counterbore.
+    #[serde(default)]
+    pub counterbore_depth: Option<f64>,
+    /// The outer diameter of the countersink.
+    #[serde(default)]
+    pub countersink_diameter: Option<f64>,
+    /// The included angle of the countersink, in degrees. Only 90 can be cut for now.
+    #[serde(default)]
+    pub countersink_angle: Option<f64>,
+    /// How deep the thread of a tapped hole runs. Defaults to the hole depth.
+    #[serde(default)]
+    pub thread_depth: Option<f64>,
+}
+
+/// The kind of a hole feature.
+#[derive(Debug, Default, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub enum HoleType {
+    /// A plain cylindrical hole.
+    #[default]
+    Simple,
+    /// A hole with a wider, flat-bottomed recess for a socket head.
+    Counterbore,
+    /// A hole with a conical recess for a flat head.
+    Countersink,
+    /// A hole drilled at tap size and carrying thread metadata.
+    Tapped,
+}
+
+/// A hole cut into a solid by `holeFeature`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct HoleInfo {
+    /// The id of the hole's circle sketch, which the cut extruded from it shares.
+    pub id: uuid::Uuid,
+    /// The id of the face the hole was cut into.
+    pub face_id: uuid::Uuid,
+    /// The kind of hole.
+    #[serde(rename = "type")]
+    pub hole_type: HoleType,
+    /// The position of the hole on the face.
+    pub position: [f64; 2],
+    /// The diameter of the hole.
+    pub diameter: f64,
+    /// The depth of the hole.
+    pub depth: f64,
+    /// The counterbore, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counterbore: Option<Counterbore>,
+    /// The countersink, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub countersink: Option<Countersink>,
+    /// The thread of a tapped hole.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread: Option<ThreadInfo>,
+}
+
+/// The recess of a counterbored hole.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Counterbore {
+    /// The diameter of the counterbore.
+    pub diameter: f64,
+    /// The depth of the counterbore.
+    pub depth: f64,
+}
+
+/// The cone of a countersunk hole.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct Countersink {
+    /// The outer diameter of the countersink.
+    pub diameter: f64,
+    /// The included angle of the countersink, in degrees.
+    pub angle: f64,
+}
+
+/// Thread metadata for a tapped hole.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadInfo {
+    /// The thread designation, e.g. `M6x1` or `1/4-20 UNC`.
+    pub designation: String,
+    /// The nominal (major) diameter of the thread.
+    pub major_diameter: f64,
+    /// The distance between threads.
+    pub pitch: f64,
+    /// How deep the thread runs.
+    pub depth: f64,
+}
+
+/// The thread standard a fastener size belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThreadStandard {
+    /// ISO metric coarse, dimensions in millimeters.
+    Metric,
+    /// Unified coarse, dimensions in inches.
+    Unified,
+}
+
+/// An entry in the fastener size tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FastenerSize {
+    name: &'static str,
+    standard: ThreadStandard,
+    major_diameter: f64,
+    /// Thread pitch in millimeters for metric sizes, threads per inch for unified sizes.
+    pitch: f64,
+    tap_drill: f64,
+    clearance: f64,
+    counterbore_diameter: f64,
+    counterbore_depth: f64,
+    /// Only metric sizes have a countersink, as unified flat heads need an 82° cone.
+    countersink_diameter: Option<f64>,
+}
+
+macro_rules! fastener {
+    ($name:literal, $standard:ident, $major:literal, $pitch:literal, $tap:literal, $clearance:literal, $cbore_d:literal, $cbore_depth:literal, $csk_d:expr) => {
+        FastenerSize {
+            name: $name,
+            standard: ThreadStandard::$standard,
+            major_diameter: $major,
+            pitch: $pitch,
+            tap_drill: $tap,
+            clearance: $clearance,
+            counterbore_diameter: $cbore_d,
+            counterbore_depth: $cbore_depth,
+            countersink_diameter: $csk_d,
+        }
+    };
+}
+
+/// Fastener sizes we know the hole dimensions of. Metric sizes use normal
+/// clearance holes and ISO 10642 countersinks, unified sizes use close-fit
+/// clearance holes.
+const FASTENER_SIZES: &[FastenerSize] = &[
+    fastener!("M2", Metric, 2.0, 0.4, 1.6, 2.4, 4.4, 2.0, Some(4.4)),
+    fastener!("M2.5", Metric, 2.5, 0.45, 2.05, 2.9, 5.4, 2.5, Some(5.5)),
+    fastener!("M3", Metric, 3.0, 0.5, 2.5, 3.4, 6.5, 3.0, Some(6.7)),
+    fastener!("M4", Metric, 4.0, 0.7, 3.3, 4.5, 8.0, 4.0, Some(9.0)),
+    fastener!("M5", Metric, 5.0, 0.8, 4.2, 5.5, 10.0, 5.0, Some(11.2)),
+    fastener!("M6", Metric, 6.0, 1.0, 5.0, 6.6, 11.0, 6.0, Some(13.4)),
+    fastener!("M8", Metric, 8.0, 1.25, 6.8, 9.0, 15.0, 8.0, Some(17.9)),
+    fastener!("M10", Metric, 10.0, 1.5, 8.5, 11.0, 18.0, 10.0, Some(22.4)),
+    fastener!("M12", Metric, 12.0, 1.75, 10.2, 13.5, 20.0, 12.0, Some(26.9)),
+    fastener!("M16", Metric, 16.0, 2.0, 14.0, 17.5, 26.0, 16.0, Some(33.6)),
+    fastener!("M20", Metric, 20.0, 2.5, 17.5, 22.0, 33.0, 20.0, Some(40.3)),
+    fastener!("#4-40", Unified, 0.112, 40.0, 0.089, 0.116, 0.2187, 0.112, None),
+    fastener!("#6-32", Unified, 0.138, 32.0, 0.1065, 0.144, 0.2812, 0.138, None),
+    fastener!("#8-32", Unified, 0.164, 32.0, 0.136, 0.1695, 0.3125, 0.164, None),
+    fastener!("#10-24", Unified, 0.19, 24.0, 0.1495, 0.196, 0.375, 0.19, None),
+    fastener!("1/4-20", Unified, 0.25, 20.0, 0.201, 0.266, 0.4375, 0.25, None),
+    fastener!("5/16-18", Unified, 0.3125, 18.0, 0.257, 0.332, 0.5312, 0.3125, None),
+    fastener!("3/8-16", Unified, 0.375, 16.0, 0.3125, 0.397, 0.625, 0.375, None),
+    fastener!("1/2-13", Unified, 0.5, 13.0, 0.4219, 0.5312, 0.8125, 0.5, None),
+];
+
+impl FastenerSize {
+    /// Find a size by name. Case is ignored, as is the `#` on numbered unified sizes.
+    fn find(name: &str) -> Option<&'static FastenerSize> {
+        let wanted = name.trim().trim_start_matches('#').to_lowercase();
+        FASTENER_SIZES
+            .iter()
+            .find(|size| size.name.trim_start_matches('#').to_lowercase() == wanted)
+    }
+
+    /// Convert a length from this size's table units into the given units.
+    fn in_units(&self, value: f64, units: UnitLength) -> f64 {
+        match (self.standard, units) {
+            (ThreadStandard::Metric, UnitLength::Mm) | (ThreadStandard::Unified, UnitLength::In) => return value,
+            _ => {}
+        }
+        let length = match self.standard {
+            ThreadStandard::Metric => measurements::Length::from_millimeters(value),
+            ThreadStandard::Unified => measurements::Length::from_inches(value),
+        };
+        match units {
+            UnitLength::Mm => length.as_millimeters(),
+            UnitLength::In => length.as_inches(),
+            UnitLength::Ft => length.as_feet(),
+            UnitLength::M => length.as_meters(),
+            UnitLength::Cm => length.as_centimeters(),
+            UnitLength::Yd => length.as_yards(),
+        }
+    }
+
+    fn pitch(&self, units: UnitLength) -> f64 {
+        match self.standard {
+            ThreadStandard::Metric => self.in_units(self.pitch, units),
+            ThreadStandard::Unified => self.in_units(1.0 / self.pitch, units),
+        }
+    }
+
+    fn designation(&self) -> String {
+        match self.standard {
+            ThreadStandard::Metric => format!("{}x{}", self.name, self.pitch),
+            ThreadStandard::Unified => format!("{} UNC", self.name),
+        }
+    }
+}
+
+/// The fully resolved dimensions of the holes to cut, in project units.
+#[derive(Debug, Clone, PartialEq)]
+struct ResolvedHole {
+    diameter: f64,
+    depth: f64,
+    counterbore: Option<Counterbore>,
+    countersink: Option<Countersink>,
+    thread: Option<ThreadInfo>,
+}
+
+impl HoleData {
+    /// Work out every dimension of the hole, filling in anything not given
+    /// explicitly from the fastener size tables.
+    fn resolve(&self, units: UnitLength, source_range: SourceRange) -> Result<ResolvedHole, KclError> {
+        let err = |message: String| {
+            KclError::Semantic(KclErrorDetails {
+                message,
+                source_ranges: vec![source_range],
+            })
+        };
+
+        let size = match &self.size {
+            Some(name) => Some(FastenerSize::find(name).ok_or_else(|| {
+                err(format!(
+                    "Unknown hole size `{}`. Known sizes are: {}",
+                    name,
+                    FASTENER_SIZES.iter().map(|s| s.name).collect::<Vec<_>>().join(", ")
+                ))
+            })?),
+            None => None,
+        };
+
+        if self.positions.is_empty() {
+            return Err(err("Expected at least one hole position".to_string()));
+        }
+        if self.depth <= 0.0 {
+            return Err(err(format!("Hole depth must be greater than 0, got {}", self.depth)));
+        }
+
+        let default_diameter = size.map(|s| match self.hole_type {
+            HoleType::Tapped => s.in_units(s.tap_drill, units),
+            _ => s.in_units(s.clearance, units),
+        });
+        let diameter = self
+            .diameter
+            .or(default_diameter)
+            .ok_or_else(|| err("Expected either a `diameter` or a `size` for the hole".to_string()))?;
+        if diameter <= 0.0 {
+            return Err(err(format!("Hole diameter must be greater than 0, got {}", diameter)));
+        }
+
+        let counterbore = if self.hole_type == HoleType::Counterbore {
+            let cbore_diameter = self
+                .counterbore_diameter
+                .or(size.map(|s| s.in_units(s.counterbore_diameter, units)))
+                .ok_or_else(|| err("Expected a `counterboreDiameter` or a `size` for the counterbore".to_string()))?;
+            let cbore_depth = self
+                .counterbore_depth
+                .or(size.map(|s| s.in_units(s.counterbore_depth, units)))
+                .ok_or_else(|| err("Expected a `counterboreDepth` or a `size` for the counterbore".to_string()))?;
+            if cbore_diameter <= diameter {
+                return Err(err(format!(
+                    "Counterbore diameter ({}) must be larger than the hole diameter ({})",
+                    cbore_diameter, diameter
+                )));
+            }
+            if cbore_depth <= 0.0 || cbore_depth >= self.depth {
+                return Err(err(format!(
+                    "Counterbore depth ({}) must be greater than 0 and less than the hole depth ({})",
+                    cbore_depth, self.depth
+                )));
+            }
+            Some(Counterbore {
+                diameter: cbore_diameter,
+                depth: cbore_depth,
+            })
+        } else {
+            None
+        };
+
+        let countersink = if self.hole_type == HoleType::Countersink {
+            let csk_diameter = self
+                .countersink_diameter
+                .or(size.and_then(|s| s.countersink_diameter.map(|d| s.in_units(d, units))))
+                .ok_or_else(|| match size {
+                    Some(size) => err(format!(
+                        "There is no standard countersink for `{}`, give a `countersinkDiameter`",
+                        size.name
+                    )),
+                    None => err("Expected a `countersinkDiameter` or a `size` for the countersink".to_string()),
+                })?;
+            let angle = self.countersink_angle.unwrap_or(90.0);
+            if csk_diameter <= diameter {
+                return Err(err(format!(
+                    "Countersink diameter ({}) must be larger than the hole diameter ({})",
+                    csk_diameter, diameter
+                )));
+            }
+            if angle <= 0.0 || angle >= 180.0 {
+                return Err(err(format!(
+                    "Countersink angle must be between 0 and 180 degrees, got {}",
+                    angle
+                )));
+            }
+            // Countersinks are cut as a chamfer on the edge of the hole, which the engine can
+            // only cut at 45° to each face.
+            if angle != 90.0 {
+                return Err(err(format!(
+                    "Countersinks can only be cut at 90 degrees for now, got {}",
+                    angle
+                )));
+            }
+            Some(Countersink {
+                diameter: csk_diameter,
+                angle,
+            })
+        } else {
+            None
+        };
+
+        let thread = if self.hole_type == HoleType::Tapped {
+            let Some(size) = size else {
+                return Err(err(
+                    "Tapped holes need a thread `size`, e.g. `M6` or `1/4-20`".to_string()
+                ));
+            };
+            let depth = self.thread_depth.unwrap_or(self.depth);
+            if depth <= 0.0 || depth > self.depth {
+                return Err(err(format!(
+                    "Thread depth ({}) must be greater than 0 and no more than the hole depth ({})",
+                    depth, self.depth
+                )));
+            }
+            Some(ThreadInfo {
+                designation: size.designation(),
+                major_diameter: size.in_units(size.major_diameter, units),
+                pitch: size.pitch(units),
+                depth,
+            })
+        } else {
+            None
+        };
+
+        Ok(ResolvedHole {
+            diameter,
+            depth: self.depth,
+            counterbore,
+            countersink,
+            thread,
+        })
+    }
+}
+
+/// Cut holes into a face of a solid.
+pub async fn hole_feature(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, solid, face): (HoleData, Box<Solid>, FaceTag) = args.get_data_and_solid_and_face()?;
+
+    let solid = inner_hole_feature(data, solid, face, exec_state, args).await?;
+    Ok(KclValue::Solid(solid))
+}
+
+/// Cut simple, counterbored, countersunk or tapped holes into a face of a solid.
+///
+/// Dimensions can be given explicitly, or looked up from a standard
+/// fastener `size` such as `M6` (ISO metric coarse) or `1/4-20` (unified
+/// coarse). Looked up dimensions are converted to the project units.
+/// Every hole is recorded on the returned solid, and tapped holes carry
+/// their thread designation, pitch and major diameter.
+///
+/// Countersinks are cut as a chamfer on the edge of the hole, so only 90°
+/// countersinks can be cut for now. The size tables only have countersinks
+/// for metric sizes, as unified flat heads need an 82° countersink.
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([40, 0], %)
+///   |> line([0, 20], %)
+///   |> line([-40, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// const drilled = holeFeature({
+///   type: "counterbore",
+///   size: "M6",
+///   depth: 10,
+///   positions: [[10, 10], [30, 10]],
+/// }, plate, 'end')
+/// ```
+///
+/// ```no_run,no_image
+/// const block = startSketchOn('XZ')
+///   |> startProfileAt([0, 0], %)
+///   |> line([30, 0], %)
+///   |> line([0, 30], %)
+///   |> line([-30, 0], %)
+///   |> close(%, $side)
+///   |> extrude(20, %)
+///
+/// const tapped = holeFeature({
+///   type: "tapped",
+///   size: "M8",
+///   depth: 15,
+///   threadDepth: 12,
+///   positions: [[15, 15]],
+/// }, block, 'start')
+/// ```
+///
+/// ```no_run,no_image
+/// const bracket = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([50, 0], %)
+///   |> line([0, 25], %)
+///   |> line([-50, 0], %)
+///   |> close(%)
+///   |> extrude(8, %)
+///
+/// const countersunk = holeFeature({
+///   type: "countersink",
+///   diameter: 5,
+///   countersinkDiameter: 10,
+///   depth: 8,
+///   positions: [[12, 12.5], [38, 12.5]],
+/// }, bracket, 'end')
+/// ```
+#[stdlib {
+    name = "holeFeature",
+}]
+async fn inner_hole_feature(
+    data: HoleData,
+    solid: Box<Solid>,
+    face: FaceTag,
+    exec_state: &mut ExecState,
+    args: Args,
+) -> Result<Box<Solid>, KclError> {
+    let resolved = data.resolve(args.ctx.settings.units, args.source_range)?;
+
+    let surface =
+        crate::std::sketch::inner_start_sketch_on(SketchData::Solid(solid.clone()), Some(face), exec_state, &args)
+            .await?;
+    let face_id = surface.id();
+    exec_state.plain_extrusions.remove(&solid.id);
+
+    let mut solid = solid;
+    for position in &data.positions {
+        // The counterbore goes in first, the through hole is then cut from the
+        // same face, so it runs through the bottom of the counterbore.
+        if let Some(counterbore) = resolved.counterbore {
+            cut_cylinder(
+                &surface,
+                *position,
+                counterbore.diameter,
+                counterbore.depth,
+                exec_state,
+                &args,
+            )
+            .await?;
+        }
+
+        let (id, edge_id) = cut_cylinder(
+            &surface,
+            *position,
+            resolved.diameter,
+            resolved.depth,
+            exec_state,
+            &args,
+        )
+        .await?;
+
+        if let Some(countersink) = resolved.countersink {
+            let length = (countersink.diameter - resolved.diameter) / 2.0;
+            let chamfer_id = exec_state.id_generator.next_uuid();
+            args.batch_end_cmd(
+                chamfer_id,
+                ModelingCmd::from(mcmd::Solid3dFilletEdge {
+                    edge_id,
+                    object_id: solid.id,
+                    radius: LengthUnit(length),
+                    tolerance: LengthUnit(DEFAULT_TOLERANCE),
+                    cut_type: CutType::Chamfer,
+                    face_id: Some(chamfer_id),
+                }),
+            )
+            .await?;
+
+            solid.edge_cuts.push(EdgeCut::Chamfer {
+                id: chamfer_id,
+                edge_id,
+                length,
+                tag: Box::new(None),
+            });
+        }
+
+        solid.holes.push(HoleInfo {
+            id,
+            face_id,
+            hole_type: data.hole_type,
+            position: *position,
+            diameter: resolved.diameter,
+            depth: resolved.depth,
+            counterbore: resolved.counterbore,
+            countersink: resolved.countersink,
+            thread: resolved.thread.clone(),
+        });
+    }
+
+    Ok(solid)
+}
+
+/// Sketch a circle on the surface and extrude it back into the solid.
+/// Returns the id of the cut and the id of the circle's edge on the face.
+async fn cut_cylinder(
+    surface: &SketchSurface,
+    center: [f64; 2],
+    diameter: f64,
+    depth: f64,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<(uuid::Uuid, uuid::Uuid), KclError> {
+    let sketch = crate::std::shapes::inner_circle(
+        CircleData {
+            center,
+            radius: diameter / 2.0,
+        },
+        SketchOrSurface::SketchSurface(surface.clone()),
+        None,
+        exec_state,
+        args.clone(),
+    )
+    .await?;
+
+    let edge_id = sketch
+        .paths
+        .iter()
+        .find_map(|path| match path {
+            Path::Circle { base, .. } => Some(base.geo_meta.id),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            KclError::Engine(KclErrorDetails {
+                message: "Expected the hole sketch to contain a circle".to_string(),
+                source_ranges: vec![args.source_range],
+            })
+        })?;
+    let id = sketch.id;
+
+    crate::std::extrude::inner_extrude(-depth, SketchSet::from(sketch), exec_state, args.clone()).await?;
+
+    Ok((id, edge_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn hole(hole_type: HoleType, size: Option<&str>) -> HoleData {
+        HoleData {
+            hole_type,
+            size: size.map(|s| s.to_string()),
+            diameter: None,
+            depth: 20.0,
+            positions: vec![[0.0, 0.0]],
+            counterbore_diameter: None,
+            counterbore_depth: None,
+            countersink_diameter: None,
+            countersink_angle: None,
+            thread_depth: None,
+        }
+    }
+
+    #[test]
+    fn test_find_fastener_size() {
+        assert_eq!(FastenerSize::find("m6").unwrap().name, "M6");
+        assert_eq!(FastenerSize::find(" 10-24 ").unwrap().name, "#10-24");
+        assert_eq!(FastenerSize::find("1/4-20").unwrap().name, "1/4-20");
+        assert!(FastenerSize::find("M7").is_none());
+    }
+
+    #[test]
+    fn test_resolve_counterbore_from_table() {
+        let resolved = hole(HoleType::Counterbore, Some("M6"))
+            .resolve(UnitLength::Mm, SourceRange::default())
+            .unwrap();
+        assert_eq!(resolved.diameter, 6.6);
+        assert_eq!(
+            resolved.counterbore,
+            Some(Counterbore {
+                diameter: 11.0,
+                depth: 6.0
+            })
+        );
+        assert_eq!(resolved.countersink, None);
+        assert_eq!(resolved.thread, None);
+    }
+
+    #[test]
+    fn test_resolve_tapped_converts_units() {
+        let resolved = hole(HoleType::Tapped, Some("1/4-20"))
+            .resolve(UnitLength::Mm, SourceRange::default())
+            .unwrap();
+        assert!((resolved.diameter - 5.1054).abs() < 1e-9);
+        let thread = resolved.thread.unwrap();
+        assert_eq!(thread.designation, "1/4-20 UNC");
+        assert!((thread.major_diameter - 6.35).abs() < 1e-9);
+        assert!((thread.pitch - 1.27).abs() < 1e-9);
+        assert_eq!(thread.depth, 20.0);
+
+        let resolved = hole(HoleType::Tapped, Some("M8"))
+            .resolve(UnitLength::In, SourceRange::default())
+            .unwrap();
+        let thread = resolved.thread.unwrap();
+        assert_eq!(thread.designation, "M8x1.25");
+        assert!((thread.pitch - 1.25 / 25.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_resolve_countersink_overrides() {
+        let resolved = hole(HoleType::Countersink, Some("M6"))
+            .resolve(UnitLength::Mm, SourceRange::default())
+            .unwrap();
+        assert_eq!(
+            resolved.countersink,
+            Some(Countersink {
+                diameter: 13.4,
+                angle: 90.0
+            })
+        );
+
+        let mut data = hole(HoleType::Countersink, Some("#8-32"));
+        let err = data.resolve(UnitLength::In, SourceRange::default()).unwrap_err();
+        assert!(
+            err.message().contains("no standard countersink for `#8-32`"),
+            "{}",
+            err.message()
+        );
+
+        data.countersink_diameter = Some(0.3);
+        data.countersink_angle = Some(82.0);
+        let err = data.resolve(UnitLength::In, SourceRange::default()).unwrap_err();
+        assert!(err.message().contains("only be cut at 90"), "{}", err.message());
+
+        data.countersink_angle = None;
+        let resolved = data.resolve(UnitLength::In, SourceRange::default()).unwrap();
+        assert_eq!(resolved.diameter, 0.1695);
+        assert_eq!(
+            resolved.countersink,
+            Some(Countersink {
+                diameter: 0.3,
+                angle: 90.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let err = hole(HoleType::Simple, None)
+            .resolve(UnitLength::Mm, SourceRange::default())
+            .unwrap_err();
+        assert!(err.message().contains("`diameter` or a `size`"), "{}", err.message());
+
+        let err = hole(HoleType::Tapped, None)
+            .resolve(UnitLength::Mm, SourceRange::default())
+            .unwrap_err();
+        assert!(err.message().contains("`diameter` or a `size`"), "{}", err.message());
+
+        let mut data = hole(HoleType::Tapped, None);
+        data.diameter = Some(5.0);
+        let err = data.resolve(UnitLength::Mm, SourceRange::default()).unwrap_err();
+        assert!(
+            err.message().contains("Tapped holes need a thread"),
+            "{}",
+            err.message()
+        );
+
+        let err = hole(HoleType::Simple, Some("M7"))
+            .resolve(UnitLength::Mm, SourceRange::default())
+            .unwrap_err();
+        assert!(err.message().starts_with("Unknown hole size `M7`"), "{}", err.message());
+
+        let mut data = hole(HoleType::Counterbore, None);
+        data.diameter = Some(5.0);
+        data.counterbore_diameter = Some(4.0);
+        data.counterbore_depth = Some(2.0);
+        let err = data.resolve(UnitLength::Mm, SourceRange::default()).unwrap_err();
+        assert!(err.message().contains("must be larger"), "{}", err.message());
+    }
+}
@@ -130,7 +130,7 @@ pub async fn import(exec_state: &mut ExecState, args: Args) -> Result<KclValue,
     let (file_path, options): (String, Option<ImportFormat>) = args.get_import_data()?;
 
     let imported_geometry = inner_import(file_path, options, exec_state, args).await?;
-    Ok(KclValue::ImportedGeometry(imported_geometry))
+    Ok(KclValue::ImportedGeometry(Box::new(imported_geometry)))
 }
 
 /// Import a CAD file.
@@ -289,6 +289,10 @@ async fn inner_import(
         return Ok(ImportedGeometry {
             id: exec_state.id_generator.next_uuid(),
             value: import_files.iter().map(|f| f.path.to_string()).collect(),
+            transform: Default::default(),
+            part: None,
+            appearance: None,
+            material: None,
             meta: vec![args.source_range.into()],
         });
     }
@@ -317,6 +321,10 @@ async fn inner_import(
     Ok(ImportedGeometry {
         id: imported_files.object_id,
         value: import_files.iter().map(|f| f.path.to_string()).collect(),
+        transform: Default::default(),
+        part: None,
+        appearance: None,
+        material: None,
         meta: vec![args.source_range.into()],
     })
 }
@@ -0,0 +1,906 @@
+//! Standard library measurements: lengths, areas, angles, bounding boxes and distances in 3D.
+//!
+//! Solids which are still just an extruded profile are measured from the profile and extrusion,
+//! without the engine. Other solids, and distances and masses, are measured by asking the engine.
+//! Lengths are in the program's units (`ExecutorSettings::units`) and areas in those units squared.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use kcmc::{
+    each_cmd as mcmd,
+    ok_response::OkModelingCmdResponse,
+    shared::{CurveType, DistanceType},
+    units::{UnitArea, UnitDensity, UnitMass},
+    websocket::OkWebSocketResponseData,
+    ModelingCmd,
+};
+use kittycad_modeling_cmds as kcmc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use uuid::Uuid;
+
+use crate::{
+    artifact_graph::{Artifact, EdgeSubType},
+    errors::{KclError, KclErrorDetails},
+    executor::{ExecState, KclValue, Metadata, Point3d, Solid, SolidSet, SourceRange, TagIdentifier, UserVal},
+    settings::types::UnitLength,
+    std::{
+        datums::{add, cross, dot, face_frame, mul, norm, normalize, point3d, profile_frame, sub, Frame},
+        fillet::EdgeReference,
+        sketch::FaceTag,
+        utils::CurveSegment,
+        Args,
+    },
+};
+
+/// Arcs which can't be measured exactly are split into this many lines.
+const ARC_STEPS: usize = 256;
+
+/// The smallest box, lined up with the global axes, which a solid fits in.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBox3d {
+    /// The corner with the smallest X, Y and Z.
+    pub min: [f64; 3],
+    /// The corner with the largest X, Y and Z.
+    pub max: [f64; 3],
+}
+
+/// Something to measure the distance to.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum MeasureEntity {
+    /// A solid.
+    Solid(Box<Solid>),
+    /// A tagged edge.
+    Tag(Box<TagIdentifier>),
+}
+
+/// The length of an edge of an extruded solid: one along a segment of its profile, on the start
+/// or end face, or one up the extrusion. `None` for edges the profile doesn't have, such as those
+/// of fillets.
+pub fn solid_edge_length(
+    solid: &Solid,
+    edge: &EdgeReference,
+    exec_state: &ExecState,
+    source_range: SourceRange,
+) -> Result<Option<f64>, KclError> {
+    let frame = profile_frame(solid, "Measuring", source_range)?;
+    let paths = &solid.sketch.paths;
+    let (path, sub_type) = match edge {
+        EdgeReference::Tag(tag) => (
+            paths
+                .iter()
+                .find(|path| path.get_tag().map(|t| t.name == tag.value).unwrap_or(false)),
+            None,
+        ),
+        // Edges looked up from a segment, e.g. by `edges`, are in the artifact graph.
+        EdgeReference::Uuid(id) => {
+            let (seg_id, sub_type) = match exec_state.artifact_graph.get(*id) {
+                Some(Artifact::Edge(edge)) if edge.solid_id == solid.id => (edge.seg_id, Some(edge.sub_type)),
+                _ => (*id, None),
+            };
+            (
+                paths.iter().find(|path| path.get_base().geo_meta.id == seg_id),
+                sub_type,
+            )
+        }
+    };
+    Ok(path.map(|path| match sub_type {
+        Some(EdgeSubType::Adjacent) => norm(mul(frame.z_axis, solid.height)),
+        // The edge on the end face is the one on the start face moved along the extrusion.
+        _ => length_3d(&CurveSegment::from(path), &frame),
+    }))
+}
+
+/// The area of a face of an extruded solid.
+pub fn solid_face_area(solid: &Solid, face: &FaceTag, source_range: SourceRange) -> Result<f64, KclError> {
+    let frame = profile_frame(solid, "Measuring", source_range)?;
+    match face {
+        FaceTag::StartOrEnd(_) => {
+            let area: f64 = solid
+                .sketch
+                .paths
+                .iter()
+                .map(|p| CurveSegment::from(p).signed_area())
+                .sum();
+            Ok(area.abs() * norm(cross(frame.x_axis, frame.y_axis)))
+        }
+        FaceTag::Tag(_) | FaceTag::Uuid(_) => {
+            let path = face.side_path(solid).ok_or_else(|| {
+                KclError::Type(KclErrorDetails {
+                    message: format!("Expected a face `{}` on the solid", face),
+                    source_ranges: vec![source_range],
+                })
+            })?;
+            Ok(swept_area(
+                &CurveSegment::from(path),
+                &frame,
+                mul(frame.z_axis, solid.height),
+            ))
+        }
+    }
+}
+
+/// The unit normal of a planar face of an extruded solid, pointing out of the solid.
+pub fn solid_face_normal(solid: &Solid, face: &FaceTag, source_range: SourceRange) -> Result<Point3d, KclError> {
+    let frame = face_frame(solid, face, source_range)?;
+    normalize(frame.z_axis).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "The face has no area, so it has no normal".to_string(),
+            source_ranges: vec![source_range],
+        })
+    })
+}
+
+/// The angle between the normals of two planar faces of a solid, in degrees.
+pub fn solid_angle_between_faces(
+    solid: &Solid,
+    a: &FaceTag,
+    b: &FaceTag,
+    source_range: SourceRange,
+) -> Result<f64, KclError> {
+    let (a, b) = (
+        solid_face_normal(solid, a, source_range)?,
+        solid_face_normal(solid, b, source_range)?,
+    );
+    Ok(dot(a, b).clamp(-1.0, 1.0).acos().to_degrees())
+}
+
+/// The bounding box of an extruded solid.
+pub fn solid_bounding_box(solid: &Solid, source_range: SourceRange) -> Result<BoundingBox3d, KclError> {
+    let frame = profile_frame(solid, "Measuring", source_range)?;
+    let mut points = vec![];
+    for path in &solid.sketch.paths {
+        let segment = CurveSegment::from(path);
+        points.push(frame.point(segment.point_at(0.0)));
+        points.push(frame.point(segment.point_at(segment.length())));
+        if let CurveSegment::Arc {
+            center,
+            radius,
+            start,
+            sweep,
+        } = segment
+        {
+            // Along each global axis, the arc is furthest out where its tangent is square to it.
+            let axes = [frame.x_axis, frame.y_axis];
+            for (x, y) in [(axes[0].x, axes[1].x), (axes[0].y, axes[1].y), (axes[0].z, axes[1].z)] {
+                let theta = y.atan2(x);
+                for angle in [theta, theta + std::f64::consts::PI] {
+                    let into = if sweep >= 0.0 { angle - start } else { start - angle };
+                    if into.rem_euclid(std::f64::consts::TAU) <= sweep.abs() {
+                        let (sin, cos) = angle.sin_cos();
+                        points.push(frame.point([center[0] + radius * cos, center[1] + radius * sin]));
+                    }
+                }
+            }
+        }
+    }
+    if points.is_empty() {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "Expected a solid with at least one segment".to_string(),
+            source_ranges: vec![source_range],
+        }));
+    }
+
+    let extrusion = mul(frame.z_axis, solid.height);
+    let mut bbox = BoundingBox3d {
+        min: [f64::INFINITY; 3],
+        max: [f64::NEG_INFINITY; 3],
+    };
+    for point in points.iter().flat_map(|p| [*p, add(*p, extrusion)]) {
+        for (i, v) in [point.x, point.y, point.z].into_iter().enumerate() {
+            bbox.min[i] = bbox.min[i].min(v);
+            bbox.max[i] = bbox.max[i].max(v);
+        }
+    }
+    Ok(bbox)
+}
+
+/// The error for measurements which can't be made against the mock engine, as there's no geometry
+/// to measure without the engine.
+fn needs_engine(measuring: &str, source_range: SourceRange) -> KclError {
+    KclError::Semantic(KclErrorDetails {
+        message: format!("{} needs the engine, so it can't be done in mock mode", measuring),
+        source_ranges: vec![source_range],
+    })
+}
+
+/// Whether the solid is still just its profile extruded, so it can be measured from that. Fillets,
+/// chamfers, holes, shells and features swept onto its faces change it from what its profile
+/// says, and revolves, lofts and the copies patterns and mirrors make aren't where their profile
+/// is.
+fn is_plain_extrusion(solid: &Solid, exec_state: &ExecState) -> bool {
+    exec_state.plain_extrusions.contains(&solid.id)
+}
+
+/// Refuse to work from a solid's profile unless that's all it is, for measurements and queries
+/// the engine can't make either. `done` is what's being done to it, e.g. "measured".
+pub(crate) fn expect_plain_extrusion(
+    solid: &Solid,
+    exec_state: &ExecState,
+    done: &str,
+    source_range: SourceRange,
+) -> Result<(), KclError> {
+    if is_plain_extrusion(solid, exec_state) {
+        return Ok(());
+    }
+    Err(KclError::Semantic(KclErrorDetails {
+        message: format!(
+            "Only solids which are a profile extruded, without fillets, chamfers, holes, shells or other \
+            features added since, can be {}",
+            done
+        ),
+        source_ranges: vec![source_range],
+    }))
+}
+
+/// Get the solid's fillets and chamfers made by the engine, so they're there to be measured.
+async fn flush_for_engine_measurement(
+    solid: &Solid,
+    measuring: &str,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<(), KclError> {
+    if args.ctx.is_mock() {
+        return Err(needs_engine(measuring, args.source_range));
+    }
+    args.flush_batch_for_solid_set(exec_state, vec![Box::new(solid.clone())])
+        .await
+}
+
+/// The length of a straight edge of a solid, from the engine.
+async fn engine_edge_length(
+    solid: &Solid,
+    edge_id: Uuid,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<f64, KclError> {
+    flush_for_engine_measurement(solid, "Measuring the edges of this solid", exec_state, args).await?;
+
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::CurveGetType { curve_id: edge_id }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::CurveGetType(curve),
+    } = &resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("mcmd::CurveGetType response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    if curve.curve_type != CurveType::Line {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "Only straight edges can be measured on solids which aren't just an extruded profile".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::CurveGetEndPoints { curve_id: edge_id }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::CurveGetEndPoints(ends),
+    } = &resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("mcmd::CurveGetEndPoints response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    let start = point3d([ends.start.x.0, ends.start.y.0, ends.start.z.0]);
+    let end = point3d([ends.end.x.0, ends.end.y.0, ends.end.z.0]);
+    Ok(norm(sub(end, start)))
+}
+
+/// The area of a face of a solid, from the engine.
+async fn engine_face_area(
+    solid: &Solid,
+    face: &FaceTag,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<f64, KclError> {
+    flush_for_engine_measurement(solid, "Measuring the faces of this solid", exec_state, args).await?;
+    let face_id = face.get_face_id(solid, exec_state, args, false).await?;
+
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::SurfaceArea {
+                entity_ids: vec![face_id],
+                output_unit: area_unit(args.ctx.settings.units),
+            }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::SurfaceArea(area),
+    } = &resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("mcmd::SurfaceArea response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    Ok(area.surface_area)
+}
+
+/// The unit normal of a planar face of a solid, from the engine.
+async fn engine_face_normal(
+    solid: &Solid,
+    face: &FaceTag,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Point3d, KclError> {
+    flush_for_engine_measurement(solid, "Measuring the faces of this solid", exec_state, args).await?;
+    let face_id = face.get_face_id(solid, exec_state, args, true).await?;
+
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::FaceIsPlanar { object_id: face_id }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::FaceIsPlanar(planar),
+    } = &resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("mcmd::FaceIsPlanar response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    planar
+        .z_axis
+        .and_then(|z| normalize(point3d([z.x, z.y, z.z])))
+        .ok_or_else(|| {
+            KclError::Semantic(KclErrorDetails {
+                message: "The face isn't planar, so it has no normal".to_string(),
+                source_ranges: vec![args.source_range],
+            })
+        })
+}
+
+/// Areas come back from the engine in the square of the program's length unit.
+fn area_unit(units: UnitLength) -> UnitArea {
+    match units {
+        UnitLength::Cm => UnitArea::SquareCentimeters,
+        UnitLength::Ft => UnitArea::SquareFeet,
+        UnitLength::In => UnitArea::SquareInches,
+        UnitLength::M => UnitArea::SquareMeters,
+        UnitLength::Mm => UnitArea::SquareMillimeters,
+        UnitLength::Yd => UnitArea::SquareYards,
+    }
+}
+
+/// How much the frame scales lengths in its plane, if it scales them the same in every
+/// direction, which is true unless the solid was scaled unevenly.
+fn uniform_scale(frame: &Frame) -> Option<f64> {
+    let (x, y) = (norm(frame.x_axis), norm(frame.y_axis));
+    let square = dot(frame.x_axis, frame.y_axis).abs() <= 1e-9 * x * y;
+    (square && (x - y).abs() <= 1e-9 * x).then_some(x)
+}
+
+/// The segment as points in global coordinates.
+fn polyline(segment: &CurveSegment, frame: &Frame) -> Vec<Point3d> {
+    let steps = match segment {
+        CurveSegment::Line { .. } => 1,
+        CurveSegment::Arc { .. } => ARC_STEPS,
+    };
+    (0..=steps)
+        .map(|i| frame.point(segment.point_at(segment.length() * i as f64 / steps as f64)))
+        .collect()
+}
+
+fn length_3d(segment: &CurveSegment, frame: &Frame) -> f64 {
+    if let (CurveSegment::Arc { .. }, Some(scale)) = (segment, uniform_scale(frame)) {
+        return segment.length() * scale;
+    }
+    polyline(segment, frame).windows(2).map(|w| norm(sub(w[1], w[0]))).sum()
+}
+
+/// The area swept by moving the segment along the extrusion.
+fn swept_area(segment: &CurveSegment, frame: &Frame, extrusion: Point3d) -> f64 {
+    let perpendicular = dot(extrusion, frame.x_axis).abs() <= 1e-9 * norm(extrusion) * norm(frame.x_axis)
+        && dot(extrusion, frame.y_axis).abs() <= 1e-9 * norm(extrusion) * norm(frame.y_axis);
+    if let (CurveSegment::Arc { .. }, Some(scale), true) = (segment, uniform_scale(frame), perpendicular) {
+        return segment.length() * scale * norm(extrusion);
+    }
+    polyline(segment, frame)
+        .windows(2)
+        .map(|w| norm(cross(sub(w[1], w[0]), extrusion)))
+        .sum()
+}
+
+/// Get the length of an edge.
+pub async fn edge_length(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, edge): (Box<Solid>, EdgeReference) = args.get_solid_and_edge()?;
+
+    let length = inner_edge_length(solid, edge, exec_state, &args).await?;
+    args.make_user_val_from_f64(length)
+}
+
+/// Get the length of an edge of a solid, given by the tag of a segment
+/// of its profile, for the edge on the face the solid was sketched on, or
+/// by the edge's id, such as those `edges` finds.
+///
+/// Solids which are still just an extruded profile are measured from
+/// it. Solids which have been filleted, chamfered, shelled or had holes
+/// or other features added are measured by the engine, which can only
+/// measure their straight edges.
+///
+/// ```no_run,no_image
+/// const slot = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %, $bottom)
+///   |> tangentialArc({ radius: 5, offset: 180 }, %, $end)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(4, %)
+///
+/// // The length of the rounded end, 5 * PI.
+/// const endLength = edgeLength(slot, end)
+/// // The length of the edges up the sides, 4.
+/// const sides = edges(slot, { parallelTo: 'Z' })
+/// const upright = edgeLength(slot, sides[0])
+/// ```
+#[stdlib {
+    name = "edgeLength",
+}]
+async fn inner_edge_length(
+    solid: Box<Solid>,
+    edge: EdgeReference,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<f64, KclError> {
+    if is_plain_extrusion(&solid, exec_state) {
+        if let Some(length) = solid_edge_length(&solid, &edge, exec_state, args.source_range)? {
+            return Ok(length);
+        }
+    }
+    let edge_id = edge.get_engine_id(exec_state, args)?;
+    engine_edge_length(&solid, edge_id, exec_state, args).await
+}
+
+/// Get the area of a face.
+pub async fn face_area(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, face): (Box<Solid>, FaceTag) = args.get_solid_and_face_tag()?;
+
+    let area = inner_face_area(solid, face, exec_state, &args).await?;
+    args.make_user_val_from_f64(area)
+}
+
+/// Get the area of a face of a solid, given by the face's tag or
+/// `'start'`/`'end'`. Faces of solids which have had fillets, chamfers,
+/// holes, shells or other features added to them are measured by the
+/// engine.
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %, $side)
+///   |> line([0, 10], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(2, %)
+///
+/// // Size a gasket from the area of the top.
+/// const topArea = faceArea(plate, 'end')
+/// const sideArea = faceArea(plate, side)
+/// ```
+#[stdlib {
+    name = "faceArea",
+}]
+async fn inner_face_area(
+    solid: Box<Solid>,
+    face: FaceTag,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<f64, KclError> {
+    if is_plain_extrusion(&solid, exec_state) {
+        return solid_face_area(&solid, &face, args.source_range);
+    }
+    engine_face_area(&solid, &face, exec_state, args).await
+}
+
+/// Get the normal of a face.
+pub async fn face_normal(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, face): (Box<Solid>, FaceTag) = args.get_solid_and_face_tag()?;
+
+    let normal = inner_face_normal(solid, face, exec_state, &args).await?;
+    args.make_user_val_from_f64_array(normal.to_vec())
+}
+
+/// Get the unit normal of a planar face of a solid, pointing out of the
+/// solid, as `[x, y, z]`. Faces of solids which have had features added
+/// to them are measured by the engine.
+///
+/// ```no_run,no_image
+/// const block = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %, $side)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// // Push a peg out of the side, along its normal.
+/// const out = faceNormal(block, side)
+/// const peg = startSketchOn('XY')
+///   |> circle({ center: [5 + out[0] * 10, 5 + out[1] * 10], radius: 1 }, %)
+///   |> extrude(2, %)
+/// ```
+#[stdlib {
+    name = "faceNormal",
+}]
+async fn inner_face_normal(
+    solid: Box<Solid>,
+    face: FaceTag,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<[f64; 3], KclError> {
+    let normal = if is_plain_extrusion(&solid, exec_state) {
+        solid_face_normal(&solid, &face, args.source_range)?
+    } else {
+        engine_face_normal(&solid, &face, exec_state, args).await?
+    };
+    Ok([normal.x, normal.y, normal.z])
+}
+
+/// Get the angle between two faces.
+pub async fn angle_between_faces(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, a, b): (Box<Solid>, FaceTag, FaceTag) = args.get_solid_and_two_face_tags()?;
+
+    let angle = inner_angle_between_faces(solid, a, b, exec_state, &args).await?;
+    args.make_user_val_from_f64(angle)
+}
+
+/// Get the angle in degrees between the normals of two planar faces of a
+/// solid. Faces which are square to each other are 90 degrees apart, and
+/// the angle inside the solid where they meet is 180 minus the result.
+/// Faces of solids which have had features added to them are measured
+/// by the engine.
+///
+/// ```no_run,no_image
+/// const wedge = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %, $base)
+///   |> line([-10, 10], %, $slope)
+///   |> close(%)
+///   |> extrude(5, %)
+///
+/// // 135
+/// const between = angleBetweenFaces(wedge, base, slope)
+/// ```
+#[stdlib {
+    name = "angleBetweenFaces",
+}]
+async fn inner_angle_between_faces(
+    solid: Box<Solid>,
+    face_a: FaceTag,
+    face_b: FaceTag,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<f64, KclError> {
+    if is_plain_extrusion(&solid, exec_state) {
+        return solid_angle_between_faces(&solid, &face_a, &face_b, args.source_range);
+    }
+    let a = engine_face_normal(&solid, &face_a, exec_state, args).await?;
+    let b = engine_face_normal(&solid, &face_b, exec_state, args).await?;
+    Ok(dot(a, b).clamp(-1.0, 1.0).acos().to_degrees())
+}
+
+/// Get the bounding box of a solid.
+pub async fn bounding_box(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let solid: Box<Solid> = args.get_data()?;
+
+    let bbox = inner_bounding_box(solid, exec_state, &args).await?;
+    Ok(KclValue::UserVal(UserVal::new(
+        vec![Metadata {
+            source_range: args.source_range,
+        }],
+        bbox,
+    )))
+}
+
+/// Get the smallest box lined up with the global axes which an extruded
+/// solid fits in, as `{ min: [x, y, z], max: [x, y, z] }`. Revolved and
+/// lofted solids, copies made by patterns and solids which have had
+/// features added to them aren't supported.
+///
+/// ```no_run,no_image
+/// const part = startSketchOn('XZ')
+///   |> circle({ center: [0, 0], radius: 5 }, %)
+///   |> extrude(20, %)
+///
+/// const bbox = boundingBox(part)
+/// const width = bbox.max[0] - bbox.min[0]
+///
+/// // Sit a base plate under the part, as wide as it is.
+/// const base = startSketchOn(offsetPlane('XY', bbox.min[2]))
+///   |> startProfileAt([bbox.min[0], bbox.min[1]], %)
+///   |> line([width, 0], %)
+///   |> line([0, bbox.max[1] - bbox.min[1]], %)
+///   |> line([-width, 0], %)
+///   |> close(%)
+///   |> extrude(-2, %)
+/// ```
+#[stdlib {
+    name = "boundingBox",
+}]
+async fn inner_bounding_box(
+    solid: Box<Solid>,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<BoundingBox3d, KclError> {
+    expect_plain_extrusion(&solid, exec_state, "measured", args.source_range)?;
+    solid_bounding_box(&solid, args.source_range)
+}
+
+/// Measure the distance between two entities.
+pub async fn measure_distance(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (a, b): (MeasureEntity, MeasureEntity) = args.get_two_measure_entities()?;
+
+    let distance = inner_measure_distance(a, b, exec_state, &args).await?;
+    args.make_user_val_from_f64(distance)
+}
+
+/// Measure the shortest distance between two solids or tagged edges.
+/// This needs the engine, so it's an error when executing without one.
+///
+/// ```no_run,no_image
+/// const a = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %, $edgeA)
+///   |> line([0, 10], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(5, %)
+///
+/// const b = startSketchOn('XY')
+///   |> circle({ center: [30, 5], radius: 5 }, %)
+///   |> extrude(5, %)
+///
+/// // 15
+/// const gap = measureDistance(a, b)
+/// const toEdge = measureDistance(edgeA, b)
+/// ```
+#[stdlib {
+    name = "measureDistance",
+    needs_engine = true,
+}]
+async fn inner_measure_distance(
+    a: MeasureEntity,
+    b: MeasureEntity,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<f64, KclError> {
+    if args.ctx.is_mock() {
+        return Err(needs_engine("Measuring distances", args.source_range));
+    }
+
+    let mut ids = vec![];
+    for entity in [a, b] {
+        ids.push(match entity {
+            MeasureEntity::Solid(solid) => solid.id,
+            MeasureEntity::Tag(tag) => args.get_tag_engine_info(exec_state, &tag)?.id,
+        });
+    }
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::EntityGetDistance {
+                entity_id1: ids[0],
+                entity_id2: ids[1],
+                distance_type: DistanceType::Euclidean {},
+            }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::EntityGetDistance(distance),
+    } = &resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("mcmd::EntityGetDistance response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+
+    Ok(distance.min_distance.0)
+}
+
+/// Get the mass of solids.
+pub async fn mass(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let solid_set: SolidSet = args.get_data()?;
+
+    let mass = inner_mass(solid_set, exec_state, &args).await?;
+    args.make_user_val_from_f64(mass)
+}
+
+/// Get the mass of solids, in kilograms, from their volume and the density
+/// of the material they were given with `material`. This needs the
+/// engine, so it's an error when executing without one.
+///
+/// ```no_run,no_image
+/// const bracket = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 3], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///   |> material({ name: "Steel", density: 7850 }, %)
+///
+/// // 600 cubic millimeters of steel.
+/// const bracketMass = mass(bracket)
+/// assertEqual(bracketMass, 0.00471, 0.00001, "The bracket should weigh 4.71 grams")
+/// ```
+#[stdlib {
+    name = "mass",
+    needs_engine = true,
+}]
+async fn inner_mass(solid_set: SolidSet, exec_state: &mut ExecState, args: &Args) -> Result<f64, KclError> {
+    let solids: Vec<Box<Solid>> = solid_set.into();
+    let mut densities = vec![];
+    for solid in &solids {
+        let material = solid.material.as_ref().ok_or_else(|| {
+            KclError::Semantic(KclErrorDetails {
+                message: "Expected the solid to have a material, given with `material`".to_string(),
+                source_ranges: vec![args.source_range],
+            })
+        })?;
+        densities.push(material.density);
+    }
+    if args.ctx.is_mock() {
+        return Err(needs_engine("Measuring mass", args.source_range));
+    }
+
+    // The mass is of the solid with its fillets and chamfers.
+    args.flush_batch_for_solid_set(exec_state, solids.clone()).await?;
+
+    let mut total = 0.0;
+    for (solid, density) in solids.iter().zip(densities) {
+        let resp = args
+            .send_modeling_cmd(
+                exec_state.id_generator.next_uuid(),
+                ModelingCmd::from(mcmd::Mass {
+                    entity_ids: vec![solid.id],
+                    material_density: density,
+                    material_density_unit: UnitDensity::KilogramsPerCubicMeter,
+                    output_unit: UnitMass::Kilograms,
+                }),
+            )
+            .await?;
+        let OkWebSocketResponseData::Modeling {
+            modeling_response: OkModelingCmdResponse::Mass(mass),
+        } = &resp
+        else {
+            return Err(KclError::Engine(KclErrorDetails {
+                message: format!("mcmd::Mass response was not as expected: {:?}", resp),
+                source_ranges: vec![args.source_range],
+            }));
+        };
+        total += mass.mass;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(scale: [f64; 3]) -> Frame {
+        Frame {
+            origin: Point3d::ZERO,
+            x_axis: Point3d::new(scale[0], 0.0, 0.0),
+            y_axis: Point3d::new(0.0, scale[1], 0.0),
+            z_axis: Point3d::new(0.0, 0.0, scale[2]),
+        }
+    }
+
+    fn half_disc() -> Vec<CurveSegment> {
+        // The top half of a circle of radius 2 around the origin, counterclockwise.
+        vec![
+            CurveSegment::Line {
+                from: [-2.0, 0.0],
+                to: [2.0, 0.0],
+            },
+            CurveSegment::Arc {
+                center: [0.0, 0.0],
+                radius: 2.0,
+                start: 0.0,
+                sweep: std::f64::consts::PI,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_signed_area() {
+        let area: f64 = half_disc().iter().map(CurveSegment::signed_area).sum();
+        assert!((area - 2.0 * std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lengths_and_areas_when_scaled() {
+        let arc = &half_disc()[1];
+        let expected = 2.0 * std::f64::consts::PI;
+        assert!((length_3d(arc, &frame([1.0, 1.0, 1.0])) - expected).abs() < 1e-9);
+        assert!((length_3d(arc, &frame([3.0, 3.0, 3.0])) - 3.0 * expected).abs() < 1e-9);
+        // Squashed flat, the arc is as long as it is wide.
+        assert!((length_3d(arc, &frame([1.0, 1e-12, 1.0])) - 4.0).abs() < 1e-6);
+
+        let extrusion = Point3d::new(0.0, 0.0, 5.0);
+        assert!((swept_area(arc, &frame([1.0, 1.0, 1.0]), extrusion) - 5.0 * expected).abs() < 1e-9);
+        let line = &half_disc()[0];
+        assert!((swept_area(line, &frame([1.0, 1.0, 1.0]), Point3d::new(0.0, 3.0, 4.0)) - 20.0).abs() < 1e-9);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_only_plain_extrusions_are_measured() {
+        let plate = r#"const plate = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([20, 0], %, $edge)
+  |> line([0, 10], %)
+  |> line([-20, 0], %)
+  |> close(%)
+  |> extrude(2, %)
+const bbox = boundingBox(plate)
+"#;
+        let ctx = crate::executor::ExecutorContext::new_mock(Default::default())
+            .await
+            .unwrap();
+        let run = |code: String| {
+            let ctx = ctx.clone();
+            async move {
+                let program = crate::parser::parse(&code).unwrap();
+                ctx.run(&program, None, crate::executor::IdGenerator::default(), None)
+                    .await
+            }
+        };
+        run(plate.to_string()).await.unwrap();
+
+        // After a fillet the plate isn't its profile extruded, however it's referred to.
+        for measured in ["rounded", "plate"] {
+            let code = format!(
+                "{}const rounded = fillet({{ radius: 1, tags: [getOppositeEdge(edge)] }}, plate)\nboundingBox({})\n",
+                plate, measured
+            );
+            let err = run(code).await.unwrap_err();
+            assert!(err.message().contains("can be measured"), "{}", err.message());
+        }
+
+        // Edges are measured whether they're given by a tag or by the ids `edges` finds.
+        let code = format!(
+            "{}const byTag = edgeLength(plate, edge)
+const topEdges = edges(plate, {{ onFace: 'end' }})
+const top = edgeLength(plate, topEdges[0])
+const uprightEdges = edges(plate, {{ parallelTo: 'Z' }})
+const upright = edgeLength(plate, uprightEdges[0])
+",
+            plate
+        );
+        let exec_state = run(code).await.unwrap();
+        for (name, expected) in [("byTag", 20.0), ("top", 20.0), ("upright", 2.0)] {
+            let value = exec_state.memory.get(name, SourceRange::default()).unwrap();
+            assert_eq!(value.get_json_value().unwrap().as_f64(), Some(expected), "{}", name);
+        }
+
+        // Anything else is measured by the engine, which there isn't in mock mode.
+        let code = format!(
+            "{}const rounded = fillet({{ radius: 1, tags: [getOppositeEdge(edge)] }}, plate)\nfaceArea(rounded, 'end')\n",
+            plate
+        );
+        let err = run(code).await.unwrap_err();
+        assert!(err.message().contains("needs the engine"), "{}", err.message());
+    }
+}
@@ -1,16 +1,29 @@
 //! Standard library mirror.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use derive_docs::stdlib;
-use kcmc::{each_cmd as mcmd, ModelingCmd};
+use kcmc::{
+    each_cmd as mcmd,
+    shared::{Angle, OriginType, Rotation, Transform},
+    ModelingCmd,
+};
 use kittycad_modeling_cmds::{self as kcmc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::KclError,
-    executor::{ExecState, KclValue, Sketch, SketchSet},
-    std::{revolve::AxisOrEdgeReference, Args},
+    errors::{KclError, KclErrorDetails},
+    executor::{ExecState, KclValue, Plane, Point3d, Sketch, SketchSet, Solid, SolidSet, Transform3d},
+    std::{
+        datums::face_frame,
+        patterns::send_pattern_transform,
+        revolve::AxisOrEdgeReference,
+        sketch::{FaceTag, SketchData},
+        transform::{copied_ids, transform_solid, use_copied_ids},
+        Args,
+    },
 };
 
 /// Data for a mirror.
@@ -130,6 +143,12 @@ async fn inner_mirror_2d(
             )
             .await?;
         }
+        AxisOrEdgeReference::Datum(_) => {
+            return Err(KclError::Semantic(KclErrorDetails {
+                message: "Mirroring a sketch across a datum axis is not supported yet".to_string(),
+                source_ranges: vec![args.source_range],
+            }));
+        }
         AxisOrEdgeReference::Edge(edge) => {
             let edge_id = edge.get_engine_id(exec_state, &args)?;
 
@@ -146,3 +165,227 @@ async fn inner_mirror_2d(
 
     Ok(starting_sketches)
 }
+
+/// Mirror solids across a plane or a planar face.
+pub async fn mirror_3d(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid_set, data, face): (SolidSet, SketchData, Option<FaceTag>) = args.get_mirror_3d_args()?;
+
+    let solids = inner_mirror_3d(solid_set, data, face, exec_state, &args).await?;
+    Ok(solids.into())
+}
+
+/// Mirror solids across a plane or a planar face, creating a mirrored copy
+/// of each one.
+///
+/// The solids come first, then the mirror. The mirror can be one of the
+/// standard planes, a plane made with `offsetPlane` or `planeOnFace`, or a
+/// face of an extruded solid given by the solid and the face's tag (or
+/// `'start'`/`'end'`), the same way as in `startSketchOn`. The original
+/// solids are left in place, only the copies are returned.
+///
+/// The copies keep the tags of the solids they're copied from, naming the
+/// mirrored faces and edges, which can be sketched on, filleted or chamfered.
+/// As when a sketch is extruded twice, a tag names the face of the latest
+/// solid made with it, so after mirroring one solid its tags name the copy's
+/// faces.
+///
+/// ```no_run,no_image
+/// // Model half of a bracket, then mirror it to make the other half.
+/// const half = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 5], %)
+///   |> line([-15, 10], %)
+///   |> lineTo([0, 15], %)
+///   |> close(%)
+///   |> extrude(4, %)
+///
+/// const otherHalf = mirror3d(half, 'YZ')
+/// ```
+///
+/// ```no_run,no_image
+/// // Mirror a boss across a plane offset from the origin.
+/// const boss = startSketchOn('XY')
+///   |> circle({ center: [5, 5], radius: 2 }, %)
+///   |> extrude(6, %)
+///
+/// const mirrored = mirror3d(boss, offsetPlane('YZ', 10))
+/// ```
+///
+/// ```no_run,no_image
+/// // Mirror a peg across the tagged side face of a block.
+/// const block = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %, $side)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// const peg = startSketchOn('XY')
+///   |> circle({ center: [5, 5], radius: 1 }, %)
+///   |> extrude(15, %)
+///
+/// const mirroredPeg = mirror3d(peg, block, side)
+/// ```
+#[stdlib {
+    name = "mirror3d",
+}]
+async fn inner_mirror_3d(
+    solid_set: SolidSet,
+    data: SketchData,
+    face: Option<FaceTag>,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Vec<Box<Solid>>, KclError> {
+    let (origin, normal) = match data {
+        SketchData::Plane(plane_data) => {
+            let plane = Plane::from_plane_data(plane_data, exec_state);
+            (plane.origin, plane.z_axis)
+        }
+        SketchData::Solid(solid) => {
+            let Some(face) = face else {
+                return Err(KclError::Type(KclErrorDetails {
+                    message: "Expected a tag for the face to mirror across".to_string(),
+                    source_ranges: vec![args.source_range],
+                }));
+            };
+            let frame = face_frame(&solid, &face, args.source_range)?;
+            (frame.origin, frame.z_axis)
+        }
+    };
+    let (normal, translate) = reflection(origin, normal).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "The normal of the mirror plane cannot be the zero vector".to_string(),
+            source_ranges: vec![args.source_range],
+        })
+    })?;
+    // A reflection across a plane through the global origin is an inversion
+    // through the origin followed by a half turn about the plane's normal.
+    let transform = Transform {
+        replicate: true,
+        scale: Point3d::new(-1.0, -1.0, -1.0).into(),
+        translate: translate.into(),
+        rotation: Rotation {
+            axis: normal.into(),
+            angle: Angle::from_degrees(180.0),
+            origin: OriginType::Global,
+        },
+    };
+
+    // Flush the batch for our fillets/chamfers if there are any.
+    // If we do not flush these, then you won't be able to mirror something with fillets.
+    args.flush_batch_for_solid_set(exec_state, solid_set.clone().into())
+        .await?;
+
+    let starting_solids: Vec<Box<Solid>> = solid_set.into();
+    let mirror = reflection_transform(normal, translate);
+
+    let mut solids = Vec::new();
+    for solid in starting_solids {
+        let ids = if args.ctx.is_mock() {
+            HashMap::from([(solid.id, exec_state.id_generator.next_uuid())])
+        } else {
+            // The first solid returned is the original, the rest are the copies.
+            let copies = send_pattern_transform(vec![transform.clone()], &solid, exec_state, args).await?;
+            let id = copies.last().map(|copy| copy.id).unwrap_or(solid.id);
+            copied_ids(&solid, id, exec_state, args).await?
+        };
+        solids.push(mirrored_copy(&solid, &ids, &mirror));
+    }
+    Ok(solids)
+}
+
+/// The copy of a solid, mirrored by `mirror`, with its faces and edges (and so its tags)
+/// referring to the copy's by their ids from [`copied_ids`].
+fn mirrored_copy(solid: &Solid, ids: &HashMap<uuid::Uuid, uuid::Uuid>, mirror: &Transform3d) -> Box<Solid> {
+    let mut copy = Box::new(solid.clone());
+    use_copied_ids(&mut copy, ids);
+    transform_solid(&mut copy, mirror);
+    copy
+}
+
+/// The reflection across the plane with the unit normal `normal`, moved by `translate`,
+/// as from [`reflection`].
+fn reflection_transform(normal: Point3d, translate: Point3d) -> Transform3d {
+    let n = [normal.x, normal.y, normal.z];
+    let t = [translate.x, translate.y, translate.z];
+    let mut matrix = Transform3d::IDENTITY.0;
+    for (i, row) in matrix.iter_mut().take(3).enumerate() {
+        for (j, cell) in row.iter_mut().take(3).enumerate() {
+            *cell -= 2.0 * n[i] * n[j];
+        }
+        row[3] = t[i];
+    }
+    Transform3d(matrix)
+}
+
+/// Split a reflection across the plane through `origin` with the given normal
+/// into the unit normal of the plane, and the translation which moves a
+/// reflection across the parallel plane through the global origin onto it.
+/// Returns `None` if the normal is the zero vector.
+fn reflection(origin: Point3d, normal: Point3d) -> Option<(Point3d, Point3d)> {
+    let len = (normal.x * normal.x + normal.y * normal.y + normal.z * normal.z).sqrt();
+    if len <= f64::EPSILON {
+        return None;
+    }
+    let n = Point3d::new(normal.x / len, normal.y / len, normal.z / len);
+    let offset = 2.0 * (origin.x * n.x + origin.y * n.y + origin.z * n.z);
+    Some((n, Point3d::new(n.x * offset, n.y * offset, n.z * offset)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reflection_offset_plane() {
+        // The YZ plane moved 10 along X, with a normal which isn't unit length.
+        let (normal, translate) = reflection(Point3d::new(10.0, 3.0, -2.0), Point3d::new(2.0, 0.0, 0.0)).unwrap();
+        assert_eq!(normal, Point3d::new(1.0, 0.0, 0.0));
+        assert_eq!(translate, Point3d::new(20.0, 0.0, 0.0));
+
+        assert!(reflection(Point3d::ZERO, Point3d::ZERO).is_none());
+
+        let mirror = reflection_transform(normal, translate);
+        assert_eq!(
+            mirror.transform_point(Point3d::new(4.0, 3.0, -2.0)),
+            Point3d::new(16.0, 3.0, -2.0)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_mirrored_copy_keeps_tags() {
+        let code = r#"const block = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %, $side)
+  |> line([0, 10], %)
+  |> line([-10, 0], %)
+  |> close(%)
+  |> extrude(10, %)
+const other = mirror3d(block, 'XZ')
+"#;
+        let ctx = crate::executor::ExecutorContext::new_mock(Default::default())
+            .await
+            .unwrap();
+        let program = crate::parser::parse(code).unwrap();
+        let exec_state = ctx
+            .run(&program, None, crate::executor::IdGenerator::default(), None)
+            .await
+            .unwrap();
+
+        let KclValue::Solid(block) = exec_state.memory.get("block", Default::default()).unwrap() else {
+            panic!("Expected a solid");
+        };
+        let KclValue::Solid(other) = exec_state.memory.get("other", Default::default()).unwrap() else {
+            panic!("Expected a solid");
+        };
+        assert_ne!(other.id, block.id);
+        let side = other.sketch.tags.get("side").unwrap();
+        assert_eq!(side.info.as_ref().unwrap().sketch, other.id);
+
+        // The side face is mirrored, so its normal still points out of the copy.
+        let frame = face_frame(other, &FaceTag::Tag(Box::new(side.clone())), Default::default()).unwrap();
+        assert!((frame.z_axis.y - 1.0).abs() < 1e-9, "{:?}", frame.z_axis);
+    }
+}
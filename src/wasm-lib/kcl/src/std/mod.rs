@@ -1,26 +1,35 @@
 //! Functions implemented for language execution.
 
+pub mod appearance;
 pub mod args;
 pub mod array;
+pub mod assembly;
 pub mod assert;
+pub mod bom;
 pub mod chamfer;
 pub mod convert;
+pub mod datums;
 pub mod extrude;
 pub mod fillet;
 pub mod helix;
+pub mod holes;
 pub mod import;
 pub mod kcl_stdlib;
 pub mod loft;
 pub mod math;
+pub mod measure;
 pub mod mirror;
 pub mod patterns;
 pub mod planes;
 pub mod polar;
+pub mod query;
 pub mod revolve;
 pub mod segment;
 pub mod shapes;
 pub mod shell;
 pub mod sketch;
+pub mod text;
+pub mod transform;
 pub mod types;
 pub mod units;
 pub mod utils;
@@ -91,12 +100,20 @@ lazy_static! {
         Box::new(crate::std::sketch::TangentialArcToRelative),
         Box::new(crate::std::sketch::BezierCurve),
         Box::new(crate::std::sketch::Hole),
+        Box::new(crate::std::text::Text),
         Box::new(crate::std::mirror::Mirror2D),
+        Box::new(crate::std::mirror::Mirror3D),
+        Box::new(crate::std::transform::Translate),
+        Box::new(crate::std::transform::Rotate),
+        Box::new(crate::std::transform::ScaleBy),
+        Box::new(crate::std::transform::TransformBy),
         Box::new(crate::std::patterns::PatternLinear2D),
         Box::new(crate::std::patterns::PatternLinear3D),
         Box::new(crate::std::patterns::PatternCircular2D),
         Box::new(crate::std::patterns::PatternCircular3D),
         Box::new(crate::std::patterns::PatternTransform),
+        Box::new(crate::std::patterns::PatternAlongPath),
+        Box::new(crate::std::patterns::PatternGridOnFace),
         Box::new(crate::std::array::Reduce),
         Box::new(crate::std::array::Map),
         Box::new(crate::std::chamfer::Chamfer),
@@ -107,9 +124,29 @@ lazy_static! {
         Box::new(crate::std::helix::Helix),
         Box::new(crate::std::shell::Shell),
         Box::new(crate::std::shell::Hollow),
+        Box::new(crate::std::holes::HoleFeature),
         Box::new(crate::std::revolve::Revolve),
         Box::new(crate::std::loft::Loft),
         Box::new(crate::std::planes::OffsetPlane),
+        Box::new(crate::std::datums::DatumPoint),
+        Box::new(crate::std::datums::DatumAxis),
+        Box::new(crate::std::datums::PlaneThroughPoints),
+        Box::new(crate::std::datums::PlaneAtAngle),
+        Box::new(crate::std::datums::PlaneNormalToPath),
+        Box::new(crate::std::datums::PlaneOnFace),
+        Box::new(crate::std::measure::EdgeLength),
+        Box::new(crate::std::measure::FaceArea),
+        Box::new(crate::std::measure::FaceNormal),
+        Box::new(crate::std::measure::AngleBetweenFaces),
+        Box::new(crate::std::measure::BoundingBox),
+        Box::new(crate::std::measure::MeasureDistance),
+        Box::new(crate::std::measure::Mass),
+        Box::new(crate::std::query::Edges),
+        Box::new(crate::std::query::Faces),
+        Box::new(crate::std::assembly::Instance),
+        Box::new(crate::std::bom::PartInfo),
+        Box::new(crate::std::appearance::Appearance),
+        Box::new(crate::std::appearance::Material),
         Box::new(crate::std::import::Import),
         Box::new(crate::std::math::Cos),
         Box::new(crate::std::math::Sin),
@@ -22,7 +22,13 @@ use crate::{
         ExecState, Geometries, Geometry, KclValue, Point3d, Sketch, SketchSet, Solid, SolidSet, SourceRange, UserVal,
     },
     function_param::FunctionParam,
-    std::{types::Uint, Args},
+    std::{
+        datums::{face_frame, Axis3d},
+        sketch::FaceTag,
+        types::Uint,
+        utils::PathCurve,
+        Args,
+    },
 };
 
 const MUST_HAVE_ONE_INSTANCE: &str = "There must be at least 1 instance of your geometry";
@@ -41,6 +47,9 @@ pub struct LinearPattern2dData {
     pub distance: f64,
     /// The axis of the pattern. This is a 2D vector.
     pub axis: [f64; 2],
+    /// Indices of instances to leave out of the pattern. Index 0 is the
+    /// original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
 }
 
 /// Data for a linear pattern on a 3D model.
@@ -57,6 +66,9 @@ pub struct LinearPattern3dData {
     pub distance: f64,
     /// The axis of the pattern.
     pub axis: [f64; 3],
+    /// Indices of instances to leave out of the pattern. Index 0 is the
+    /// original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
 }
 
 pub enum LinearPattern {
@@ -86,13 +98,30 @@ impl LinearPattern {
             LinearPattern::ThreeD(lp) => lp.distance,
         }
     }
+
+    fn skip(&self) -> Vec<u32> {
+        match self {
+            LinearPattern::TwoD(lp) => lp.skip.iter().flatten().map(Uint::u32).collect(),
+            LinearPattern::ThreeD(lp) => lp.skip.iter().flatten().map(Uint::u32).collect(),
+        }
+    }
+}
+
+/// Options for a pattern made by a transform function.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternTransformData {
+    /// Indices of instances to leave out of the pattern. Index 0 is the
+    /// original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
 }
 
 /// A linear pattern
 /// Each element in the pattern repeats a particular piece of geometry.
 /// The repetitions can be transformed by the `transform` parameter.
 pub async fn pattern_transform(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
-    let (num_repetitions, transform, extr) = args.get_pattern_transform_args()?;
+    let (num_repetitions, transform, extr, data) = args.get_pattern_transform_args()?;
 
     let solids = inner_pattern_transform(
         num_repetitions,
@@ -104,6 +133,7 @@ pub async fn pattern_transform(exec_state: &mut ExecState, args: Args) -> Result
             memory: *transform.memory,
         },
         extr,
+        data,
         exec_state,
         &args,
     )
@@ -126,6 +156,9 @@ pub async fn pattern_transform(exec_state: &mut ExecState, args: Args) -> Result
 /// will be passed the argument `1`. This simplifies your math: the transform function can
 /// rely on id `0` being the original instance passed into the `patternTransform`. See the examples.
 ///
+/// Options can be given after the solid. Skipping an id with their `skip` list is the same
+/// as returning `replicate: false` from the transform function for it.
+///
 /// The transform function returns a transform object. All properties of the object are optional,
 /// they each default to "no change". So the overall transform object defaults to "no change" too.
 /// Its properties are:
@@ -273,6 +306,17 @@ pub async fn pattern_transform(exec_state: &mut ExecState, args: Args) -> Result
 /// // The 100 layers are replica of each other, with a slight transformation applied to each.
 /// let vase = layer() |> patternTransform(100, transform, %)
 /// ```
+/// ```no_run,no_image
+/// // A row of 8 pillars with the 3rd and 6th left out.
+/// fn transform = (id) => {
+///   return { translate: [10 * id, 0, 0] }
+/// }
+///
+/// const pillars = startSketchOn('XY')
+///   |> circle({ center: [0, 0], radius: 2 }, %)
+///   |> extrude(20, %)
+///   |> patternTransform(8, transform, %, { skip: [3, 6] })
+/// ```
 #[stdlib {
      name = "patternTransform",
  }]
@@ -280,6 +324,7 @@ async fn inner_pattern_transform<'a>(
     total_instances: u32,
     transform_function: FunctionParam<'a>,
     solid_set: SolidSet,
+    data: Option<PatternTransformData>,
     exec_state: &mut ExecState,
     args: &'a Args,
 ) -> Result<Vec<Box<Solid>>, KclError> {
@@ -291,8 +336,16 @@ async fn inner_pattern_transform<'a>(
             message: MUST_HAVE_ONE_INSTANCE.to_owned(),
         }));
     }
+    let skip = validate_skip(
+        data.and_then(|data| data.skip).iter().flatten().map(Uint::u32),
+        total_instances,
+        args.source_range,
+    )?;
     for i in 1..total_instances {
-        let t = make_transform(i, &transform_function, args.source_range, exec_state).await?;
+        let mut t = make_transform(i, &transform_function, args.source_range, exec_state).await?;
+        if skip.contains(&i) {
+            t.replicate = false;
+        }
         transform.push(t);
     }
     // Flush the batch for our fillets/chamfers if there are any.
@@ -315,7 +368,7 @@ async fn inner_pattern_transform<'a>(
     Ok(solids)
 }
 
-async fn send_pattern_transform(
+pub(crate) async fn send_pattern_transform(
     // This should be passed via reference, see
     // https://github.com/KittyCAD/modeling-app/issues/2821
     transform: Vec<Transform>,
@@ -484,6 +537,60 @@ mod tests {
         let actual = array_to_point3d(&input, Vec::new());
         assert_eq!(actual.unwrap(), expected);
     }
+
+    #[test]
+    fn test_validate_skip() {
+        let range = SourceRange::default();
+        assert_eq!(validate_skip([4, 2, 4], 5, range).unwrap(), vec![2, 4]);
+        assert_eq!(validate_skip([], 1, range).unwrap(), Vec::<u32>::new());
+        assert!(validate_skip([0], 5, range)
+            .unwrap_err()
+            .message()
+            .contains("cannot be skipped"));
+        assert!(validate_skip([5], 5, range)
+            .unwrap_err()
+            .message()
+            .contains("instances 0 to 4"));
+    }
+
+    #[test]
+    fn test_circular_pattern_center() {
+        let data = |axis: serde_json::Value, center: Option<[f64; 3]>| {
+            serde_json::from_value::<CircularPattern3dData>(serde_json::json!({
+                "instances": 4,
+                "axis": axis,
+                "center": center,
+                "arcDegrees": 360,
+                "rotateDuplicates": true,
+            }))
+            .unwrap()
+        };
+        let datum = serde_json::json!({ "origin": [1, 2, 3], "direction": [0, 0, 1] });
+        assert_eq!(data(datum.clone(), None).axis(), [0.0, 0.0, 1.0]);
+        assert_eq!(data(datum.clone(), None).center(), [1.0, 2.0, 3.0]);
+        assert_eq!(data(datum, Some([4.0, 5.0, 6.0])).center(), [4.0, 5.0, 6.0]);
+        assert_eq!(data(serde_json::json!([0, 1, 0]), None).center(), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_grid_offsets() {
+        let data = PatternGridData {
+            rows: Uint::new(2.0),
+            columns: Uint::new(3.0),
+            spacing: [10.0, 5.0],
+            skip: None,
+        };
+        assert_eq!(
+            data.offsets(),
+            vec![[10.0, 0.0], [20.0, 0.0], [0.0, 5.0], [10.0, 5.0], [20.0, 5.0]]
+        );
+    }
+
+    #[test]
+    fn test_angle_between() {
+        assert!((angle_between([1.0, 0.0], [0.0, 1.0]) - 90.0).abs() < 1e-9);
+        assert!((angle_between([1.0, 0.0], [0.0, -1.0]) + 90.0).abs() < 1e-9);
+    }
 }
 
 /// A linear pattern on a 2D sketch.
@@ -499,6 +606,12 @@ pub async fn pattern_linear_2d(exec_state: &mut ExecState, args: Args) -> Result
         }));
     }
 
+    validate_skip(
+        data.skip.iter().flatten().map(Uint::u32),
+        data.instances.u32(),
+        args.source_range,
+    )?;
+
     let sketches = inner_pattern_linear_2d(data, sketch_set, exec_state, args).await?;
     Ok(sketches.into())
 }
@@ -568,6 +681,12 @@ pub async fn pattern_linear_3d(exec_state: &mut ExecState, args: Args) -> Result
         }));
     }
 
+    validate_skip(
+        data.skip.iter().flatten().map(Uint::u32),
+        data.instances.u32(),
+        args.source_range,
+    )?;
+
     let solids = inner_pattern_linear_3d(data, solid_set, exec_state, args).await?;
     Ok(solids.into())
 }
@@ -698,7 +817,7 @@ async fn pattern_linear(
         }
     };
 
-    Ok(geometries)
+    remove_skipped(geometries, &data.skip(), exec_state, &args).await
 }
 
 /// Data for a circular pattern on a 2D sketch.
@@ -717,6 +836,9 @@ pub struct CircularPattern2dData {
     pub arc_degrees: f64,
     /// Whether or not to rotate the duplicates as they are copied.
     pub rotate_duplicates: bool,
+    /// Indices of instances to leave out of the pattern. Index 0 is the
+    /// original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
 }
 
 /// Data for a circular pattern on a 3D model.
@@ -729,19 +851,51 @@ pub struct CircularPattern3dData {
     /// there will be two copies -- the original, and one new copy.
     /// If instances is 1, this has no effect.
     pub instances: Uint,
-    /// The axis around which to make the pattern. This is a 3D vector.
-    pub axis: [f64; 3],
-    /// The center about which to make the pattern. This is a 3D vector.
-    pub center: [f64; 3],
+    /// The axis around which to make the pattern. This is a 3D vector, or a datum axis.
+    pub axis: PatternAxis,
+    /// The center about which to make the pattern. This is a 3D vector. It defaults to the
+    /// origin of a datum axis, or the global origin for an axis which is a vector.
+    #[serde(default)]
+    pub center: Option<[f64; 3]>,
     /// The arc angle (in degrees) to place the repetitions. Must be greater than 0.
     pub arc_degrees: f64,
     /// Whether or not to rotate the duplicates as they are copied.
     pub rotate_duplicates: bool,
+    /// Indices of instances to leave out of the pattern. Index 0 is the
+    /// original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
+}
+
+/// The axis of a circular pattern.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum PatternAxis {
+    /// A direction, through the pattern's center.
+    Vector([f64; 3]),
+    /// A datum axis.
+    Datum(Axis3d),
+}
+
+impl CircularPattern3dData {
+    fn axis(&self) -> [f64; 3] {
+        match &self.axis {
+            PatternAxis::Vector(axis) => *axis,
+            PatternAxis::Datum(axis) => axis.direction,
+        }
+    }
+
+    fn center(&self) -> [f64; 3] {
+        match &self.axis {
+            PatternAxis::Vector(_) => self.center.unwrap_or_default(),
+            PatternAxis::Datum(axis) => self.center.unwrap_or(axis.origin),
+        }
+    }
 }
 
 pub enum CircularPattern {
-    ThreeD(CircularPattern3dData),
-    TwoD(CircularPattern2dData),
+    ThreeD(Box<CircularPattern3dData>),
+    TwoD(Box<CircularPattern2dData>),
 }
 
 enum RepetitionsNeeded {
@@ -767,14 +921,14 @@ impl CircularPattern {
     pub fn axis(&self) -> [f64; 3] {
         match self {
             CircularPattern::TwoD(_lp) => [0.0, 0.0, 0.0],
-            CircularPattern::ThreeD(lp) => lp.axis,
+            CircularPattern::ThreeD(lp) => lp.axis(),
         }
     }
 
     pub fn center(&self) -> [f64; 3] {
         match self {
             CircularPattern::TwoD(lp) => [lp.center[0], lp.center[1], 0.0],
-            CircularPattern::ThreeD(lp) => lp.center,
+            CircularPattern::ThreeD(lp) => lp.center(),
         }
     }
 
@@ -799,12 +953,25 @@ impl CircularPattern {
             CircularPattern::ThreeD(lp) => lp.rotate_duplicates,
         }
     }
+
+    fn skip(&self) -> Vec<u32> {
+        match self {
+            CircularPattern::TwoD(lp) => lp.skip.iter().flatten().map(Uint::u32).collect(),
+            CircularPattern::ThreeD(lp) => lp.skip.iter().flatten().map(Uint::u32).collect(),
+        }
+    }
 }
 
 /// A circular pattern on a 2D sketch.
 pub async fn pattern_circular_2d(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
     let (data, sketch_set): (CircularPattern2dData, SketchSet) = args.get_data_and_sketch_set()?;
 
+    validate_skip(
+        data.skip.iter().flatten().map(Uint::u32),
+        data.instances.u32(),
+        args.source_range,
+    )?;
+
     let sketches = inner_pattern_circular_2d(data, sketch_set, exec_state, args).await?;
     Ok(sketches.into())
 }
@@ -848,7 +1015,7 @@ async fn inner_pattern_circular_2d(
     let mut sketches = Vec::new();
     for sketch in starting_sketches.iter() {
         let geometries = pattern_circular(
-            CircularPattern::TwoD(data.clone()),
+            CircularPattern::TwoD(Box::new(data.clone())),
             Geometry::Sketch(sketch.clone()),
             exec_state,
             args.clone(),
@@ -872,6 +1039,12 @@ async fn inner_pattern_circular_2d(
 pub async fn pattern_circular_3d(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
     let (data, solid_set): (CircularPattern3dData, SolidSet) = args.get_data_and_solid_set()?;
 
+    validate_skip(
+        data.skip.iter().flatten().map(Uint::u32),
+        data.instances.u32(),
+        args.source_range,
+    )?;
+
     let solids = inner_pattern_circular_3d(data, solid_set, exec_state, args).await?;
     Ok(solids.into())
 }
@@ -894,6 +1067,38 @@ pub async fn pattern_circular_3d(exec_state: &mut ExecState, args: Args) -> Resu
 ///        rotateDuplicates: true
 ///      }, %)
 /// ```
+///
+/// ```no_run,no_image
+/// // A bolt circle of 8 holes with two left out.
+/// const bolt = startSketchOn('XY')
+///   |> circle({ center: [20, 0], radius: 2 }, %)
+///   |> extrude(10, %)
+///   |> patternCircular3d({
+///        axis: [0, 0, 1],
+///        center: [0, 0, 0],
+///        instances: 8,
+///        arcDegrees: 360,
+///        rotateDuplicates: false,
+///        skip: [3, 4],
+///      }, %)
+/// ```
+///
+/// ```no_run,no_image
+/// // Fins around a datum axis, which is also the center of the pattern.
+/// const shaft = datumAxis({ origin: [0, 0, 0], direction: [0, 0, 1] })
+/// const fin = startSketchOn('XZ')
+///   |> startProfileAt([5, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 20], %)
+///   |> close(%)
+///   |> extrude(1, %)
+///   |> patternCircular3d({
+///        axis: shaft,
+///        instances: 6,
+///        arcDegrees: 360,
+///        rotateDuplicates: true
+///      }, %)
+/// ```
 #[stdlib {
     name = "patternCircular3d",
 }]
@@ -918,7 +1123,7 @@ async fn inner_pattern_circular_3d(
     let mut solids = Vec::new();
     for solid in starting_solids.iter() {
         let geometries = pattern_circular(
-            CircularPattern::ThreeD(data.clone()),
+            CircularPattern::ThreeD(Box::new(data.clone())),
             Geometry::Solid(solid.clone()),
             exec_state,
             args.clone(),
@@ -1008,5 +1213,364 @@ async fn pattern_circular(
         }
     };
 
-    Ok(geometries)
+    remove_skipped(geometries, &data.skip(), exec_state, &args).await
+}
+
+/// Check a pattern's skip list against its number of instances.
+/// Returns the sorted, de-duplicated indices to skip.
+fn validate_skip(
+    skip: impl IntoIterator<Item = u32>,
+    total_instances: u32,
+    source_range: SourceRange,
+) -> Result<Vec<u32>, KclError> {
+    let mut indices = Vec::new();
+    for i in skip {
+        if i == 0 {
+            return Err(KclError::Semantic(KclErrorDetails {
+                message: "The original instance (index 0) of a pattern cannot be skipped".to_string(),
+                source_ranges: vec![source_range],
+            }));
+        }
+        if i >= total_instances {
+            return Err(KclError::Semantic(KclErrorDetails {
+                message: format!(
+                    "Cannot skip instance {i}, the pattern only has instances 0 to {}",
+                    total_instances.saturating_sub(1)
+                ),
+                source_ranges: vec![source_range],
+            }));
+        }
+        indices.push(i);
+    }
+    indices.sort_unstable();
+    indices.dedup();
+    Ok(indices)
+}
+
+/// Delete the skipped instances of a pattern and leave them out of its result.
+async fn remove_skipped(
+    geometries: Geometries,
+    skip: &[u32],
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Geometries, KclError> {
+    if skip.is_empty() {
+        return Ok(geometries);
+    }
+
+    let ids: Vec<uuid::Uuid> = match &geometries {
+        Geometries::Sketches(sketches) => sketches.iter().map(|s| s.id).collect(),
+        Geometries::Solids(solids) => solids.iter().map(|s| s.id).collect(),
+    };
+    // The engine's linear and circular patterns can't leave instances out, so delete them.
+    let object_ids = skip.iter().filter_map(|i| ids.get(*i as usize)).copied().collect();
+    args.batch_modeling_cmd(
+        exec_state.id_generator.next_uuid(),
+        ModelingCmd::from(mcmd::RemoveSceneObjects { object_ids }),
+    )
+    .await?;
+
+    let keep = |i: usize| !skip.contains(&(i as u32));
+    Ok(match geometries {
+        Geometries::Sketches(sketches) => Geometries::Sketches(
+            sketches
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, s)| keep(i).then_some(s))
+                .collect(),
+        ),
+        Geometries::Solids(solids) => Geometries::Solids(
+            solids
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, s)| keep(i).then_some(s))
+                .collect(),
+        ),
+    })
+}
+
+/// Data for a pattern along a path.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternAlongPathData {
+    /// The number of total instances. Must be greater than or equal to 1.
+    /// This includes the original entity. On an open path the last instance
+    /// sits on the end of the path, on a closed path the instances are spread
+    /// evenly around it.
+    pub instances: Uint,
+    /// Whether to turn each instance to follow the direction of the path.
+    #[serde(default)]
+    pub align_to_tangent: bool,
+    /// Indices of instances to leave out of the pattern. Index 0 is the
+    /// original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
+}
+
+/// A pattern along a path.
+pub async fn pattern_along_path(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, path, solid_set) = args.get_pattern_along_path_args()?;
+
+    let solids = inner_pattern_along_path(data, path, solid_set, exec_state, &args).await?;
+    Ok(solids.into())
+}
+
+/// Repeat a 3-dimensional solid at even spacing along the segments of a
+/// sketch, such as a cam profile or a rail. Each instance may additionally
+/// be turned to follow the direction of the path.
+///
+/// The solid should sit on the start of the path. Instances are moved
+/// along the path relative to that point, and when aligned to the tangent
+/// they are rotated about their own center, around the normal of the path's
+/// sketch plane.
+///
+/// ```no_run,no_image
+/// const rail = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> tangentialArcTo([40, 20], %)
+///   |> line([0, 20], %)
+///
+/// const pegs = startSketchOn('XY')
+///   |> circle({ center: [0, 0], radius: 1 }, %)
+///   |> extrude(5, %)
+///   |> patternAlongPath({ instances: 8 }, rail, %)
+/// ```
+///
+/// ```no_run,no_image
+/// // Teeth around a ring, with a gap left for a clasp.
+/// const ring = startSketchOn('XY')
+///   |> circle({ center: [0, 0], radius: 20 }, %)
+///
+/// const teeth = startSketchOn('XY')
+///   |> startProfileAt([19, -1], %)
+///   |> line([2, 0], %)
+///   |> line([0, 2], %)
+///   |> line([-2, 0], %)
+///   |> close(%)
+///   |> extrude(3, %)
+///   |> patternAlongPath({
+///        instances: 24,
+///        alignToTangent: true,
+///        skip: [11, 12, 13],
+///      }, ring, %)
+/// ```
+#[stdlib {
+    name = "patternAlongPath",
+}]
+async fn inner_pattern_along_path(
+    data: PatternAlongPathData,
+    path: Sketch,
+    solid_set: SolidSet,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Vec<Box<Solid>>, KclError> {
+    let total_instances = data.instances.u32();
+    if total_instances < 1 {
+        return Err(KclError::Syntax(KclErrorDetails {
+            source_ranges: vec![args.source_range],
+            message: MUST_HAVE_ONE_INSTANCE.to_owned(),
+        }));
+    }
+    let skip = validate_skip(
+        data.skip.iter().flatten().map(Uint::u32),
+        total_instances,
+        args.source_range,
+    )?;
+
+    let curve = PathCurve::from_sketch(&path);
+    let length = curve.length();
+    if length <= f64::EPSILON {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "The path to pattern along must have a non-zero length".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+
+    // On a closed path the last instance would land back on the first one.
+    let spacing = if curve.is_closed() {
+        length / total_instances as f64
+    } else if total_instances > 1 {
+        length / (total_instances - 1) as f64
+    } else {
+        0.0
+    };
+
+    let x_axis = path.on.x_axis();
+    let y_axis = path.on.y_axis();
+    let start = curve.point_at(0.0);
+    let start_tangent = curve.tangent_at(0.0);
+    let mut transform = Vec::with_capacity(total_instances as usize);
+    for i in 1..total_instances {
+        let distance = spacing * i as f64;
+        let point = curve.point_at(distance);
+        let (dx, dy) = (point[0] - start[0], point[1] - start[1]);
+        let translate = Point3d {
+            x: dx * x_axis.x + dy * y_axis.x,
+            y: dx * x_axis.y + dy * y_axis.y,
+            z: dx * x_axis.z + dy * y_axis.z,
+        };
+
+        let mut rotation = Rotation::default();
+        if data.align_to_tangent {
+            let tangent = curve.tangent_at(distance);
+            rotation.axis = path.on.z_axis().into();
+            rotation.angle = Angle::from_degrees(angle_between(start_tangent, tangent));
+            rotation.origin = OriginType::Local;
+        }
+
+        transform.push(Transform {
+            replicate: !skip.contains(&i),
+            scale: Point3d { x: 1.0, y: 1.0, z: 1.0 }.into(),
+            translate: translate.into(),
+            rotation,
+        });
+    }
+
+    // Flush the batch for our fillets/chamfers if there are any.
+    // If we do not flush these, then you won't be able to pattern something with fillets.
+    // Flush just the fillets/chamfers that apply to these solids.
+    args.flush_batch_for_solid_set(exec_state, solid_set.clone().into())
+        .await?;
+
+    let starting_solids: Vec<Box<Solid>> = solid_set.into();
+
+    if args.ctx.context_type == crate::executor::ContextType::Mock {
+        return Ok(starting_solids);
+    }
+
+    let mut solids = Vec::new();
+    for e in starting_solids {
+        let new_solids = send_pattern_transform(transform.clone(), &e, exec_state, args).await?;
+        solids.extend(new_solids);
+    }
+    Ok(solids)
+}
+
+/// Data for a grid pattern on a face.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternGridData {
+    /// The number of rows, along the face's y axis. Must be greater than or equal to 1.
+    pub rows: Uint,
+    /// The number of columns, along the face's x axis. Must be greater than or equal to 1.
+    pub columns: Uint,
+    /// The distance between columns and between rows.
+    pub spacing: [f64; 2],
+    /// Indices of instances to leave out of the pattern, counted along each
+    /// row in turn, so the instance in row `r` and column `c` has the index
+    /// `r * columns + c`. Index 0 is the original entity, which cannot be skipped.
+    pub skip: Option<Vec<Uint>>,
+}
+
+impl PatternGridData {
+    /// The offset of every instance after the original, in the face's coordinates.
+    fn offsets(&self) -> Vec<[f64; 2]> {
+        let columns = self.columns.u32();
+        (1..self.rows.u32() * columns)
+            .map(|i| {
+                let (row, column) = (i / columns, i % columns);
+                [column as f64 * self.spacing[0], row as f64 * self.spacing[1]]
+            })
+            .collect()
+    }
+}
+
+/// A grid pattern on a face.
+pub async fn pattern_grid_on_face(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, solid, face, solid_set) = args.get_pattern_grid_on_face_args()?;
+
+    let solids = inner_pattern_grid_on_face(data, solid, face, solid_set, exec_state, &args).await?;
+    Ok(solids.into())
+}
+
+/// Repeat a 3-dimensional solid in rows and columns laid out on a planar
+/// face of another solid, such as bosses on a plate or vents in a panel.
+///
+/// Columns run along the face's x axis and rows along its y axis, the same
+/// axes a sketch started on the face uses. The solid should sit on the face
+/// at the first row and column.
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([40, 0], %)
+///   |> line([0, 30], %)
+///   |> line([-40, 0], %)
+///   |> close(%)
+///   |> extrude(4, %)
+///
+/// const bosses = startSketchOn(plate, 'end')
+///   |> circle({ center: [5, 5], radius: 2 }, %)
+///   |> extrude(6, %)
+///   |> patternGridOnFace({
+///        rows: 3,
+///        columns: 4,
+///        spacing: [10, 10],
+///        skip: [5, 6],
+///      }, plate, 'end', %)
+/// ```
+#[stdlib {
+    name = "patternGridOnFace",
+}]
+async fn inner_pattern_grid_on_face(
+    data: PatternGridData,
+    solid: Box<Solid>,
+    face: FaceTag,
+    solid_set: SolidSet,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Vec<Box<Solid>>, KclError> {
+    let total_instances = data.rows.u32() * data.columns.u32();
+    if total_instances < 1 {
+        return Err(KclError::Syntax(KclErrorDetails {
+            source_ranges: vec![args.source_range],
+            message: MUST_HAVE_ONE_INSTANCE.to_owned(),
+        }));
+    }
+    let skip = validate_skip(
+        data.skip.iter().flatten().map(Uint::u32),
+        total_instances,
+        args.source_range,
+    )?;
+
+    let frame = face_frame(&solid, &face, args.source_range)?;
+    let transform: Vec<Transform> = data
+        .offsets()
+        .into_iter()
+        .zip(1..)
+        .map(|(offset, i)| Transform {
+            replicate: !skip.contains(&i),
+            scale: Point3d { x: 1.0, y: 1.0, z: 1.0 }.into(),
+            translate: frame.vector(offset).into(),
+            rotation: Rotation::default(),
+        })
+        .collect();
+
+    // Flush the batch for our fillets/chamfers if there are any.
+    // If we do not flush these, then you won't be able to pattern something with fillets.
+    // Flush just the fillets/chamfers that apply to these solids.
+    args.flush_batch_for_solid_set(exec_state, solid_set.clone().into())
+        .await?;
+
+    let starting_solids: Vec<Box<Solid>> = solid_set.into();
+
+    if args.ctx.context_type == crate::executor::ContextType::Mock {
+        return Ok(starting_solids);
+    }
+
+    let mut solids = Vec::new();
+    for e in starting_solids {
+        let new_solids = send_pattern_transform(transform.clone(), &e, exec_state, args).await?;
+        solids.extend(new_solids);
+    }
+    Ok(solids)
+}
+
+/// The signed angle in degrees to turn direction `from` onto direction `to`.
+fn angle_between(from: [f64; 2], to: [f64; 2]) -> f64 {
+    let cross = from[0] * to[1] - from[1] * to[0];
+    let dot = from[0] * to[0] + from[1] * to[1];
+    cross.atan2(dot).to_degrees()
 }
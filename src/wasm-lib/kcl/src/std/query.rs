@@ -0,0 +1,517 @@
+//! Standard library queries: picking a solid's edges and faces by their geometry instead of by tags.
+//!
+//! Queries look at the solid's profile and extrusion, so they see the edges and faces of the
+//! extruded shape, and only solids which are still just that can be queried.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use kcmc::{each_cmd as mcmd, ok_response::OkModelingCmdResponse, websocket::OkWebSocketResponseData, ModelingCmd};
+use kittycad_modeling_cmds as kcmc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    artifact_graph::EdgeSubType,
+    errors::{KclError, KclErrorDetails},
+    executor::{ExecState, KclValue, Metadata, Path, Point3d, Solid, SourceRange, UserVal},
+    std::{
+        datums::{cross, dot, face_frame, norm, normalize, point3d, profile_frame},
+        measure::{expect_plain_extrusion, solid_face_area},
+        sketch::{FaceTag, StartOrEnd},
+        utils::CurveSegment,
+        Args,
+    },
+};
+
+/// How far from parallel, as the sine of the angle between them, two directions can be and
+/// still match.
+const PARALLEL_TOLERANCE: f64 = 1e-6;
+
+/// A direction to compare edges and faces with.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum QueryDirection {
+    /// One of the global axes.
+    Axis(GlobalAxis),
+    /// Any direction, as `[x, y, z]`.
+    Vector([f64; 3]),
+}
+
+/// A global axis.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+pub enum GlobalAxis {
+    /// The X axis.
+    #[serde(rename = "X", alias = "x")]
+    X,
+    /// The Y axis.
+    #[serde(rename = "Y", alias = "y")]
+    Y,
+    /// The Z axis.
+    #[serde(rename = "Z", alias = "z")]
+    Z,
+    /// The negative X axis.
+    #[serde(rename = "-X", alias = "-x")]
+    NegX,
+    /// The negative Y axis.
+    #[serde(rename = "-Y", alias = "-y")]
+    NegY,
+    /// The negative Z axis.
+    #[serde(rename = "-Z", alias = "-z")]
+    NegZ,
+}
+
+impl QueryDirection {
+    fn unit(&self, source_range: SourceRange) -> Result<Point3d, KclError> {
+        let v = match self {
+            QueryDirection::Axis(GlobalAxis::X) => [1.0, 0.0, 0.0],
+            QueryDirection::Axis(GlobalAxis::Y) => [0.0, 1.0, 0.0],
+            QueryDirection::Axis(GlobalAxis::Z) => [0.0, 0.0, 1.0],
+            QueryDirection::Axis(GlobalAxis::NegX) => [-1.0, 0.0, 0.0],
+            QueryDirection::Axis(GlobalAxis::NegY) => [0.0, -1.0, 0.0],
+            QueryDirection::Axis(GlobalAxis::NegZ) => [0.0, 0.0, -1.0],
+            QueryDirection::Vector(v) => *v,
+        };
+        normalize(point3d(v)).ok_or_else(|| {
+            KclError::Semantic(KclErrorDetails {
+                message: "Expected a direction which isn't the zero vector".to_string(),
+                source_ranges: vec![source_range],
+            })
+        })
+    }
+}
+
+/// Which edges of a solid to find.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeQuery {
+    /// Only straight edges parallel to this direction, either way along it.
+    #[serde(default)]
+    pub parallel_to: Option<QueryDirection>,
+    /// Only edges around this face.
+    #[serde(default)]
+    pub on_face: Option<FaceTag>,
+}
+
+/// Which faces of a solid to find.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct FaceQuery {
+    /// Only planar faces whose outward normal points this way.
+    #[serde(default)]
+    pub normal: Option<QueryDirection>,
+    /// Only the largest of the faces which match, by area.
+    #[serde(default)]
+    pub largest: bool,
+    /// Only the smallest of the faces which match, by area.
+    #[serde(default)]
+    pub smallest: bool,
+}
+
+/// An edge of an extruded solid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    /// The edge along a segment of the profile, on the start face.
+    Start(usize),
+    /// The edge along a segment of the profile, on the end face.
+    End(usize),
+    /// The edge up the extrusion, between the side face of a segment and the next.
+    Side(usize),
+}
+
+/// The edges of the solid which match the query, in the order of the profile.
+fn query_edges(solid: &Solid, query: &EdgeQuery, source_range: SourceRange) -> Result<Vec<Edge>, KclError> {
+    let paths = &solid.sketch.paths;
+    let mut edges: Vec<Edge> = (0..paths.len()).flat_map(|i| [Edge::Start(i), Edge::End(i)]).collect();
+    edges.extend((0..paths.len()).filter(|i| has_side_edge(paths, *i)).map(Edge::Side));
+
+    if let Some(face) = &query.on_face {
+        edges.retain(|edge| match (face, edge) {
+            (FaceTag::StartOrEnd(StartOrEnd::Start), Edge::Start(_)) => true,
+            (FaceTag::StartOrEnd(StartOrEnd::End), Edge::End(_)) => true,
+            (FaceTag::StartOrEnd(_), _) => false,
+            (_, edge) => {
+                let Some(i) = face.side_path(solid).and_then(|path| {
+                    paths
+                        .iter()
+                        .position(|p| p.get_base().geo_meta.id == path.get_base().geo_meta.id)
+                }) else {
+                    return false;
+                };
+                let previous = (i + paths.len() - 1) % paths.len();
+                matches!(edge, Edge::Start(j) | Edge::End(j) | Edge::Side(j) if *j == i)
+                    || *edge == Edge::Side(previous)
+            }
+        });
+    }
+    if let Some(direction) = &query.parallel_to {
+        let direction = direction.unit(source_range)?;
+        let frame = profile_frame(solid, "Querying", source_range)?;
+        edges.retain(|edge| {
+            let along = match edge {
+                Edge::Start(i) | Edge::End(i) => match CurveSegment::from(&paths[*i]) {
+                    CurveSegment::Line { from, to } => frame.vector([to[0] - from[0], to[1] - from[1]]),
+                    // Curved edges aren't parallel to anything.
+                    CurveSegment::Arc { .. } => return false,
+                },
+                Edge::Side(_) => frame.z_axis,
+            };
+            normalize(along)
+                .map(|along| norm(cross(along, direction)) <= PARALLEL_TOLERANCE)
+                .unwrap_or(false)
+        });
+    }
+    Ok(edges)
+}
+
+/// Whether there's an edge up the extrusion where the segment meets the next.
+fn has_side_edge(paths: &[Path], i: usize) -> bool {
+    if paths.len() < 2 {
+        // A circle meets only itself.
+        return false;
+    }
+    if i + 1 < paths.len() {
+        return true;
+    }
+    // The last segment meets the first only if the profile is closed.
+    let (end, start) = (paths[i].get_base().to, paths[0].get_base().from);
+    (end[0] - start[0]).hypot(end[1] - start[1]) <= 1e-9
+}
+
+/// The faces of the solid which match the query: the start and end faces, then the side
+/// faces in the order of the profile.
+fn query_faces(solid: &Solid, query: &FaceQuery, source_range: SourceRange) -> Result<Vec<FaceTag>, KclError> {
+    if query.largest && query.smallest {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "A face query can ask for the largest or the smallest face, not both".to_string(),
+            source_ranges: vec![source_range],
+        }));
+    }
+
+    let mut faces = vec![
+        FaceTag::StartOrEnd(StartOrEnd::Start),
+        FaceTag::StartOrEnd(StartOrEnd::End),
+    ];
+    for path in &solid.sketch.paths {
+        let id = path.get_base().geo_meta.id;
+        let Some(surface) = solid.value.iter().find(|surface| surface.get_id() == id) else {
+            continue;
+        };
+        // Tagged faces are given by their tags, so they can be used wherever tags can.
+        let tag = surface.get_tag().and_then(|tag| solid.sketch.tags.get(&tag.name));
+        faces.push(match tag {
+            Some(tag) => FaceTag::Tag(Box::new(tag.clone())),
+            None => FaceTag::Uuid(surface.face_id()),
+        });
+    }
+
+    if let Some(normal) = &query.normal {
+        let normal = normal.unit(source_range)?;
+        profile_frame(solid, "Querying", source_range)?;
+        let mut matching = vec![];
+        for face in faces {
+            let Ok(frame) = face_frame(solid, &face, source_range) else {
+                // Curved faces have no normal.
+                continue;
+            };
+            let Some(z) = normalize(frame.z_axis) else {
+                continue;
+            };
+            if dot(z, normal) >= 1.0 - PARALLEL_TOLERANCE * PARALLEL_TOLERANCE {
+                matching.push(face);
+            }
+        }
+        faces = matching;
+    }
+
+    if query.largest || query.smallest {
+        let mut best: Option<(f64, FaceTag)> = None;
+        for face in faces {
+            let area = solid_face_area(solid, &face, source_range)?;
+            let better = match &best {
+                None => true,
+                Some((best_area, _)) if query.largest => area > *best_area,
+                Some((best_area, _)) => area < *best_area,
+            };
+            if better {
+                best = Some((area, face));
+            }
+        }
+        faces = best.map(|(_, face)| vec![face]).unwrap_or_default();
+    }
+    Ok(faces)
+}
+
+/// The engine's id for an edge.
+async fn edge_id(solid: &Solid, edge: Edge, exec_state: &mut ExecState, args: &Args) -> Result<Uuid, KclError> {
+    let (Edge::Start(i) | Edge::End(i) | Edge::Side(i)) = edge;
+    let path_id = solid.sketch.paths[i].get_base().geo_meta.id;
+    if let Edge::Start(_) = edge {
+        // The edge on the start face is the segment itself.
+        return Ok(path_id);
+    }
+    if args.ctx.is_mock() {
+        // Recorded all the same, so the edge can still be measured.
+        let id = exec_state.id_generator.next_uuid();
+        let sub_type = match edge {
+            Edge::End(_) => EdgeSubType::Opposite,
+            _ => EdgeSubType::Adjacent,
+        };
+        exec_state.artifact_graph.record_edge(id, path_id, solid.id, sub_type);
+        return Ok(id);
+    }
+
+    let face_id = solid
+        .value
+        .iter()
+        .find(|surface| surface.get_id() == path_id)
+        .map(|surface| surface.face_id())
+        .ok_or_else(|| {
+            KclError::Type(KclErrorDetails {
+                message: "Expected the segment to have a side face".to_string(),
+                source_ranges: vec![args.source_range],
+            })
+        })?;
+    let cmd = match edge {
+        Edge::End(_) => ModelingCmd::from(mcmd::Solid3dGetOppositeEdge {
+            edge_id: path_id,
+            object_id: solid.sketch.id,
+            face_id,
+        }),
+        _ => ModelingCmd::from(mcmd::Solid3dGetNextAdjacentEdge {
+            edge_id: path_id,
+            object_id: solid.sketch.id,
+            face_id,
+        }),
+    };
+    let resp = args.send_modeling_cmd(exec_state.id_generator.next_uuid(), cmd).await?;
+    let (id, sub_type) = match &resp {
+        OkWebSocketResponseData::Modeling {
+            modeling_response: OkModelingCmdResponse::Solid3dGetOppositeEdge(opposite_edge),
+        } => (opposite_edge.edge, EdgeSubType::Opposite),
+        OkWebSocketResponseData::Modeling {
+            modeling_response: OkModelingCmdResponse::Solid3dGetNextAdjacentEdge(adjacent_edge),
+        } => (
+            adjacent_edge.edge.ok_or_else(|| {
+                KclError::Type(KclErrorDetails {
+                    message: "No edge found where the side faces meet".to_string(),
+                    source_ranges: vec![args.source_range],
+                })
+            })?,
+            EdgeSubType::Adjacent,
+        ),
+        _ => {
+            return Err(KclError::Engine(KclErrorDetails {
+                message: format!("Edge query response was not as expected: {:?}", resp),
+                source_ranges: vec![args.source_range],
+            }))
+        }
+    };
+    exec_state.artifact_graph.record_edge(id, path_id, solid.id, sub_type);
+    Ok(id)
+}
+
+/// Find edges of a solid.
+pub async fn edges(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, query): (Box<Solid>, Option<EdgeQuery>) = args.get_solid_and_optional_data()?;
+
+    let edges = inner_edges(solid, query, exec_state, &args).await?;
+    Ok(KclValue::UserVal(UserVal::new(
+        vec![Metadata {
+            source_range: args.source_range,
+        }],
+        edges,
+    )))
+}
+
+/// Find the edges of an extruded solid which match a query, instead of
+/// tagging them. The edges can be filleted, chamfered or used anywhere
+/// else an edge can.
+///
+/// Without a query, every edge of the solid is found. `parallelTo` keeps
+/// the straight edges parallel to an axis (`'X'`, `'Y'` or `'Z'`) or a
+/// direction, and `onFace` keeps the edges around a face.
+///
+/// Solids which have had fillets, chamfers, holes, shells or other
+/// features added to them can't be queried, so find edges before adding
+/// those.
+///
+/// ```no_run,no_image
+/// const box = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// // Round over the four upright edges.
+/// const rounded = fillet({
+///   radius: 2,
+///   tags: edges(box, { parallelTo: 'Z' })
+/// }, box)
+/// ```
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(2, %)
+///
+/// // Break the edges around the top.
+/// const broken = chamfer({
+///   length: 0.5,
+///   tags: edges(plate, { onFace: 'end' })
+/// }, plate)
+/// ```
+#[stdlib {
+    name = "edges",
+}]
+async fn inner_edges(
+    solid: Box<Solid>,
+    query: Option<EdgeQuery>,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Vec<Uuid>, KclError> {
+    expect_plain_extrusion(&solid, exec_state, "queried", args.source_range)?;
+    let query = query.unwrap_or_default();
+    let mut ids = vec![];
+    for edge in query_edges(&solid, &query, args.source_range)? {
+        ids.push(edge_id(&solid, edge, exec_state, args).await?);
+    }
+    Ok(ids)
+}
+
+/// Find faces of a solid.
+pub async fn faces(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (solid, query): (Box<Solid>, Option<FaceQuery>) = args.get_solid_and_optional_data()?;
+
+    let faces = inner_faces(solid, query, exec_state, &args).await?;
+    Ok(KclValue::UserVal(UserVal::new(
+        vec![Metadata {
+            source_range: args.source_range,
+        }],
+        faces,
+    )))
+}
+
+/// Find the faces of an extruded solid which match a query, instead of
+/// tagging them. The faces can be sketched on, shelled or used anywhere
+/// else a face can.
+///
+/// Without a query, every face of the solid is found: the start and end
+/// faces, then the side faces. `normal` keeps the planar faces facing an
+/// axis (`'X'`, `'-Z'` and so on) or a direction, and `largest` or
+/// `smallest` keeps only the biggest or smallest of those by area.
+///
+/// Solids which have had fillets, chamfers, holes, shells or other
+/// features added to them can't be queried.
+///
+/// ```no_run,no_image
+/// const part = startSketchOn('XZ')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 5], %)
+///   |> line([-10, 5], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// // Sketch a boss on the biggest face which faces up.
+/// const top = faces(part, { normal: 'Z', largest: true })
+/// const boss = startSketchOn(part, top[0])
+///   |> circle({ center: [10, 5], radius: 2 }, %)
+///   |> extrude(3, %)
+/// ```
+///
+/// ```no_run,no_image
+/// const cup = startSketchOn('XY')
+///   |> startProfileAt([-5, -5], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///
+/// const thinned = shell({
+///   faces: faces(cup, { normal: [0, 0, 1] }),
+///   thickness: 0.5
+/// }, cup)
+/// ```
+#[stdlib {
+    name = "faces",
+}]
+async fn inner_faces(
+    solid: Box<Solid>,
+    query: Option<FaceQuery>,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Vec<FaceTag>, KclError> {
+    expect_plain_extrusion(&solid, exec_state, "queried", args.source_range)?;
+    query_faces(&solid, &query.unwrap_or_default(), args.source_range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_queries() {
+        let query: EdgeQuery = serde_json::from_str(r#"{"parallelTo": "Z", "onFace": "end"}"#).unwrap();
+        assert_eq!(
+            query,
+            EdgeQuery {
+                parallel_to: Some(QueryDirection::Axis(GlobalAxis::Z)),
+                on_face: Some(FaceTag::StartOrEnd(StartOrEnd::End)),
+            }
+        );
+
+        let query: FaceQuery = serde_json::from_str(r#"{"normal": [0, 0, -1], "largest": true}"#).unwrap();
+        assert_eq!(
+            query,
+            FaceQuery {
+                normal: Some(QueryDirection::Vector([0.0, 0.0, -1.0])),
+                largest: true,
+                smallest: false,
+            }
+        );
+
+        let face: FaceTag = serde_json::from_str(r#""4a1ab8f0-2f4a-4a8e-9d4c-7c1e2b0b9a55""#).unwrap();
+        assert!(matches!(face, FaceTag::Uuid(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_only_plain_extrusions_are_queried() {
+        let ctx = crate::executor::ExecutorContext::new_mock(Default::default())
+            .await
+            .unwrap();
+        for query in ["edges", "faces"] {
+            let code = format!(
+                r#"const plate = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([20, 0], %, $edge)
+  |> line([0, 10], %)
+  |> line([-20, 0], %)
+  |> close(%)
+  |> extrude(2, %)
+const rounded = fillet({{ radius: 1, tags: [getOppositeEdge(edge)] }}, plate)
+{}(rounded)
+"#,
+                query
+            );
+            let program = crate::parser::parse(&code).unwrap();
+            let err = ctx
+                .run(&program, None, crate::executor::IdGenerator::default(), None)
+                .await
+                .unwrap_err();
+            assert!(err.message().contains("can be queried"), "{}", err.message());
+        }
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
     errors::{KclError, KclErrorDetails},
     executor::{ExecState, KclValue, Sketch, Solid},
     std::{
+        datums::Axis3d,
         extrude::do_post_extrude,
         fillet::{default_tolerance, EdgeReference},
         Args,
@@ -41,6 +42,8 @@ pub enum AxisOrEdgeReference {
     Axis(AxisAndOrigin),
     /// Tagged edge.
     Edge(EdgeReference),
+    /// A datum axis, in global coordinates rather than the sketch's.
+    Datum(Axis3d),
 }
 
 /// Axis and origin.
@@ -280,6 +283,28 @@ async fn inner_revolve(
             )
             .await?;
         }
+        AxisOrEdgeReference::Datum(datum) => {
+            args.batch_modeling_cmd(
+                id,
+                ModelingCmd::from(mcmd::Revolve {
+                    angle,
+                    target: sketch.id.into(),
+                    axis: kcmc::shared::Point3d {
+                        x: datum.direction[0],
+                        y: datum.direction[1],
+                        z: datum.direction[2],
+                    },
+                    origin: kcmc::shared::Point3d {
+                        x: LengthUnit(datum.origin[0]),
+                        y: LengthUnit(datum.origin[1]),
+                        z: LengthUnit(datum.origin[2]),
+                    },
+                    tolerance: LengthUnit(data.tolerance.unwrap_or(default_tolerance(&args.ctx.settings.units))),
+                    axis_is_2d: false,
+                }),
+            )
+            .await?;
+        }
         AxisOrEdgeReference::Edge(edge) => {
             let edge_id = edge.get_engine_id(exec_state, &args)?;
             args.batch_modeling_cmd(
@@ -303,7 +328,10 @@ mod tests {
 
     use pretty_assertions::assert_eq;
 
-    use crate::std::revolve::{AxisAndOrigin, AxisOrEdgeReference};
+    use crate::std::{
+        datums::Axis3d,
+        revolve::{AxisAndOrigin, AxisOrEdgeReference},
+    };
 
     #[test]
     fn test_deserialize_revolve_axis() {
@@ -339,5 +367,15 @@ mod tests {
                 origin: [1.0, 2.0]
             })
         );
+
+        str_json = r#"{"origin": [0, -5, 0], "direction": [0, 0, 1]}"#.to_string();
+        let data: AxisOrEdgeReference = serde_json::from_str(&str_json).unwrap();
+        assert_eq!(
+            data,
+            AxisOrEdgeReference::Datum(Axis3d {
+                origin: [0.0, -5.0, 0.0],
+                direction: [0.0, 0.0, 1.0]
+            })
+        );
     }
 }
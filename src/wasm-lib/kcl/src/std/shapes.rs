@@ -74,7 +74,7 @@ pub async fn circle(exec_state: &mut ExecState, args: Args) -> Result<KclValue,
 #[stdlib {
     name = "circle",
 }]
-async fn inner_circle(
+pub(crate) async fn inner_circle(
     data: CircleData,
     sketch_surface_or_group: SketchOrSurface,
     tag: Option<TagDeclarator>,
@@ -238,6 +238,7 @@ async fn inner_shell(
         }),
     )
     .await?;
+    exec_state.plain_extrusions.remove(&solids[0].id);
 
     Ok(solid_set)
 }
@@ -323,6 +324,7 @@ async fn inner_hollow(
         }),
     )
     .await?;
+    exec_state.plain_extrusions.remove(&solid.id);
 
     Ok(solid)
 }
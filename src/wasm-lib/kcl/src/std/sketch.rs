@@ -36,6 +36,8 @@ pub enum FaceTag {
     StartOrEnd(StartOrEnd),
     /// A tag for the face.
     Tag(Box<TagIdentifier>),
+    /// The id of the face, as returned by `faces`.
+    Uuid(uuid::Uuid),
 }
 
 impl std::fmt::Display for FaceTag {
@@ -44,6 +46,7 @@ impl std::fmt::Display for FaceTag {
             FaceTag::Tag(t) => write!(f, "{}", t),
             FaceTag::StartOrEnd(StartOrEnd::Start) => write!(f, "start"),
             FaceTag::StartOrEnd(StartOrEnd::End) => write!(f, "end"),
+            FaceTag::Uuid(id) => write!(f, "{}", id),
         }
     }
 }
@@ -71,8 +74,26 @@ impl FaceTag {
                     source_ranges: vec![args.source_range],
                 })
             }),
+            FaceTag::Uuid(id) => Ok(*id),
         }
     }
+
+    /// The segment of the solid's profile which this side face was extruded from, or `None`
+    /// for the start and end faces and faces which aren't on the solid.
+    pub(crate) fn side_path<'a>(&self, solid: &'a Solid) -> Option<&'a Path> {
+        let id = match self {
+            FaceTag::StartOrEnd(_) => return None,
+            FaceTag::Tag(tag) => {
+                return solid
+                    .sketch
+                    .paths
+                    .iter()
+                    .find(|path| path.get_tag().map(|t| t.name == tag.value).unwrap_or(false))
+            }
+            FaceTag::Uuid(id) => solid.value.iter().find(|surface| surface.face_id() == *id)?.get_id(),
+        };
+        solid.sketch.paths.iter().find(|path| path.get_base().geo_meta.id == id)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema, FromStr, Display)]
@@ -1036,7 +1057,7 @@ pub async fn start_sketch_on(exec_state: &mut ExecState, args: Args) -> Result<K
 #[stdlib {
     name = "startSketchOn",
 }]
-async fn inner_start_sketch_on(
+pub(crate) async fn inner_start_sketch_on(
     data: SketchData,
     tag: Option<FaceTag>,
     exec_state: &mut ExecState,
@@ -0,0 +1,577 @@
+//! Standard library text.
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use kcmc::shared::Point2d as KPoint2d;
+use kcmc::{each_cmd as mcmd, length_unit::LengthUnit, shared::PathSegment, ModelingCmd};
+use kittycad_modeling_cmds as kcmc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{KclError, KclErrorDetails},
+    executor::{BasePath, ExecState, GeoMeta, KclValue, Path, Sketch, SketchSurface, SourceRange},
+    fs::FileSystem,
+    std::Args,
+};
+
+/// The font used when no font file is given.
+const DEFAULT_FONT: &[u8] = include_bytes!("../../fonts/Roboto-Regular.ttf");
+
+/// Data for sketching text.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct TextData {
+    /// Path to a TrueType (`.ttf`) font file. Defaults to the bundled Roboto font.
+    pub font: Option<String>,
+    /// The font size, i.e. the height of the em square.
+    pub size: f64,
+    /// How the text is aligned horizontally around its position.
+    #[serde(default)]
+    pub anchor: TextAnchor,
+    /// Where the text's baseline starts, in sketch coordinates. Defaults to the origin.
+    pub position: Option<[f64; 2]>,
+}
+
+/// Horizontal alignment of text around its position.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub enum TextAnchor {
+    /// The text starts at the position.
+    #[default]
+    Start,
+    /// The text is centered on the position.
+    Middle,
+    /// The text ends at the position.
+    End,
+}
+
+/// A segment of a glyph contour, in sketch coordinates.
+#[derive(Debug, Clone, PartialEq)]
+enum ContourSegment {
+    Line {
+        to: [f64; 2],
+    },
+    Bezier {
+        control1: [f64; 2],
+        control2: [f64; 2],
+        to: [f64; 2],
+    },
+}
+
+impl ContourSegment {
+    fn to(&self) -> [f64; 2] {
+        match self {
+            ContourSegment::Line { to } => *to,
+            ContourSegment::Bezier { to, .. } => *to,
+        }
+    }
+}
+
+/// A single closed glyph contour.
+#[derive(Debug, Clone, PartialEq)]
+struct Contour {
+    start: [f64; 2],
+    segments: Vec<ContourSegment>,
+}
+
+impl Contour {
+    /// Approximate the contour by a polygon, using the segment end and control points.
+    fn polygon(&self) -> Vec<[f64; 2]> {
+        let mut points = vec![self.start];
+        for segment in &self.segments {
+            if let ContourSegment::Bezier { control1, control2, .. } = segment {
+                points.push(*control1);
+                points.push(*control2);
+            }
+            points.push(segment.to());
+        }
+        points
+    }
+
+    /// Twice the signed area of the contour. Positive when counter-clockwise.
+    fn signed_area(&self) -> f64 {
+        let points = self.polygon();
+        let mut area = 0.0;
+        for (i, a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            area += a[0] * b[1] - b[0] * a[1];
+        }
+        area
+    }
+
+    /// Whether the given point lies inside the contour (even-odd rule).
+    fn contains(&self, point: [f64; 2]) -> bool {
+        let points = self.polygon();
+        let mut inside = false;
+        let mut j = points.len() - 1;
+        for i in 0..points.len() {
+            let (a, b) = (points[i], points[j]);
+            if (a[1] > point[1]) != (b[1] > point[1])
+                && point[0] < (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0]
+            {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+}
+
+/// A closed profile of a glyph with the holes cut out of it.
+#[derive(Debug, Clone, PartialEq)]
+struct TextProfile {
+    outer: Contour,
+    holes: Vec<Contour>,
+}
+
+/// Collects glyph outlines, scaled and moved into sketch coordinates.
+struct ContourBuilder {
+    scale: f64,
+    offset: [f64; 2],
+    contours: Vec<Contour>,
+    current: Option<Contour>,
+}
+
+impl ContourBuilder {
+    fn point(&self, x: f32, y: f32) -> [f64; 2] {
+        [
+            self.offset[0] + x as f64 * self.scale,
+            self.offset[1] + y as f64 * self.scale,
+        ]
+    }
+
+    fn current_point(&self) -> [f64; 2] {
+        self.current
+            .as_ref()
+            .map(|c| c.segments.last().map(|s| s.to()).unwrap_or(c.start))
+            .unwrap_or(self.offset)
+    }
+
+    fn push(&mut self, segment: ContourSegment) {
+        if let Some(current) = &mut self.current {
+            current.segments.push(segment);
+        }
+    }
+}
+
+impl ttf_parser::OutlineBuilder for ContourBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        self.current = Some(Contour {
+            start: self.point(x, y),
+            segments: Vec::new(),
+        });
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.push(ContourSegment::Line { to });
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        // Elevate the quadratic curve to a cubic one, which the engine understands.
+        let from = self.current_point();
+        let control = self.point(x1, y1);
+        let to = self.point(x, y);
+        self.push(ContourSegment::Bezier {
+            control1: [
+                from[0] + 2.0 / 3.0 * (control[0] - from[0]),
+                from[1] + 2.0 / 3.0 * (control[1] - from[1]),
+            ],
+            control2: [
+                to[0] + 2.0 / 3.0 * (control[0] - to[0]),
+                to[1] + 2.0 / 3.0 * (control[1] - to[1]),
+            ],
+            to,
+        });
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let control1 = self.point(x1, y1);
+        let control2 = self.point(x2, y2);
+        let to = self.point(x, y);
+        self.push(ContourSegment::Bezier { control1, control2, to });
+    }
+
+    fn close(&mut self) {
+        if let Some(mut contour) = self.current.take() {
+            // The closing segment is drawn by closing the path.
+            if contour.segments.last().map(|s| s.to()) == Some(contour.start) {
+                if let Some(ContourSegment::Line { .. }) = contour.segments.last() {
+                    contour.segments.pop();
+                }
+            }
+            if !contour.segments.is_empty() {
+                self.contours.push(contour);
+            }
+        }
+    }
+}
+
+/// Lay out the text with the given font, returning the closed profiles to sketch.
+fn layout_text(
+    font_data: &[u8],
+    text: &str,
+    data: &TextData,
+    source_range: SourceRange,
+) -> Result<Vec<TextProfile>, KclError> {
+    if data.size <= 0.0 {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: format!("Expected a positive text size, found `{}`", data.size),
+            source_ranges: vec![source_range],
+        }));
+    }
+
+    let face = ttf_parser::Face::parse(font_data, 0).map_err(|e| {
+        KclError::Semantic(KclErrorDetails {
+            message: format!("Failed to parse font: {}", e),
+            source_ranges: vec![source_range],
+        })
+    })?;
+
+    let scale = data.size / face.units_per_em() as f64;
+    let line_height = face.height() as f64 * scale;
+    let [x, y] = data.position.unwrap_or([0.0, 0.0]);
+
+    let mut profiles = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+        let mut glyphs = Vec::new();
+        for c in line.chars() {
+            let glyph = face.glyph_index(c).ok_or_else(|| {
+                KclError::Semantic(KclErrorDetails {
+                    message: format!("The font has no glyph for the character `{}`", c),
+                    source_ranges: vec![source_range],
+                })
+            })?;
+            glyphs.push(glyph);
+        }
+
+        let width: f64 = glyphs
+            .iter()
+            .map(|g| face.glyph_hor_advance(*g).unwrap_or_default() as f64 * scale)
+            .sum();
+        let mut pen_x = match data.anchor {
+            TextAnchor::Start => x,
+            TextAnchor::Middle => x - width / 2.0,
+            TextAnchor::End => x - width,
+        };
+        let pen_y = y - line_index as f64 * line_height;
+
+        for glyph in glyphs {
+            let mut builder = ContourBuilder {
+                scale,
+                offset: [pen_x, pen_y],
+                contours: Vec::new(),
+                current: None,
+            };
+            face.outline_glyph(glyph, &mut builder);
+            ttf_parser::OutlineBuilder::close(&mut builder);
+            profiles.extend(group_contours(builder.contours));
+
+            pen_x += face.glyph_hor_advance(glyph).unwrap_or_default() as f64 * scale;
+        }
+    }
+
+    Ok(profiles)
+}
+
+/// Sort the contours of a glyph into outer profiles and the holes inside them.
+fn group_contours(contours: Vec<Contour>) -> Vec<TextProfile> {
+    // Outer contours wind in the same direction as the largest contour in the glyph,
+    // holes wind in the opposite direction.
+    let Some(outer_sign) = contours
+        .iter()
+        .map(|c| c.signed_area())
+        .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+        .map(f64::signum)
+    else {
+        return Vec::new();
+    };
+
+    let (outers, holes): (Vec<_>, Vec<_>) = contours
+        .into_iter()
+        .partition(|c| c.signed_area().signum() == outer_sign);
+
+    let mut profiles: Vec<TextProfile> = outers
+        .into_iter()
+        .map(|outer| TextProfile { outer, holes: vec![] })
+        .collect();
+    for hole in holes {
+        // Put the hole in the smallest outer contour that contains it.
+        let parent = profiles
+            .iter_mut()
+            .filter(|p| p.outer.contains(hole.start))
+            .min_by(|a, b| a.outer.signed_area().abs().total_cmp(&b.outer.signed_area().abs()));
+        if let Some(parent) = parent {
+            parent.holes.push(hole);
+        }
+    }
+
+    profiles
+}
+
+/// Sketch text as closed profiles.
+pub async fn text(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (text, data, sketch_surface): (String, TextData, SketchSurface) = args.get_text_args()?;
+
+    let sketches = inner_text(text, data, sketch_surface, exec_state, args).await?;
+    Ok(sketches.into())
+}
+
+/// Sketch text as closed 2-dimensional profiles, which can then be extruded
+/// or used to cut into a solid, e.g. for serial numbers, labels and logos.
+///
+/// Each glyph outline becomes its own sketch, with any holes in the glyph
+/// (like the inside of an `O`) already cut out. The outlines are read from a
+/// TrueType font file, relative to the current project directory, or from the
+/// bundled Roboto font if no font is given.
+///
+/// ```no_run,no_image
+/// const label = startSketchOn('XY')
+///   |> text("PART-42", { size: 10 }, %)
+///
+/// const example = extrude(2, label)
+/// ```
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([-40, -10], %)
+///   |> line([80, 0], %)
+///   |> line([0, 20], %)
+///   |> line([-80, 0], %)
+///   |> close(%)
+///   |> extrude(5, %)
+///
+/// // Engrave a centered label into the top of the plate.
+/// const label = startSketchOn(plate, 'end')
+///   |> text("KCL", { size: 12, anchor: "middle", position: [0, -4] }, %)
+///
+/// const engraving = extrude(-1, label)
+/// ```
+#[stdlib {
+    name = "text",
+}]
+async fn inner_text(
+    text: String,
+    data: TextData,
+    sketch_surface: SketchSurface,
+    exec_state: &mut ExecState,
+    args: Args,
+) -> Result<Vec<Box<Sketch>>, KclError> {
+    let font_data = match &data.font {
+        Some(font) => {
+            if !args.ctx.fs.exists(font, args.source_range).await? {
+                return Err(KclError::Semantic(KclErrorDetails {
+                    message: format!("Font file `{}` does not exist.", font),
+                    source_ranges: vec![args.source_range],
+                }));
+            }
+            std::borrow::Cow::Owned(args.ctx.fs.read(font, args.source_range).await?)
+        }
+        None => std::borrow::Cow::Borrowed(DEFAULT_FONT),
+    };
+
+    let profiles = layout_text(&font_data, &text, &data, args.source_range)?;
+
+    let mut sketches = Vec::with_capacity(profiles.len());
+    for profile in profiles {
+        let sketch = sketch_contour(&profile.outer, sketch_surface.clone(), exec_state, &args).await?;
+        for hole in &profile.holes {
+            let hole_sketch = sketch_contour(hole, sketch_surface.clone(), exec_state, &args).await?;
+            args.batch_modeling_cmd(
+                exec_state.id_generator.next_uuid(),
+                ModelingCmd::from(mcmd::Solid2dAddHole {
+                    object_id: sketch.id,
+                    hole_id: hole_sketch.id,
+                }),
+            )
+            .await?;
+            args.batch_modeling_cmd(
+                exec_state.id_generator.next_uuid(),
+                ModelingCmd::from(mcmd::ObjectVisible {
+                    object_id: hole_sketch.id,
+                    hidden: true,
+                }),
+            )
+            .await?;
+        }
+        sketches.push(Box::new(sketch));
+    }
+
+    Ok(sketches)
+}
+
+/// Sketch a single closed contour on the surface.
+async fn sketch_contour(
+    contour: &Contour,
+    sketch_surface: SketchSurface,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Sketch, KclError> {
+    let mut sketch =
+        crate::std::sketch::inner_start_profile_at(contour.start, sketch_surface, None, exec_state, args.clone())
+            .await?;
+
+    let mut from = contour.start;
+    for segment in &contour.segments {
+        let id = exec_state.id_generator.next_uuid();
+        let path_segment = match segment {
+            ContourSegment::Line { to } => PathSegment::Line {
+                end: KPoint2d::from(*to).with_z(0.0).map(LengthUnit),
+                relative: false,
+            },
+            ContourSegment::Bezier { control1, control2, to } => PathSegment::Bezier {
+                control1: KPoint2d::from(*control1).with_z(0.0).map(LengthUnit),
+                control2: KPoint2d::from(*control2).with_z(0.0).map(LengthUnit),
+                end: KPoint2d::from(*to).with_z(0.0).map(LengthUnit),
+                relative: false,
+            },
+        };
+        args.batch_modeling_cmd(
+            id,
+            ModelingCmd::from(mcmd::ExtendPath {
+                path: sketch.id.into(),
+                segment: path_segment,
+            }),
+        )
+        .await?;
+
+        let to = segment.to();
+        sketch.paths.push(Path::ToPoint {
+            base: BasePath {
+                from,
+                to,
+                tag: None,
+                geo_meta: GeoMeta {
+                    id,
+                    metadata: args.source_range.into(),
+                },
+            },
+        });
+        from = to;
+    }
+
+    let id = exec_state.id_generator.next_uuid();
+    args.batch_modeling_cmd(id, ModelingCmd::from(mcmd::ClosePath { path_id: sketch.id }))
+        .await?;
+    if from != contour.start {
+        sketch.paths.push(Path::ToPoint {
+            base: BasePath {
+                from,
+                to: contour.start,
+                tag: None,
+                geo_meta: GeoMeta {
+                    id,
+                    metadata: args.source_range.into(),
+                },
+            },
+        });
+    }
+
+    if let SketchSurface::Plane(_) = sketch.on {
+        args.batch_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::SketchModeDisable(mcmd::SketchModeDisable {}),
+        )
+        .await?;
+    }
+
+    Ok(sketch)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn data(size: f64, anchor: TextAnchor) -> TextData {
+        TextData {
+            font: None,
+            size,
+            anchor,
+            position: None,
+        }
+    }
+
+    #[test]
+    fn test_layout_text_holes() {
+        let profiles = layout_text(
+            DEFAULT_FONT,
+            "O",
+            &data(10.0, TextAnchor::Start),
+            SourceRange::default(),
+        )
+        .unwrap();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].holes.len(), 1);
+
+        let profiles = layout_text(
+            DEFAULT_FONT,
+            "i-",
+            &data(10.0, TextAnchor::Start),
+            SourceRange::default(),
+        )
+        .unwrap();
+        // The stem and dot of the `i`, and the dash.
+        assert_eq!(profiles.len(), 3);
+        assert!(profiles.iter().all(|p| p.holes.is_empty()));
+    }
+
+    #[test]
+    fn test_layout_text_anchor() {
+        let max_x = |profiles: &[TextProfile]| {
+            profiles
+                .iter()
+                .flat_map(|p| p.outer.polygon())
+                .map(|p| p[0])
+                .fold(f64::MIN, f64::max)
+        };
+        let min_x = |profiles: &[TextProfile]| {
+            profiles
+                .iter()
+                .flat_map(|p| p.outer.polygon())
+                .map(|p| p[0])
+                .fold(f64::MAX, f64::min)
+        };
+
+        let start = layout_text(
+            DEFAULT_FONT,
+            "H",
+            &data(10.0, TextAnchor::Start),
+            SourceRange::default(),
+        )
+        .unwrap();
+        assert!(min_x(&start) > 0.0);
+
+        let end = layout_text(DEFAULT_FONT, "H", &data(10.0, TextAnchor::End), SourceRange::default()).unwrap();
+        assert!(max_x(&end) < 0.0);
+
+        let middle = layout_text(
+            DEFAULT_FONT,
+            "H",
+            &data(10.0, TextAnchor::Middle),
+            SourceRange::default(),
+        )
+        .unwrap();
+        assert!(min_x(&middle) < 0.0 && max_x(&middle) > 0.0);
+    }
+
+    #[test]
+    fn test_layout_text_errors() {
+        let err = layout_text(DEFAULT_FONT, "H", &data(0.0, TextAnchor::Start), SourceRange::default()).unwrap_err();
+        assert_eq!(err.message(), "Expected a positive text size, found `0`");
+
+        let err = layout_text(
+            b"not a font",
+            "H",
+            &data(1.0, TextAnchor::Start),
+            SourceRange::default(),
+        )
+        .unwrap_err();
+        assert!(err.message().starts_with("Failed to parse font"));
+    }
+}
@@ -0,0 +1,767 @@
+//! Standard library transforms: moving, turning and resizing solids.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use derive_docs::stdlib;
+use kcmc::{
+    each_cmd as mcmd,
+    length_unit::LengthUnit,
+    ok_response::OkModelingCmdResponse,
+    shared::{self, ExtrusionFaceCapType},
+    websocket::OkWebSocketResponseData,
+    ModelingCmd,
+};
+use kittycad_modeling_cmds::{self as kcmc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{KclError, KclErrorDetails},
+    executor::{
+        ExecState, ExtrudeSurface, ImportedGeometry, KclValue, Path, Point3d, SketchSurface, Solid, SolidSet,
+        SourceRange, Transform3d,
+    },
+    std::Args,
+};
+
+/// Solids, or geometry imported from a file.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum SolidSetOrImportedGeometry {
+    SolidSet(SolidSet),
+    ImportedGeometry(Box<ImportedGeometry>),
+}
+
+impl From<SolidSetOrImportedGeometry> for KclValue {
+    fn from(value: SolidSetOrImportedGeometry) -> Self {
+        match value {
+            SolidSetOrImportedGeometry::SolidSet(solid_set) => solid_set.into(),
+            SolidSetOrImportedGeometry::ImportedGeometry(geometry) => KclValue::ImportedGeometry(geometry),
+        }
+    }
+}
+
+/// Data for a rotation.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateData {
+    /// The axis to rotate around.
+    pub axis: [f64; 3],
+    /// The angle to rotate by, in degrees.
+    pub angle: f64,
+    /// A point on the line to rotate around. Defaults to the global origin.
+    #[serde(default)]
+    pub origin: Option<[f64; 3]>,
+}
+
+/// Data for a scale.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum ScaleData {
+    /// The same factor along every axis.
+    Uniform(f64),
+    /// A factor for each of the X, Y and Z axes.
+    PerAxis([f64; 3]),
+}
+
+/// Move solids or imported geometry.
+pub async fn translate(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (offset, geometry): ([f64; 3], SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_translate(offset, geometry, exec_state, args).await?;
+    Ok(geometry.into())
+}
+
+/// Move solids or imported geometry by an offset along the global X, Y and Z axes.
+///
+/// The solid is moved, not copied: other names bound to it refer to it
+/// where it is now. The same goes for `rotate`, `scaleBy` and `transformBy`.
+///
+/// ```no_run,no_image
+/// const cube = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(10, %)
+///   |> translate([20, 0, 5], %)
+/// ```
+///
+/// ```no_run,no_image
+/// // Move a part imported from another file.
+/// const part = import("tests/inputs/cube.step")
+///   |> translate([0, 0, 12.5], %)
+/// ```
+#[stdlib {
+    name = "translate",
+}]
+async fn inner_translate(
+    offset: [f64; 3],
+    geometry: SolidSetOrImportedGeometry,
+    exec_state: &mut ExecState,
+    args: Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    let transform = Transform3d::translation(Point3d::new(offset[0], offset[1], offset[2]));
+    apply_transform(transform, geometry, exec_state, &args).await
+}
+
+/// Rotate solids or imported geometry.
+pub async fn rotate(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, geometry): (RotateData, SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_rotate(data, geometry, exec_state, args).await?;
+    Ok(geometry.into())
+}
+
+/// Rotate solids or imported geometry about an axis.
+///
+/// The rotation is counterclockwise looking down the axis, about the line
+/// along the axis through `origin`, or through the global origin if no
+/// origin is given.
+///
+/// ```no_run,no_image
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(2, %)
+///   |> rotate({ axis: [0, 0, 1], angle: 45 }, %)
+/// ```
+///
+/// ```no_run,no_image
+/// // Stand a plate up on its edge, turning it about that edge.
+/// const plate = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([20, 0], %)
+///   |> line([0, 10], %)
+///   |> line([-20, 0], %)
+///   |> close(%)
+///   |> extrude(2, %)
+///   |> rotate({ axis: [1, 0, 0], angle: 90, origin: [0, 10, 0] }, %)
+/// ```
+#[stdlib {
+    name = "rotate",
+}]
+async fn inner_rotate(
+    data: RotateData,
+    geometry: SolidSetOrImportedGeometry,
+    exec_state: &mut ExecState,
+    args: Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    let axis = Point3d::new(data.axis[0], data.axis[1], data.axis[2]);
+    let origin = data
+        .origin
+        .map(|o| Point3d::new(o[0], o[1], o[2]))
+        .unwrap_or(Point3d::ZERO);
+    let transform = Transform3d::rotation(axis, data.angle, origin).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "The axis to rotate around cannot be the zero vector".to_string(),
+            source_ranges: vec![args.source_range],
+        })
+    })?;
+    apply_transform(transform, geometry, exec_state, &args).await
+}
+
+/// Scale solids or imported geometry.
+pub async fn scale_by(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (data, geometry): (ScaleData, SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_scale_by(data, geometry, exec_state, args).await?;
+    Ok(geometry.into())
+}
+
+/// Scale solids or imported geometry about the global origin, either by
+/// one factor for every axis or by a factor for each of X, Y and Z.
+///
+/// ```no_run,no_image
+/// const peg = startSketchOn('XY')
+///   |> circle({ center: [0, 0], radius: 1 }, %)
+///   |> extrude(5, %)
+///   |> scaleBy(2, %)
+/// ```
+///
+/// ```no_run,no_image
+/// // Squash a peg to half its height.
+/// const peg = startSketchOn('XY')
+///   |> circle({ center: [0, 0], radius: 1 }, %)
+///   |> extrude(5, %)
+///   |> scaleBy([1, 1, 0.5], %)
+/// ```
+#[stdlib {
+    name = "scaleBy",
+}]
+async fn inner_scale_by(
+    data: ScaleData,
+    geometry: SolidSetOrImportedGeometry,
+    exec_state: &mut ExecState,
+    args: Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    let factors = match data {
+        ScaleData::Uniform(factor) => Point3d::new(factor, factor, factor),
+        ScaleData::PerAxis(factors) => Point3d::new(factors[0], factors[1], factors[2]),
+    };
+    if factors.x == 0.0 || factors.y == 0.0 || factors.z == 0.0 {
+        return Err(KclError::Semantic(KclErrorDetails {
+            message: "Scale factors cannot be zero".to_string(),
+            source_ranges: vec![args.source_range],
+        }));
+    }
+    apply_transform(Transform3d::scale(factors), geometry, exec_state, &args).await
+}
+
+/// Transform solids or imported geometry by a matrix.
+pub async fn transform_by(exec_state: &mut ExecState, args: Args) -> Result<KclValue, KclError> {
+    let (matrix, geometry): ([[f64; 4]; 4], SolidSetOrImportedGeometry) =
+        args.get_data_and_solid_set_or_imported_geometry()?;
+
+    let geometry = inner_transform_by(matrix, geometry, exec_state, args).await?;
+    Ok(geometry.into())
+}
+
+/// Transform solids or imported geometry by a 4x4 matrix in global
+/// coordinates, given as its rows.
+///
+/// The matrix can combine any translation, rotation and scale along the
+/// geometry's axes, but it can't shear or project, so the last row must be
+/// `[0, 0, 0, 1]`.
+///
+/// ```no_run,no_image
+/// // Turn a block a quarter about Z, and move it up 10.
+/// const block = startSketchOn('XY')
+///   |> startProfileAt([0, 0], %)
+///   |> line([10, 0], %)
+///   |> line([0, 4], %)
+///   |> line([-10, 0], %)
+///   |> close(%)
+///   |> extrude(4, %)
+///   |> transformBy([
+///        [0, -1, 0, 0],
+///        [1, 0, 0, 0],
+///        [0, 0, 1, 10],
+///        [0, 0, 0, 1]
+///      ], %)
+/// ```
+#[stdlib {
+    name = "transformBy",
+}]
+async fn inner_transform_by(
+    matrix: [[f64; 4]; 4],
+    geometry: SolidSetOrImportedGeometry,
+    exec_state: &mut ExecState,
+    args: Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    apply_transform(Transform3d(matrix), geometry, exec_state, &args).await
+}
+
+/// Move the geometry in the engine, and keep track of where it is.
+///
+/// The engine can't move objects in place, so the geometry is replaced by a copy made where
+/// it's moved to, which has its own ids. The solid takes on the ids of the copy's faces and
+/// edges, and every binding of the geometry is updated to the moved geometry, as the engine
+/// no longer has the original.
+pub(crate) async fn apply_transform(
+    transform: Transform3d,
+    geometry: SolidSetOrImportedGeometry,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<SolidSetOrImportedGeometry, KclError> {
+    if transform.is_identity() {
+        return Ok(geometry);
+    }
+    let engine_transform = engine_transform(&transform, args.source_range)?;
+
+    match geometry {
+        SolidSetOrImportedGeometry::SolidSet(solid_set) => {
+            // Flush the batch for our fillets/chamfers if there are any,
+            // otherwise they'd be left on the solid being replaced.
+            args.flush_batch_for_solid_set(exec_state, solid_set.clone().into())
+                .await?;
+
+            let mut solids: Vec<Box<Solid>> = solid_set.into();
+            for solid in solids.iter_mut() {
+                let from = solid.id;
+                if !args.ctx.is_mock() {
+                    let id = copy_to(solid.id, engine_transform.clone(), exec_state, args).await?;
+                    let ids = copied_ids(solid, id, exec_state, args).await?;
+                    remove(solid.id, exec_state, args).await?;
+                    exec_state.parts.replace(solid.id, id);
+                    if exec_state.plain_extrusions.remove(&solid.id) {
+                        exec_state.plain_extrusions.insert(id);
+                    }
+                    use_copied_ids(solid, &ids);
+                }
+                transform_solid(solid, &transform);
+                exec_state.memory.update_moved_solid(from, solid);
+            }
+            Ok(SolidSetOrImportedGeometry::SolidSet(solids.into()))
+        }
+        SolidSetOrImportedGeometry::ImportedGeometry(mut geometry) => {
+            let from = geometry.id;
+            if !args.ctx.is_mock() {
+                let id = copy_to(geometry.id, engine_transform, exec_state, args).await?;
+                remove(geometry.id, exec_state, args).await?;
+                exec_state.parts.replace(geometry.id, id);
+                geometry.id = id;
+            }
+            geometry.transform = geometry.transform.then(&transform);
+            exec_state.memory.update_moved_geometry(from, &geometry);
+            Ok(SolidSetOrImportedGeometry::ImportedGeometry(geometry))
+        }
+    }
+}
+
+/// Copy the object to where it's moved to, returning the copy's id.
+async fn copy_to(
+    object_id: uuid::Uuid,
+    transform: shared::Transform,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<uuid::Uuid, KclError> {
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::EntityLinearPatternTransform {
+                entity_id: object_id,
+                transform: vec![transform],
+            }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::EntityLinearPatternTransform(copy),
+    } = &resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("EntityLinearPatternTransform response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    let [id] = copy.entity_ids[..] else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!(
+                "Expected the engine to make one copy, it made {}",
+                copy.entity_ids.len()
+            ),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    Ok(id)
+}
+
+async fn remove(object_id: uuid::Uuid, exec_state: &mut ExecState, args: &Args) -> Result<(), KclError> {
+    args.batch_modeling_cmd(
+        exec_state.id_generator.next_uuid(),
+        ModelingCmd::from(mcmd::RemoveSceneObjects {
+            object_ids: [object_id].into(),
+        }),
+    )
+    .await
+}
+
+/// The ids of the copy of a solid, by the ids of the solid they're copies of: the solid
+/// itself, the edges of its profile, and the faces extruded from them.
+pub(crate) async fn copied_ids(
+    solid: &Solid,
+    copy: uuid::Uuid,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<HashMap<uuid::Uuid, uuid::Uuid>, KclError> {
+    let mut ids = HashMap::from([(solid.id, copy)]);
+
+    // A copy has its edges in the same order as the solid it's copied from.
+    let edges = child_ids(solid.id, exec_state, args).await?;
+    let copied_edges = child_ids(copy, exec_state, args).await?;
+    ids.extend(edges.into_iter().zip(copied_edges));
+
+    // The engine finds the faces of an extrusion from any edge of its profile, like `extrude` does.
+    let Some(edge_id) = solid.sketch.paths.iter().find_map(|path| match path {
+        Path::ToPoint { base } | Path::Circle { base, .. } => ids.get(&base.geo_meta.id).copied(),
+        _ => None,
+    }) else {
+        return Ok(ids);
+    };
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::Solid3dGetExtrusionFaceInfo {
+                edge_id,
+                object_id: copy,
+            }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::Solid3dGetExtrusionFaceInfo(info),
+    } = resp
+    else {
+        return Ok(ids);
+    };
+
+    let mut faces = HashMap::new();
+    for face in info.faces {
+        let Some(face_id) = face.face_id else { continue };
+        let from = match face.cap {
+            ExtrusionFaceCapType::Bottom => solid.start_cap_id,
+            ExtrusionFaceCapType::Top => solid.end_cap_id,
+            ExtrusionFaceCapType::None => face
+                .curve_id
+                .and_then(|curve| ids.iter().find(|(_, copied)| **copied == curve))
+                .and_then(|(edge, _)| solid.value.iter().find(|surface| surface.get_id() == *edge))
+                .map(ExtrudeSurface::face_id),
+        };
+        if let Some(from) = from {
+            faces.insert(from, face_id);
+        }
+    }
+    ids.extend(faces);
+    Ok(ids)
+}
+
+async fn child_ids(
+    entity_id: uuid::Uuid,
+    exec_state: &mut ExecState,
+    args: &Args,
+) -> Result<Vec<uuid::Uuid>, KclError> {
+    let resp = args
+        .send_modeling_cmd(
+            exec_state.id_generator.next_uuid(),
+            ModelingCmd::from(mcmd::EntityGetAllChildUuids { entity_id }),
+        )
+        .await?;
+    let OkWebSocketResponseData::Modeling {
+        modeling_response: OkModelingCmdResponse::EntityGetAllChildUuids(children),
+    } = resp
+    else {
+        return Err(KclError::Engine(KclErrorDetails {
+            message: format!("EntityGetAllChildUuids response was not as expected: {:?}", resp),
+            source_ranges: vec![args.source_range],
+        }));
+    };
+    Ok(children.entity_ids)
+}
+
+/// Refer to the faces and edges of the solid's copy from now on, tags included.
+pub(crate) fn use_copied_ids(solid: &mut Solid, ids: &HashMap<uuid::Uuid, uuid::Uuid>) {
+    let copied = |id: &mut uuid::Uuid| {
+        if let Some(copy) = ids.get(id) {
+            *id = *copy;
+        }
+    };
+    let copied_surface = |surface: &mut ExtrudeSurface| {
+        let (face_id, geo_meta) = match surface {
+            ExtrudeSurface::ExtrudePlane(s) => (&mut s.face_id, &mut s.geo_meta),
+            ExtrudeSurface::ExtrudeArc(s) => (&mut s.face_id, &mut s.geo_meta),
+            ExtrudeSurface::Chamfer(s) => (&mut s.face_id, &mut s.geo_meta),
+            ExtrudeSurface::Fillet(s) => (&mut s.face_id, &mut s.geo_meta),
+        };
+        copied(face_id);
+        copied(&mut geo_meta.id);
+    };
+
+    copied(&mut solid.id);
+    copied(&mut solid.sketch.id);
+    solid.start_cap_id.iter_mut().for_each(copied);
+    solid.end_cap_id.iter_mut().for_each(copied);
+    solid.value.iter_mut().for_each(copied_surface);
+    for path in solid.sketch.paths.iter_mut() {
+        if let Some(base) = path.get_base_mut() {
+            copied(&mut base.geo_meta.id);
+        }
+    }
+    for info in solid.sketch.tags.values_mut().filter_map(|tag| tag.info.as_mut()) {
+        copied(&mut info.id);
+        copied(&mut info.sketch);
+        if let Some(path) = info.path.as_mut() {
+            copied(&mut path.geo_meta.id);
+        }
+        info.surface.iter_mut().for_each(copied_surface);
+    }
+}
+
+/// Keep the solid's record of where it is, and the surface it was sketched on, in step
+/// with the engine. Faces sketched on later take their axes from that surface.
+pub(crate) fn transform_solid(solid: &mut Solid, transform: &Transform3d) {
+    solid.transform = solid.transform.then(transform);
+    transform_surface(&mut solid.sketch.on, transform);
+}
+
+/// The axes are transformed as directions without being normalized, so points given in
+/// sketch coordinates still land on the transformed solid after a scale.
+fn transform_surface(surface: &mut SketchSurface, transform: &Transform3d) {
+    match surface {
+        SketchSurface::Plane(plane) => {
+            plane.origin = transform.transform_point(plane.origin);
+            plane.x_axis = transform.transform_vector(plane.x_axis);
+            plane.y_axis = transform.transform_vector(plane.y_axis);
+            plane.z_axis = transform.transform_vector(plane.z_axis);
+        }
+        SketchSurface::Face(face) => {
+            face.x_axis = transform.transform_vector(face.x_axis);
+            face.y_axis = transform.transform_vector(face.y_axis);
+            face.z_axis = transform.transform_vector(face.z_axis);
+        }
+    }
+}
+
+/// Split the transform into a scale, then a rotation, then a translation, which is the
+/// order the engine applies a pattern's transforms in.
+fn engine_transform(transform: &Transform3d, source_range: SourceRange) -> Result<shared::Transform, KclError> {
+    let (scale, rotation, translate) = decompose(transform).ok_or_else(|| {
+        KclError::Semantic(KclErrorDetails {
+            message: "The transform can only translate, rotate and scale, the last row must be [0, 0, 0, 1] \
+                and the columns of the upper 3x3 must be at right angles to each other"
+                .to_string(),
+            source_ranges: vec![source_range],
+        })
+    })?;
+
+    let mut engine_rotation = shared::Rotation::default();
+    if let Some((axis, angle)) = rotation {
+        engine_rotation.axis = axis.into();
+        engine_rotation.angle = shared::Angle::from_degrees(angle);
+        engine_rotation.origin = shared::OriginType::Global;
+    }
+    Ok(shared::Transform {
+        translate: shared::Point3d::<LengthUnit>::from(translate),
+        scale: scale.into(),
+        rotation: engine_rotation,
+        replicate: true,
+    })
+}
+
+const TOLERANCE: f64 = 1e-9;
+
+/// Split the transform into a scale along each axis, a rotation about an axis through the
+/// origin (in degrees) and a translation. Returns `None` for transforms which shear or
+/// project, or flatten everything onto a plane.
+#[allow(clippy::type_complexity)]
+fn decompose(transform: &Transform3d) -> Option<(Point3d, Option<(Point3d, f64)>, Point3d)> {
+    let m = &transform.0;
+    if m[3][0].abs() > TOLERANCE || m[3][1].abs() > TOLERANCE || m[3][2].abs() > TOLERANCE {
+        return None;
+    }
+    if (m[3][3] - 1.0).abs() > TOLERANCE {
+        return None;
+    }
+
+    let column = |j: usize| [m[0][j], m[1][j], m[2][j]];
+    let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let columns = [column(0), column(1), column(2)];
+    let mut scale = columns.map(|c| dot(c, c).sqrt());
+    if scale.iter().any(|s| *s <= TOLERANCE) {
+        return None;
+    }
+    for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+        if dot(columns[i], columns[j]).abs() > 1e-6 * scale[i] * scale[j] {
+            return None;
+        }
+    }
+
+    // The rotation is what's left once the columns are unit length.
+    let mut r = [[0.0; 3]; 3];
+    for (j, c) in columns.iter().enumerate() {
+        for i in 0..3 {
+            r[i][j] = c[i] / scale[j];
+        }
+    }
+    // A mirror image isn't a rotation, so flip one axis into the scale instead.
+    let det = r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1]) - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+        + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+    if det < 0.0 {
+        scale[0] = -scale[0];
+        for row in r.iter_mut() {
+            row[0] = -row[0];
+        }
+    }
+
+    let scale = Point3d::new(scale[0], scale[1], scale[2]);
+    let translate = Point3d::new(m[0][3], m[1][3], m[2][3]);
+    Some((scale, axis_angle(&r), translate))
+}
+
+/// The axis and angle in degrees of a rotation matrix, or `None` if it doesn't rotate.
+fn axis_angle(r: &[[f64; 3]; 3]) -> Option<(Point3d, f64)> {
+    let cos = ((r[0][0] + r[1][1] + r[2][2] - 1.0) / 2.0).clamp(-1.0, 1.0);
+    let angle = cos.acos();
+    if angle < 1e-9 {
+        return None;
+    }
+
+    let axis = if std::f64::consts::PI - angle < 1e-6 {
+        // A half turn is symmetric, so the axis comes from the diagonal instead.
+        let k = (0..3)
+            .max_by(|a, b| r[*a][*a].total_cmp(&r[*b][*b]))
+            .unwrap_or_default();
+        let mut axis = [0.0; 3];
+        axis[k] = ((r[k][k] + 1.0) / 2.0).sqrt();
+        for j in (0..3).filter(|j| *j != k) {
+            axis[j] = r[k][j] / (2.0 * axis[k]);
+        }
+        Point3d::new(axis[0], axis[1], axis[2])
+    } else {
+        let s = 2.0 * angle.sin();
+        Point3d::new(
+            (r[2][1] - r[1][2]) / s,
+            (r[0][2] - r[2][0]) / s,
+            (r[1][0] - r[0][1]) / s,
+        )
+    };
+    Some((axis, angle.to_degrees()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Point3d, b: Point3d) {
+        assert!(
+            (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9 && (a.z - b.z).abs() < 1e-9,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_rotation_about_a_point() {
+        // A quarter turn about the Z axis through (1, 0, 0).
+        let transform = Transform3d::rotation(Point3d::new(0.0, 0.0, 2.0), 90.0, Point3d::new(1.0, 0.0, 0.0)).unwrap();
+        assert_close(
+            transform.transform_point(Point3d::new(2.0, 0.0, 5.0)),
+            Point3d::new(1.0, 1.0, 5.0),
+        );
+        assert_close(
+            transform.transform_vector(Point3d::new(1.0, 0.0, 0.0)),
+            Point3d::new(0.0, 1.0, 0.0),
+        );
+        assert!(Transform3d::rotation(Point3d::ZERO, 90.0, Point3d::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_then() {
+        let transform = Transform3d::scale(Point3d::new(2.0, 2.0, 2.0))
+            .then(&Transform3d::translation(Point3d::new(1.0, 0.0, 0.0)));
+        assert_close(
+            transform.transform_point(Point3d::new(1.0, 1.0, 1.0)),
+            Point3d::new(3.0, 2.0, 2.0),
+        );
+    }
+
+    #[test]
+    fn test_decompose() {
+        let transform = Transform3d::scale(Point3d::new(2.0, 3.0, 4.0))
+            .then(&Transform3d::rotation(Point3d::new(1.0, 1.0, 0.0), 120.0, Point3d::new(5.0, 0.0, 0.0)).unwrap());
+        let (scale, rotation, translate) = decompose(&transform).unwrap();
+        assert_close(scale, Point3d::new(2.0, 3.0, 4.0));
+        let (axis, angle) = rotation.unwrap();
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert_close(axis, Point3d::new(half, half, 0.0));
+        assert!((angle - 120.0).abs() < 1e-9);
+
+        // Putting it back together gives the same transform.
+        let rebuilt = Transform3d::scale(scale)
+            .then(&Transform3d::rotation(axis, angle, Point3d::ZERO).unwrap())
+            .then(&Transform3d::translation(translate));
+        for p in [Point3d::ZERO, Point3d::new(1.0, -2.0, 3.0)] {
+            assert_close(rebuilt.transform_point(p), transform.transform_point(p));
+        }
+
+        // A half turn.
+        let (_, rotation, _) =
+            decompose(&Transform3d::rotation(Point3d::new(0.0, 1.0, 0.0), 180.0, Point3d::ZERO).unwrap()).unwrap();
+        let (axis, angle) = rotation.unwrap();
+        assert_close(axis, Point3d::new(0.0, 1.0, 0.0));
+        assert!((angle - 180.0).abs() < 1e-9);
+
+        // A shear can't be split up.
+        let mut shear = Transform3d::IDENTITY;
+        shear.0[0][1] = 1.0;
+        assert!(decompose(&shear).is_none());
+        assert_eq!(
+            decompose(&Transform3d::IDENTITY),
+            Some((Point3d::new(1.0, 1.0, 1.0), None, Point3d::ZERO))
+        );
+    }
+
+    async fn execute(code: &str) -> Result<ExecState, KclError> {
+        let ctx = crate::executor::ExecutorContext::new_mock(Default::default())
+            .await
+            .unwrap();
+        let program = crate::parser::parse(code).unwrap();
+        ctx.run(&program, None, crate::executor::IdGenerator::default(), None)
+            .await
+    }
+
+    const BLOCK: &str = r#"const block = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %, $side)
+  |> line([0, 10], %)
+  |> line([-10, 0], %)
+  |> close(%)
+  |> extrude(5, %)
+"#;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_moved_solids_can_be_built_on() {
+        let code = format!(
+            r#"{}const moved = translate([20, 0, 0], block)
+
+// The block was moved rather than copied, so it's where it was moved to.
+const box = boundingBox(block)
+assertEqual(box.min[0], 20, 0.000001, "the block was moved")
+
+const onSide = startSketchOn(moved, side)
+  |> circle({{ center: [5, 2.5], radius: 1 }}, %)
+const onEnd = startSketchOn(moved, 'end')
+  |> circle({{ center: [5, 5], radius: 1 }}, %)
+const rounded = fillet({{ radius: 1, tags: [getOppositeEdge(side)] }}, moved)
+const cut = chamfer({{ length: 1, tags: [side] }}, moved)
+"#,
+            BLOCK
+        );
+        let exec_state = execute(&code).await.unwrap();
+        let (KclValue::Solid(block), KclValue::Solid(moved)) = (
+            exec_state.memory.get("block", SourceRange::default()).unwrap(),
+            exec_state.memory.get("moved", SourceRange::default()).unwrap(),
+        ) else {
+            panic!("expected solids");
+        };
+        assert_eq!(block, moved);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_use_copied_ids() {
+        let exec_state = execute(BLOCK).await.unwrap();
+        let KclValue::Solid(block) = exec_state.memory.get("block", SourceRange::default()).unwrap() else {
+            panic!("expected a solid");
+        };
+        let side = block.value[0].clone();
+        let ids: HashMap<uuid::Uuid, uuid::Uuid> = [block.id, side.get_id(), side.face_id(), block.end_cap_id.unwrap()]
+            .into_iter()
+            .map(|id| (id, uuid::Uuid::new_v4()))
+            .collect();
+
+        let mut copy = block.clone();
+        use_copied_ids(&mut copy, &ids);
+        assert_eq!(copy.id, ids[&block.id]);
+        assert_eq!(copy.sketch.id, ids[&block.id]);
+        assert_eq!(copy.end_cap_id, Some(ids[&block.end_cap_id.unwrap()]));
+        assert_eq!(copy.start_cap_id, block.start_cap_id);
+        assert_eq!(copy.value[0].get_id(), ids[&side.get_id()]);
+        assert_eq!(copy.value[0].face_id(), ids[&side.face_id()]);
+        assert_eq!(copy.sketch.paths[0].get_base().geo_meta.id, ids[&side.get_id()]);
+
+        let info = copy.sketch.tags["side"].info.as_ref().unwrap();
+        assert_eq!(info.id, ids[&side.get_id()]);
+        assert_eq!(info.sketch, ids[&block.id]);
+        assert_eq!(info.path.as_ref().unwrap().geo_meta.id, ids[&side.get_id()]);
+    }
+}
@@ -4,7 +4,7 @@ use kittycad_modeling_cmds::shared::Angle;
 
 use crate::{
     errors::{KclError, KclErrorDetails},
-    executor::{Point2d, SourceRange},
+    executor::{Path, Point2d, Sketch, SourceRange},
 };
 
 /// Get the angle between these points
@@ -268,12 +268,206 @@ pub fn is_on_circumference(center: Point2d, point: Point2d, radius: f64) -> bool
     (distance_squared - radius.powi(2)).abs() < 1e-9
 }
 
+/// One piece of a sketch's path, in the coordinates of its sketch surface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum CurveSegment {
+    Line {
+        from: [f64; 2],
+        to: [f64; 2],
+    },
+    Arc {
+        center: [f64; 2],
+        radius: f64,
+        /// Start angle in radians.
+        start: f64,
+        /// Signed sweep in radians, positive is counterclockwise.
+        sweep: f64,
+    },
+}
+
+impl CurveSegment {
+    pub(crate) fn length(&self) -> f64 {
+        match self {
+            CurveSegment::Line { from, to } => (to[0] - from[0]).hypot(to[1] - from[1]),
+            CurveSegment::Arc { radius, sweep, .. } => radius * sweep.abs(),
+        }
+    }
+
+    /// The point a distance `t` along this segment.
+    pub(crate) fn point_at(&self, t: f64) -> [f64; 2] {
+        match self {
+            CurveSegment::Line { from, to } => {
+                let len = self.length();
+                if len <= f64::EPSILON {
+                    return *from;
+                }
+                let f = t / len;
+                [from[0] + (to[0] - from[0]) * f, from[1] + (to[1] - from[1]) * f]
+            }
+            CurveSegment::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => {
+                let angle = start + sweep.signum() * t / radius;
+                [center[0] + radius * angle.cos(), center[1] + radius * angle.sin()]
+            }
+        }
+    }
+
+    /// The unit direction of travel a distance `t` along this segment.
+    pub(crate) fn tangent_at(&self, t: f64) -> [f64; 2] {
+        match self {
+            CurveSegment::Line { from, to } => {
+                let len = self.length();
+                [(to[0] - from[0]) / len, (to[1] - from[1]) / len]
+            }
+            CurveSegment::Arc {
+                radius, start, sweep, ..
+            } => {
+                let angle = start + sweep.signum() * t / radius;
+                let s = sweep.signum();
+                [-s * angle.sin(), s * angle.cos()]
+            }
+        }
+    }
+
+    /// This segment's share of the signed area of the profile it's in, counterclockwise positive.
+    pub(crate) fn signed_area(&self) -> f64 {
+        match self {
+            CurveSegment::Line { from, to } => (from[0] * to[1] - to[0] * from[1]) / 2.0,
+            CurveSegment::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => {
+                let end = start + sweep;
+                (radius * radius * sweep
+                    + radius * (center[0] * (end.sin() - start.sin()) - center[1] * (end.cos() - start.cos())))
+                    / 2.0
+            }
+        }
+    }
+}
+
+impl From<&Path> for CurveSegment {
+    fn from(path: &Path) -> Self {
+        match path {
+            Path::ToPoint { base }
+            | Path::Horizontal { base, .. }
+            | Path::AngledLineTo { base, .. }
+            | Path::Base { base } => CurveSegment::Line {
+                from: base.from,
+                to: base.to,
+            },
+            Path::TangentialArcTo { base, center, ccw } | Path::TangentialArc { base, center, ccw } => {
+                arc_segment(*center, base.from, base.to, *ccw)
+            }
+            Path::Circle {
+                center, radius, ccw, ..
+            } => CurveSegment::Arc {
+                center: *center,
+                radius: *radius,
+                start: 0.0,
+                sweep: if *ccw {
+                    std::f64::consts::TAU
+                } else {
+                    -std::f64::consts::TAU
+                },
+            },
+        }
+    }
+}
+
+/// The path of a sketch as a chain of lines and arcs which can be measured
+/// and sampled by distance.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PathCurve {
+    pub(crate) segments: Vec<CurveSegment>,
+}
+
+impl PathCurve {
+    /// The sketch's path, leaving out any segments with no length.
+    pub(crate) fn from_sketch(sketch: &Sketch) -> Self {
+        let segments = sketch
+            .paths
+            .iter()
+            .map(CurveSegment::from)
+            .filter(|segment| segment.length() > f64::EPSILON)
+            .collect();
+        Self { segments }
+    }
+
+    pub(crate) fn length(&self) -> f64 {
+        self.segments.iter().map(CurveSegment::length).sum()
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        let (Some(first), Some(last)) = (self.segments.first(), self.segments.last()) else {
+            return false;
+        };
+        let start = first.point_at(0.0);
+        let end = last.point_at(last.length());
+        (end[0] - start[0]).hypot(end[1] - start[1]) < 1e-6
+    }
+
+    /// Find the segment a distance along the whole path, and how far along that segment it is.
+    fn locate(&self, distance: f64) -> (&CurveSegment, f64) {
+        let mut remaining = distance;
+        for segment in &self.segments {
+            let len = segment.length();
+            if remaining <= len {
+                return (segment, remaining);
+            }
+            remaining -= len;
+        }
+        // Past the end, which only happens through rounding. Clamp to the end.
+        let last = self.segments.last().expect("a path with a length has segments");
+        (last, last.length())
+    }
+
+    pub(crate) fn point_at(&self, distance: f64) -> [f64; 2] {
+        let (segment, t) = self.locate(distance);
+        segment.point_at(t)
+    }
+
+    pub(crate) fn tangent_at(&self, distance: f64) -> [f64; 2] {
+        let (segment, t) = self.locate(distance);
+        segment.tangent_at(t)
+    }
+}
+
+/// An arc from `from` to `to` around `center`.
+pub(crate) fn arc_segment(center: [f64; 2], from: [f64; 2], to: [f64; 2], ccw: bool) -> CurveSegment {
+    let radius = (from[0] - center[0]).hypot(from[1] - center[1]);
+    let start = (from[1] - center[1]).atan2(from[0] - center[0]);
+    let end = (to[1] - center[1]).atan2(to[0] - center[0]);
+    let mut sweep = end - start;
+    if ccw {
+        while sweep <= 0.0 {
+            sweep += std::f64::consts::TAU;
+        }
+    } else {
+        while sweep >= 0.0 {
+            sweep -= std::f64::consts::TAU;
+        }
+    }
+    CurveSegment::Arc {
+        center,
+        radius,
+        start,
+        sweep,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Here you can bring your functions into scope
     use pretty_assertions::assert_eq;
 
-    use super::{get_x_component, get_y_component, Angle};
+    use super::{arc_segment, get_x_component, get_y_component, Angle, CurveSegment, PathCurve};
     use crate::executor::SourceRange;
 
     static EACH_QUAD: [(i32, [i32; 2]); 12] = [
@@ -438,6 +632,53 @@ mod tests {
         assert_eq!(angle_start.to_degrees().round(), 0.0);
         assert_eq!(angle_end.to_degrees().round(), 180.0);
     }
+
+    fn assert_close(actual: [f64; 2], expected: [f64; 2]) {
+        assert!(
+            (actual[0] - expected[0]).abs() < 1e-9 && (actual[1] - expected[1]).abs() < 1e-9,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn test_path_curve_line_and_arc() {
+        // A 10 long line then a quarter turn counterclockwise of radius 10.
+        let curve = PathCurve {
+            segments: vec![
+                CurveSegment::Line {
+                    from: [0.0, 0.0],
+                    to: [10.0, 0.0],
+                },
+                arc_segment([10.0, 10.0], [10.0, 0.0], [20.0, 10.0], true),
+            ],
+        };
+        let quarter = std::f64::consts::FRAC_PI_2 * 10.0;
+        assert!((curve.length() - (10.0 + quarter)).abs() < 1e-9);
+        assert!(!curve.is_closed());
+
+        assert_close(curve.point_at(5.0), [5.0, 0.0]);
+        assert_close(curve.tangent_at(5.0), [1.0, 0.0]);
+        assert_close(curve.point_at(10.0 + quarter), [20.0, 10.0]);
+        assert_close(curve.tangent_at(10.0 + quarter), [0.0, 1.0]);
+        // Clamped to the end when rounding takes us past it.
+        assert_close(curve.point_at(10.0 + quarter + 1e-12), [20.0, 10.0]);
+    }
+
+    #[test]
+    fn test_path_curve_clockwise_circle() {
+        let curve = PathCurve {
+            segments: vec![CurveSegment::Arc {
+                center: [0.0, 0.0],
+                radius: 2.0,
+                start: 0.0,
+                sweep: -std::f64::consts::TAU,
+            }],
+        };
+        assert!(curve.is_closed());
+        let quarter = std::f64::consts::PI;
+        assert_close(curve.point_at(quarter), [0.0, -2.0]);
+        assert_close(curve.tangent_at(quarter), [-1.0, 0.0]);
+    }
 }
 
 pub type Coords2d = [f64; 2];
@@ -0,0 +1,316 @@
+//! Opt-in telemetry about how KCL is used.
+//!
+//! What gets collected is governed by [`TelemetryLevel`]: nothing, metadata about each file
+//! (its size and which stdlib functions it calls), or the full code. Code is always passed
+//! through [`redact`] first, so string literals and comments never leave the machine.
+//! Where it goes is up to the [`TelemetrySink`].
+
+pub mod redact;
+
+use std::{cell::RefCell, collections::BTreeMap, io::Write};
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+
+pub use crate::settings::types::{TelemetryLevel, TelemetrySettings};
+
+/// A single telemetry report.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub struct TelemetryEvent {
+    /// When the event was created.
+    #[ts(type = "string")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// The level the event was collected at.
+    pub level: TelemetryLevel,
+    /// A hash of the name of the project, so projects can be told apart without naming them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_name_hash: Option<String>,
+    /// Metadata about every file.
+    pub files: Vec<FileMetadata>,
+    /// The redacted code of every file, keyed by path. Only collected at `full`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub code: BTreeMap<String, String>,
+}
+
+/// What telemetry knows about a file at `metadata_only`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ts_rs::TS, JsonSchema)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub struct FileMetadata {
+    /// A hash of the file path, so files can be told apart without naming them.
+    pub path_hash: String,
+    /// The number of lines in the file.
+    pub lines: usize,
+    /// The number of top-level statements, if the file parses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statements: Option<usize>,
+    /// How many times each function is called.
+    pub calls: BTreeMap<String, usize>,
+}
+
+impl TelemetryEvent {
+    /// Collect an event about the given files, keyed by path.
+    /// Returns `None` when telemetry is off.
+    pub fn new(level: TelemetryLevel, project_name: Option<String>, files: &BTreeMap<String, String>) -> Option<Self> {
+        if level == TelemetryLevel::Off {
+            return None;
+        }
+
+        let mut metadata = Vec::with_capacity(files.len());
+        let mut code = BTreeMap::new();
+        for (path, source) in files {
+            let program = crate::parser::parse(source).ok();
+            metadata.push(FileMetadata {
+                path_hash: hash(path),
+                lines: source.lines().count(),
+                statements: program.as_ref().map(|p| p.body.len()),
+                calls: program.as_ref().map(count_calls).unwrap_or_default(),
+            });
+
+            if level == TelemetryLevel::Full {
+                // Files that don't parse can't be redacted, so they are left out.
+                if let Some(mut program) = program {
+                    redact::redact_program(&mut program);
+                    code.insert(path.clone(), program.recast(&Default::default(), 0));
+                }
+            }
+        }
+
+        Some(Self {
+            created_at: chrono::Utc::now(),
+            level,
+            project_name_hash: project_name.as_deref().map(hash),
+            files: metadata,
+            code,
+        })
+    }
+}
+
+/// Somewhere telemetry events can be sent.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait TelemetrySink {
+    async fn send(&self, event: &TelemetryEvent) -> Result<()>;
+}
+
+/// Append events to a local file, one JSON object per line.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct LocalFileSink {
+    path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LocalFileSink {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait::async_trait]
+impl TelemetrySink for LocalFileSink {
+    async fn send(&self, event: &TelemetryEvent) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(event)?)?;
+        Ok(())
+    }
+}
+
+/// Send events to Zoo, attributed to a hash of the user's id.
+#[derive(Debug, Clone)]
+pub struct ZooSink {
+    client: kittycad::Client,
+}
+
+impl ZooSink {
+    pub fn new(client: kittycad::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TelemetrySink for ZooSink {
+    async fn send(&self, event: &TelemetryEvent) -> Result<()> {
+        // Get information about the user.
+        let user = self
+            .client
+            .users()
+            .get_self()
+            .await
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        let project_name = event
+            .project_name_hash
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("no project names"))?;
+
+        self.client
+            .meta()
+            .create_event(
+                vec![kittycad::types::multipart::Attachment {
+                    name: "attachment".to_string(),
+                    filename: Some("attachment.zip".to_string()),
+                    content_type: Some("application/x-zip".to_string()),
+                    data: event_zip(event)?,
+                }],
+                &kittycad::types::Event {
+                    // This gets generated server side so leave empty for now.
+                    attachment_uri: None,
+                    created_at: event.created_at,
+                    event_type: kittycad::types::ModelingAppEventType::SuccessfulCompileBeforeClose,
+                    last_compiled_at: Some(event.created_at),
+                    // We do not have project descriptions yet.
+                    project_description: None,
+                    project_name,
+                    // The UUID for the modeling app.
+                    source_id: uuid::Uuid::from_u128(0x70178592_dfca_47b3_bd2d_6fce2bcaee04),
+                    type_: kittycad::types::Type::ModelingAppEvent,
+                    user_id: hash(&user.id.to_string()),
+                },
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Zip the event: the metadata as `telemetry.json`, and the redacted code under its own path.
+fn event_zip(event: &TelemetryEvent) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(&mut buf));
+    let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    zip.start_file("telemetry.json", options)?;
+    zip.write_all(&serde_json::to_vec_pretty(&TelemetryEvent {
+        code: Default::default(),
+        ..event.clone()
+    })?)?;
+    for (path, code) in &event.code {
+        zip.start_file(path.trim_start_matches('/'), options)?;
+        zip.write_all(code.as_bytes())?;
+    }
+    zip.finish()?;
+
+    Ok(buf)
+}
+
+fn hash(value: &str) -> String {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(value);
+    format!("{:x}", hasher.finalize())
+}
+
+/// How often each standard library function is called. Calls to the user's own functions
+/// are left out, as their names could say what's being designed.
+fn count_calls(program: &crate::ast::types::Program) -> BTreeMap<String, usize> {
+    let calls = RefCell::new(BTreeMap::new());
+    let _ = crate::walk::walk(program, &|node: crate::walk::Node| {
+        if let crate::walk::Node::CallExpression(call) = node {
+            if crate::std::get_stdlib_fn(&call.callee.name).is_none() {
+                return Ok(true);
+            }
+            *calls.borrow_mut().entry(call.callee.name.clone()).or_insert(0) += 1;
+        }
+        Ok(true)
+    });
+    calls.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn files() -> BTreeMap<String, String> {
+        BTreeMap::from([(
+            "/projects/acme/main.kcl".to_string(),
+            r#"// Acme bracket.
+const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, 0], %)
+  |> line([0, 10], %)
+  |> close(%)
+"#
+            .to_string(),
+        )])
+    }
+
+    #[test]
+    fn test_telemetry_off() {
+        assert_eq!(TelemetryEvent::new(TelemetryLevel::Off, None, &files()), None);
+    }
+
+    #[test]
+    fn test_telemetry_metadata_only() {
+        let event = TelemetryEvent::new(TelemetryLevel::MetadataOnly, Some("acme".to_string()), &files()).unwrap();
+        assert!(event.code.is_empty());
+        assert_eq!(event.project_name_hash, Some(hash("acme")));
+        assert_eq!(event.files.len(), 1);
+        let file = &event.files[0];
+        assert_eq!(file.path_hash, hash("/projects/acme/main.kcl"));
+        assert_eq!(file.lines, 6);
+        assert_eq!(file.statements, Some(1));
+        assert_eq!(
+            file.calls,
+            BTreeMap::from([
+                ("close".to_string(), 1),
+                ("line".to_string(), 2),
+                ("startProfileAt".to_string(), 1),
+                ("startSketchOn".to_string(), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_telemetry_leaves_out_user_functions() {
+        let files = BTreeMap::from([(
+            "/projects/acme/main.kcl".to_string(),
+            r#"fn secretWidget = (size) => {
+  return startSketchOn('XY')
+    |> circle({ center: [0, 0], radius: size }, %)
+}
+const widget = secretWidget(5)
+"#
+            .to_string(),
+        )]);
+        let event = TelemetryEvent::new(TelemetryLevel::MetadataOnly, None, &files).unwrap();
+        assert_eq!(
+            event.files[0].calls,
+            BTreeMap::from([("circle".to_string(), 1), ("startSketchOn".to_string(), 1)])
+        );
+    }
+
+    #[test]
+    fn test_telemetry_full_is_redacted() {
+        let event = TelemetryEvent::new(TelemetryLevel::Full, None, &files()).unwrap();
+        let code = &event.code["/projects/acme/main.kcl"];
+        assert!(code.starts_with("part001 = startSketchOn(\"\")\n"));
+        assert!(!code.contains("Acme"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_local_file_sink() {
+        let path = std::env::temp_dir().join(format!("kcl-telemetry-{}.jsonl", uuid::Uuid::new_v4()));
+        let sink = LocalFileSink::new(&path);
+        let event = TelemetryEvent::new(TelemetryLevel::MetadataOnly, None, &files()).unwrap();
+        sink.send(&event).await.unwrap();
+        sink.send(&event).await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(serde_json::from_str::<TelemetryEvent>(lines[0]).unwrap(), event);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,187 @@
+//! Strip anything free-form out of a program before it leaves the machine.
+//!
+//! Identifiers and numbers are kept, since they are what makes the code useful to learn from,
+//! but every string literal (including import paths) is emptied and every comment is dropped.
+
+use crate::ast::types::{
+    BinaryPart, BodyItem, Expr, LiteralIdentifier, LiteralValue, MemberObject, NonCodeMeta, Program,
+};
+
+/// What a redacted import path is replaced with.
+pub const REDACTED_IMPORT_PATH: &str = "redacted.kcl";
+
+/// Redact a program in place.
+pub fn redact_program(program: &mut Program) {
+    program.non_code_meta = NonCodeMeta::default();
+    program.digest = None;
+
+    for item in &mut program.body {
+        match item {
+            BodyItem::ImportStatement(import) => {
+                import.path = REDACTED_IMPORT_PATH.to_string();
+                import.raw_path = format!("\"{}\"", REDACTED_IMPORT_PATH);
+                import.digest = None;
+            }
+            BodyItem::ExpressionStatement(statement) => {
+                redact_expr(&mut statement.expression);
+                statement.digest = None;
+            }
+            BodyItem::VariableDeclaration(declaration) => {
+                for declarator in &mut declaration.declarations {
+                    redact_expr(&mut declarator.init);
+                    declarator.digest = None;
+                }
+                declaration.digest = None;
+            }
+            BodyItem::ReturnStatement(statement) => {
+                redact_expr(&mut statement.argument);
+                statement.digest = None;
+            }
+        }
+    }
+}
+
+/// Redact KCL source, returning the redacted source.
+pub fn redact_source(code: &str) -> Result<String, crate::errors::KclError> {
+    let mut program = crate::parser::parse(code)?;
+    redact_program(&mut program);
+    Ok(program.recast(&Default::default(), 0))
+}
+
+fn redact_expr(expr: &mut Expr) {
+    match expr {
+        Expr::Literal(literal) => redact_literal(literal),
+        Expr::Identifier(_) | Expr::TagDeclarator(_) | Expr::PipeSubstitution(_) | Expr::None(_) => {}
+        Expr::BinaryExpression(binary) => {
+            redact_binary_part(&mut binary.left);
+            redact_binary_part(&mut binary.right);
+            binary.digest = None;
+        }
+        Expr::FunctionExpression(function) => {
+            redact_program(&mut function.body);
+            function.digest = None;
+        }
+        Expr::CallExpression(call) => {
+            call.arguments.iter_mut().for_each(redact_expr);
+            call.digest = None;
+        }
+        Expr::PipeExpression(pipe) => {
+            pipe.body.iter_mut().for_each(redact_expr);
+            pipe.non_code_meta = NonCodeMeta::default();
+            pipe.digest = None;
+        }
+        Expr::ArrayExpression(array) => {
+            array.elements.iter_mut().for_each(redact_expr);
+            array.non_code_meta = NonCodeMeta::default();
+            array.digest = None;
+        }
+        Expr::ArrayRangeExpression(range) => {
+            redact_expr(&mut range.start_element);
+            redact_expr(&mut range.end_element);
+            range.digest = None;
+        }
+        Expr::ObjectExpression(object) => {
+            for property in &mut object.properties {
+                redact_expr(&mut property.value);
+                property.digest = None;
+            }
+            object.non_code_meta = NonCodeMeta::default();
+            object.digest = None;
+        }
+        Expr::MemberExpression(member) => redact_member(member),
+        Expr::UnaryExpression(unary) => {
+            redact_binary_part(&mut unary.argument);
+            unary.digest = None;
+        }
+        Expr::IfExpression(if_expr) => redact_if(if_expr),
+    }
+}
+
+fn redact_binary_part(part: &mut BinaryPart) {
+    match part {
+        BinaryPart::Literal(literal) => redact_literal(literal),
+        BinaryPart::Identifier(_) => {}
+        BinaryPart::BinaryExpression(binary) => {
+            redact_binary_part(&mut binary.left);
+            redact_binary_part(&mut binary.right);
+            binary.digest = None;
+        }
+        BinaryPart::CallExpression(call) => {
+            call.arguments.iter_mut().for_each(redact_expr);
+            call.digest = None;
+        }
+        BinaryPart::UnaryExpression(unary) => {
+            redact_binary_part(&mut unary.argument);
+            unary.digest = None;
+        }
+        BinaryPart::MemberExpression(member) => redact_member(member),
+        BinaryPart::IfExpression(if_expr) => redact_if(if_expr),
+    }
+}
+
+fn redact_member(member: &mut crate::ast::types::MemberExpression) {
+    if let MemberObject::MemberExpression(object) = &mut member.object {
+        redact_member(object);
+    }
+    if let LiteralIdentifier::Literal(literal) = &mut member.property {
+        redact_literal(literal);
+    }
+    member.digest = None;
+}
+
+fn redact_if(if_expr: &mut crate::ast::types::IfExpression) {
+    redact_expr(&mut if_expr.cond);
+    redact_program(&mut if_expr.then_val);
+    for else_if in &mut if_expr.else_ifs {
+        redact_expr(&mut else_if.cond);
+        redact_program(&mut else_if.then_val);
+        else_if.digest = None;
+    }
+    redact_program(&mut if_expr.final_else);
+    if_expr.digest = None;
+}
+
+fn redact_literal(literal: &mut crate::ast::types::Literal) {
+    if let LiteralValue::String(_) = literal.value {
+        literal.value = LiteralValue::String(String::new());
+        literal.raw = "\"\"".to_string();
+        literal.digest = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_redact_strings_and_comments() {
+        let code = r#"// Secret bracket for Acme Corp.
+import thickness from "secret.kcl"
+
+const part001 = startSketchOn('XY')
+  |> startProfileAt([0, 0], %)
+  |> line([10, thickness], %, $seg01) // the long side
+  |> close(%)
+  |> extrude(5, %)
+
+/* material: "titanium" */
+const label = {
+  name: "Acme",
+  rev: 3
+}
+"#;
+        assert_eq!(
+            redact_source(code).unwrap(),
+            r#"import thickness from "redacted.kcl"
+part001 = startSketchOn("")
+  |> startProfileAt([0, 0], %)
+  |> line([10, thickness], %, $seg01)
+  |> close(%)
+  |> extrude(5, %)
+label = { name: "", rev: 3 }
+"#
+        );
+    }
+}
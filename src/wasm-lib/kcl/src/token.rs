@@ -241,6 +241,53 @@ pub fn lexer(s: &str) -> Result<Vec<Token>, KclError> {
     tokeniser::lexer(s).map_err(From::from)
 }
 
+/// A change to some code: the bytes `start..old_end` of the old code were replaced by the bytes
+/// `start..new_end` of the new code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edit {
+    pub start: usize,
+    pub old_end: usize,
+    pub new_end: usize,
+}
+
+impl Edit {
+    /// The smallest edit that turns `old` into `new`.
+    pub fn between(old: &str, new: &str) -> Self {
+        let mut start = old.bytes().zip(new.bytes()).take_while(|(a, b)| a == b).count();
+        while !new.is_char_boundary(start) {
+            start -= 1;
+        }
+
+        let max_suffix = old.len().min(new.len()) - start;
+        let mut suffix = old
+            .bytes()
+            .rev()
+            .zip(new.bytes().rev())
+            .take(max_suffix)
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+            suffix -= 1;
+        }
+
+        Self {
+            start,
+            old_end: old.len() - suffix,
+            new_end: new.len() - suffix,
+        }
+    }
+}
+
+/// Lex code again after an edit, only relexing the part of it the edit could have changed.
+/// `previous` are the tokens of the code before the edit.
+pub fn relex(previous: &[Token], s: &str, edit: &Edit) -> Result<Vec<Token>, KclError> {
+    match tokeniser::relex(previous, s, edit) {
+        Some(tokens) => Ok(tokens),
+        // Lex it all again for the error.
+        None => lexer(s),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,4 +298,33 @@ mod tests {
         let semantic_types = TokenType::all_semantic_token_types().unwrap();
         assert!(!semantic_types.is_empty());
     }
+
+    #[test]
+    fn test_edit_between() {
+        assert_eq!(
+            Edit::between("const x = 1", "const xy = 1"),
+            Edit {
+                start: 7,
+                old_end: 7,
+                new_end: 8
+            }
+        );
+        assert_eq!(
+            Edit::between("aaaa", "aa"),
+            Edit {
+                start: 2,
+                old_end: 4,
+                new_end: 2
+            }
+        );
+        // Never splits a character.
+        assert_eq!(
+            Edit::between("'é'", "'è'"),
+            Edit {
+                start: 1,
+                old_end: 3,
+                new_end: 3
+            }
+        );
+    }
 }
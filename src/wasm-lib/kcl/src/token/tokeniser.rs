@@ -8,12 +8,58 @@ use winnow::{
     Located,
 };
 
-use crate::token::{Token, TokenType};
+use crate::token::{Edit, Token, TokenType};
 
 pub fn lexer(i: &str) -> Result<Vec<Token>, ParseError<Located<&str>, ContextError>> {
     repeat(0.., token).parse(Located::new(i))
 }
 
+/// Lex `code` again after `edit`, reusing the tokens from before the edit (`previous`) wherever
+/// the edit can't have changed them. Returns None if the code doesn't lex.
+///
+/// Lexing restarts one token before the edit, since an edit can join two tokens into one
+/// (`1.` then `5`), and stops as soon as it produces a token the old code had in the same place.
+pub fn relex(previous: &[Token], code: &str, edit: &Edit) -> Option<Vec<Token>> {
+    let damaged = previous
+        .iter()
+        .position(|token| token.end >= edit.start)
+        .unwrap_or(previous.len());
+    let first = damaged.saturating_sub(1);
+    let restart = previous.get(first).map(|token| token.start).unwrap_or_default();
+
+    let mut tokens = previous[..first].to_vec();
+    let mut old = previous[first..]
+        .iter()
+        .filter(|token| token.start >= edit.old_end)
+        .peekable();
+    // Where an old token after the edit is now.
+    let moved = |offset: usize| offset - edit.old_end + edit.new_end;
+
+    let mut i = Located::new(code);
+    i.next_slice(restart);
+    while !i.is_empty() {
+        let token = token(&mut i).ok()?;
+        if token.start >= edit.new_end {
+            while old.peek().is_some_and(|o| moved(o.start) < token.start) {
+                old.next();
+            }
+            if old.peek().is_some_and(|o| {
+                moved(o.start) == token.start && o.token_type == token.token_type && o.value == token.value
+            }) {
+                // Back in step, everything from here on is the same as before.
+                tokens.extend(old.map(|o| Token {
+                    start: moved(o.start),
+                    end: moved(o.end),
+                    ..o.clone()
+                }));
+                return Some(tokens);
+            }
+        }
+        tokens.push(token);
+    }
+    Some(tokens)
+}
+
 pub fn token(i: &mut Located<&str>) -> PResult<Token> {
     match winnow::combinator::dispatch! {peek(any);
         '"' | '\'' => string,
@@ -234,6 +280,38 @@ mod tests {
         assert!(res.is_ok(), "failed to parse {s}, got {}", res.unwrap_err());
     }
 
+    #[test]
+    fn test_relex() {
+        let code = "const x = 1.\nconst y = startSketchOn('XY') // comment\n";
+        let previous = lexer(code).unwrap();
+        for (start, end, insert) in [
+            // Joins the number back together.
+            (12, 12, "5"),
+            // Inside a word.
+            (7, 7, "yz"),
+            // Opens a block comment that swallows everything after.
+            (0, 0, "/*"),
+            // Deletes across lines.
+            (8, 20, ""),
+            // At the very end.
+            (code.len(), code.len(), "x"),
+            // Replaces everything.
+            (0, code.len(), "fn"),
+        ] {
+            let new = format!("{}{}{}", &code[..start], insert, &code[end..]);
+            let edit = Edit {
+                start,
+                old_end: end,
+                new_end: start + insert.len(),
+            };
+            assert_eq!(
+                relex(&previous, &new, &edit),
+                Some(lexer(&new).unwrap()),
+                "relexing {new:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_number() {
         for valid in [
@@ -163,6 +163,24 @@ pub async fn modify_ast_for_sketch_wasm(
     JsValue::from_serde(&program).map_err(|e| e.to_string())
 }
 
+// wasm_bindgen wrapper for applying typed edits to the ast
+#[wasm_bindgen]
+pub fn apply_ast_edits_wasm(program_str: &str, edits_str: &str) -> Result<JsValue, String> {
+    console_error_panic_hook::set_once();
+
+    // deserialize the ast from a stringified json
+    let program: kcl_lib::ast::types::Program = serde_json::from_str(program_str).map_err(|e| e.to_string())?;
+    let edits: Vec<kcl_lib::ast::transaction::AstEdit> = serde_json::from_str(edits_str).map_err(|e| e.to_string())?;
+
+    let result = kcl_lib::ast::transaction::AstTransaction::new(edits)
+        .apply(&program)
+        .map_err(String::from)?;
+
+    // The serde-wasm-bindgen does not work here because of weird HashMap issues so we use the
+    // gloo-serialize crate instead.
+    JsValue::from_serde(&result).map_err(|e| e.to_string())
+}
+
 #[wasm_bindgen]
 pub fn deserialize_files(data: &[u8]) -> Result<JsValue, JsError> {
     console_error_panic_hook::set_once();
@@ -323,8 +341,11 @@ pub async fn kcl_lsp_run(
         diagnostics_map: Default::default(),
         symbols_map: Default::default(),
         semantic_tokens_map: Default::default(),
+        sent_semantic_tokens: Default::default(),
         zoo_client,
         can_send_telemetry: privacy_settings.can_train_on_data,
+        telemetry: Default::default(),
+        text_editor: Default::default(),
         can_execute: Arc::new(tokio::sync::RwLock::new(executor_ctx.is_some())),
         executor_ctx: Arc::new(tokio::sync::RwLock::new(executor_ctx)),
 
@@ -332,6 +353,11 @@ pub async fn kcl_lsp_run(
     })
     .custom_method("kcl/updateUnits", kcl_lib::lsp::kcl::Backend::update_units)
     .custom_method("kcl/updateCanExecute", kcl_lib::lsp::kcl::Backend::update_can_execute)
+    .custom_method("kcl/updateTelemetry", kcl_lib::lsp::kcl::Backend::update_telemetry)
+    .custom_method(
+        "kcl/updateTextEditorSettings",
+        kcl_lib::lsp::kcl::Backend::update_text_editor_settings,
+    )
     .finish();
 
     let input = wasm_bindgen_futures::stream::JsStream::from(into_server);
@@ -354,6 +380,28 @@ pub async fn kcl_lsp_run(
     Ok(())
 }
 
+/// Where the copilot lsp server gets its completions from.
+#[wasm_bindgen]
+pub struct CopilotLspServerConfig {
+    completion_url: Option<String>,
+    model: Option<String>,
+    api_key: Option<String>,
+}
+
+#[wasm_bindgen]
+impl CopilotLspServerConfig {
+    /// Without a completion url, completions come from the Zoo API. With one, they come from
+    /// that OpenAI-compatible server, asking for the given model.
+    #[wasm_bindgen(constructor)]
+    pub fn new(completion_url: Option<String>, model: Option<String>, api_key: Option<String>) -> Self {
+        Self {
+            completion_url,
+            model,
+            api_key,
+        }
+    }
+}
+
 /// Run the `copilot` lsp server.
 //
 // NOTE: we don't use web_sys::ReadableStream for input here because on the
@@ -362,7 +410,12 @@ pub async fn kcl_lsp_run(
 
 // NOTE: input needs to be an AsyncIterator<Uint8Array, never, void> specifically
 #[wasm_bindgen]
-pub async fn copilot_lsp_run(config: ServerConfig, token: String, baseurl: String) -> Result<(), JsValue> {
+pub async fn copilot_lsp_run(
+    config: ServerConfig,
+    copilot_config: CopilotLspServerConfig,
+    token: String,
+    baseurl: String,
+) -> Result<(), JsValue> {
     console_error_panic_hook::set_once();
 
     let ServerConfig {
@@ -371,8 +424,22 @@ pub async fn copilot_lsp_run(config: ServerConfig, token: String, baseurl: Strin
         fs,
     } = config;
 
-    let mut zoo_client = kittycad::Client::new(token);
-    zoo_client.set_base_url(baseurl.as_str());
+    let provider: Arc<dyn kcl_lib::lsp::copilot::provider::CompletionProvider> =
+        match (copilot_config.completion_url, copilot_config.model) {
+            (Some(url), Some(model)) => {
+                let mut provider = kcl_lib::lsp::copilot::provider::OpenAiCompatibleProvider::new(url, model);
+                if let Some(api_key) = copilot_config.api_key {
+                    provider = provider.with_api_key(api_key);
+                }
+                Arc::new(provider)
+            }
+            (Some(_), None) => return Err("A completion url needs a model to ask for".into()),
+            _ => {
+                let mut zoo_client = kittycad::Client::new(token);
+                zoo_client.set_base_url(baseurl.as_str());
+                Arc::new(kcl_lib::lsp::copilot::provider::ZooCompletionProvider::new(zoo_client))
+            }
+        };
 
     let file_manager = Arc::new(kcl_lib::fs::FileManager::new(fs));
 
@@ -384,7 +451,7 @@ pub async fn copilot_lsp_run(config: ServerConfig, token: String, baseurl: Strin
         editor_info: Arc::new(RwLock::new(kcl_lib::lsp::copilot::types::CopilotEditorInfo::default())),
         cache: Arc::new(kcl_lib::lsp::copilot::cache::CopilotCache::new()),
         telemetry: Default::default(),
-        zoo_client,
+        provider,
 
         is_initialized: Default::default(),
         diagnostics_map: Default::default(),
@@ -501,6 +568,31 @@ pub fn program_memory_init() -> Result<JsValue, String> {
     JsValue::from_serde(&memory).map_err(|e| e.to_string())
 }
 
+/// Get the source range of the code which made an artifact, from the artifact graph of an
+/// execution.
+#[wasm_bindgen]
+pub fn artifact_source_range(artifact_graph_str: &str, id: &str) -> Result<JsValue, String> {
+    console_error_panic_hook::set_once();
+
+    let graph: kcl_lib::artifact_graph::ArtifactGraph =
+        serde_json::from_str(artifact_graph_str).map_err(|e| e.to_string())?;
+    let id = uuid::Uuid::parse_str(id).map_err(|e| e.to_string())?;
+
+    JsValue::from_serde(&graph.source_range(id)).map_err(|e| e.to_string())
+}
+
+/// Get the ids of the artifacts made by the code at a position in the source, innermost
+/// code first, from the artifact graph of an execution.
+#[wasm_bindgen]
+pub fn artifacts_at_source_position(artifact_graph_str: &str, pos: usize) -> Result<JsValue, String> {
+    console_error_panic_hook::set_once();
+
+    let graph: kcl_lib::artifact_graph::ArtifactGraph =
+        serde_json::from_str(artifact_graph_str).map_err(|e| e.to_string())?;
+
+    JsValue::from_serde(&graph.artifacts_at(pos)).map_err(|e| e.to_string())
+}
+
 /// Get a coredump.
 #[wasm_bindgen]
 pub async fn coredump(core_dump_manager: kcl_lib::coredump::wasm::CoreDumpManager) -> Result<JsValue, String> {
@@ -514,6 +606,17 @@ pub async fn coredump(core_dump_manager: kcl_lib::coredump::wasm::CoreDumpManage
     JsValue::from_serde(&dump).map_err(|e| e.to_string())
 }
 
+/// Get a coredump as a zip archive, without uploading anything.
+#[wasm_bindgen]
+pub async fn coredump_local(core_dump_manager: kcl_lib::coredump::wasm::CoreDumpManager) -> Result<Vec<u8>, String> {
+    console_error_panic_hook::set_once();
+
+    let core_dumper = kcl_lib::coredump::wasm::CoreDumper::new(core_dump_manager);
+    let bundle = core_dumper.dump_local().await.map_err(|e| e.to_string())?;
+
+    bundle.to_zip().map_err(|e| e.to_string())
+}
+
 /// Get the default app settings.
 #[wasm_bindgen]
 pub fn default_app_settings() -> Result<JsValue, String> {